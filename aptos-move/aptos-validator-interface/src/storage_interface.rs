@@ -5,10 +5,9 @@
 use crate::AptosValidatorInterface;
 use anyhow::{anyhow, bail, ensure, Result};
 use aptos_config::config::{
-    RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
-    NO_OP_STORAGE_PRUNER_CONFIG,
+    RocksdbConfigs, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD, NO_OP_STORAGE_PRUNER_CONFIG,
 };
-use aptos_db::AptosDB;
+use aptos_db::{AptosDB, StateStoreOptions};
 use aptos_storage_interface::{DbReader, MAX_REQUEST_LIMIT};
 use aptos_types::{
     account_address::AccountAddress,
@@ -28,8 +27,8 @@ impl DBDebuggerInterface {
             NO_OP_STORAGE_PRUNER_CONFIG,
             RocksdbConfigs::default(),
             false,
-            BUFFERED_STATE_TARGET_ITEMS,
             DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            StateStoreOptions::default(),
         )?)))
     }
 }
@@ -3,7 +3,7 @@
 
 use anyhow::anyhow;
 use aptos_config::{config::NodeConfig, utils::get_genesis_txn};
-use aptos_db::AptosDB;
+use aptos_db::{AptosDB, StateStoreOptions};
 use aptos_executor::db_bootstrapper::maybe_bootstrap;
 use aptos_logger::{debug, info};
 use aptos_storage_interface::{DbReader, DbReaderWriter};
@@ -101,10 +101,15 @@ pub fn initialize_database_and_checkpoints(
         node_config.storage.storage_pruner_config,
         node_config.storage.rocksdb_configs,
         node_config.storage.enable_indexer,
-        node_config.storage.buffered_state_target_items,
         node_config.storage.max_num_nodes_per_lru_cache_shard,
+        StateStoreOptions::from(&node_config.storage),
     )
     .map_err(|err| anyhow!("DB failed to open {}", err))?;
+    if node_config.storage.verify_buffered_state_on_startup {
+        aptos_db
+            .verify_buffered_state_consistency()
+            .map_err(|err| anyhow!("DB state is inconsistent with the ledger {}", err))?;
+    }
     let (aptos_db, db_rw, backup_service) =
         bootstrap_db(aptos_db, node_config.storage.backup_service_address);
 
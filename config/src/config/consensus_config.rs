@@ -12,17 +12,29 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 pub(crate) const MAX_SENDING_BLOCK_TXNS_QUORUM_STORE_OVERRIDE: u64 = 4000;
+// Rounds lengthened beyond this can't recover from a leader failure in reasonable time.
+pub(crate) const MAX_CHAIN_HEALTH_ROUND_TIMEOUT_MULTIPLIER: f64 = 10.0;
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct ConsensusConfig {
     // length of inbound queue of messages
     pub max_network_channel_size: usize,
+    // Whether quorum store is enabled, which decides if the *_quorum_store_override limits apply.
+    // Not read from the config file: consensus sets it from the on-chain config at the start of
+    // every epoch, so all the call sites agree on it.
+    #[serde(skip)]
+    pub quorum_store_enabled: bool,
     // Use getters to read the correct value with/without quorum store.
     pub max_sending_block_txns: u64,
     pub max_sending_block_txns_quorum_store_override: u64,
     pub max_sending_block_bytes: u64,
     pub max_sending_block_bytes_quorum_store_override: u64,
+    // Ceilings on the size of proposals, that the sending limits and every
+    // max_sending_block_*_override of the pipeline_backpressure and chain_health_backoff tiers
+    // must respect.
+    pub absolute_max_sending_block_txns: u64,
+    pub absolute_max_sending_block_bytes: u64,
     pub max_receiving_block_txns: u64,
     pub max_receiving_block_txns_quorum_store_override: u64,
     pub max_receiving_block_bytes: u64,
@@ -57,6 +69,11 @@ pub struct ConsensusConfig {
     //   and others are creating large blocks, so we can wait as well.
     pub wait_for_full_blocks_above_pending_blocks: usize,
     pub wait_for_full_blocks_above_recent_fill_threshold: f32,
+    // Number of the most recent pending blocks whose max fill fraction is compared against
+    // wait_for_full_blocks_above_recent_fill_threshold. Only a window smaller than
+    // wait_for_full_blocks_above_pending_blocks has an effect, since with that many pending
+    // blocks the proposer waits for full blocks regardless of how full the recent ones are.
+    pub recent_fill_window_blocks: usize,
     pub intra_consensus_channel_buffer_size: usize,
     pub quorum_store_configs: QuorumStoreConfig,
     pub vote_back_pressure_limit: u64,
@@ -65,6 +82,17 @@ pub struct ConsensusConfig {
     // must match one of the CHAIN_HEALTH_WINDOW_SIZES values.
     pub window_for_chain_health: usize,
     pub chain_health_backoff: Vec<ChainHealthBackoffValues>,
+    // Scales round_initial_timeout_ms while the participating voting power is low enough to
+    // trigger a chain_health_backoff, giving rounds more time to succeed. Must be within
+    // [1.0, MAX_CHAIN_HEALTH_ROUND_TIMEOUT_MULTIPLIER].
+    pub chain_health_round_timeout_multiplier: f64,
+    // If set, the node stops voting and falls back to state sync once the execution pipeline
+    // lags behind ordering by more than this for a sustained window. Must be greater than every
+    // back_pressure_pipeline_latency_limit_ms, so it only trips once backpressure didn't help.
+    pub max_pipeline_latency_before_sync_ms: Option<u64>,
+    // How long the pipeline latency has to stay above max_pipeline_latency_before_sync_ms before
+    // the node stops voting.
+    pub pipeline_latency_sync_window_ms: u64,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
@@ -92,6 +120,7 @@ impl Default for ConsensusConfig {
     fn default() -> ConsensusConfig {
         ConsensusConfig {
             max_network_channel_size: 1024,
+            quorum_store_enabled: false,
             max_sending_block_txns: 2500,
             max_sending_block_txns_quorum_store_override:
                 MAX_SENDING_BLOCK_TXNS_QUORUM_STORE_OVERRIDE,
@@ -99,6 +128,10 @@ impl Default for ConsensusConfig {
             // over 1gbps link
             max_sending_block_bytes: 600 * 1024, // 600 KB
             max_sending_block_bytes_quorum_store_override: 5 * 1024 * 1024, // 5MB
+            // Large enough for the sending limits and the largest pipeline_backpressure tiers
+            // below
+            absolute_max_sending_block_txns: 10000,
+            absolute_max_sending_block_bytes: 5 * 1024 * 1024, // 5MB
             max_receiving_block_txns: 10000,
             max_receiving_block_txns_quorum_store_override: 2
                 * MAX_SENDING_BLOCK_TXNS_QUORUM_STORE_OVERRIDE,
@@ -122,6 +155,8 @@ impl Default for ConsensusConfig {
             wait_for_full_blocks_above_pending_blocks: 100,
             // Max is 1, so 1.1 disables it.
             wait_for_full_blocks_above_recent_fill_threshold: 1.1,
+            // Same as wait_for_full_blocks_above_pending_blocks, i.e. all the pending blocks
+            recent_fill_window_blocks: 100,
             intra_consensus_channel_buffer_size: 10,
             quorum_store_configs: QuorumStoreConfig::default(),
 
@@ -202,6 +237,10 @@ impl Default for ConsensusConfig {
                     max_sending_block_bytes_override: 100 * 1024,
                 },
             ],
+            // 1.0 leaves the round timeout unchanged.
+            chain_health_round_timeout_multiplier: 1.0,
+            max_pipeline_latency_before_sync_ms: None,
+            pipeline_latency_sync_window_ms: 5000,
         }
     }
 }
@@ -211,6 +250,7 @@ impl ConsensusConfig {
         self.safety_rules.set_data_dir(data_dir);
     }
 
+    #[deprecated(note = "use max_sending_block_txns_effective instead")]
     pub fn max_sending_block_txns(&self, quorum_store_enabled: bool) -> u64 {
         if quorum_store_enabled {
             self.max_sending_block_txns_quorum_store_override
@@ -219,6 +259,7 @@ impl ConsensusConfig {
         }
     }
 
+    #[deprecated(note = "use max_sending_block_bytes_effective instead")]
     pub fn max_sending_block_bytes(&self, quorum_store_enabled: bool) -> u64 {
         if quorum_store_enabled {
             self.max_sending_block_bytes_quorum_store_override
@@ -227,6 +268,7 @@ impl ConsensusConfig {
         }
     }
 
+    #[deprecated(note = "use max_receiving_block_txns_effective instead")]
     pub fn max_receiving_block_txns(&self, quorum_store_enabled: bool) -> u64 {
         if quorum_store_enabled {
             self.max_receiving_block_txns_quorum_store_override
@@ -235,6 +277,7 @@ impl ConsensusConfig {
         }
     }
 
+    #[deprecated(note = "use max_receiving_block_bytes_effective instead")]
     pub fn max_receiving_block_bytes(&self, quorum_store_enabled: bool) -> u64 {
         if quorum_store_enabled {
             self.max_receiving_block_bytes_quorum_store_override
@@ -242,6 +285,38 @@ impl ConsensusConfig {
             self.max_receiving_block_bytes
         }
     }
+
+    pub fn max_sending_block_txns_effective(&self) -> u64 {
+        if self.quorum_store_enabled {
+            self.max_sending_block_txns_quorum_store_override
+        } else {
+            self.max_sending_block_txns
+        }
+    }
+
+    pub fn max_sending_block_bytes_effective(&self) -> u64 {
+        if self.quorum_store_enabled {
+            self.max_sending_block_bytes_quorum_store_override
+        } else {
+            self.max_sending_block_bytes
+        }
+    }
+
+    pub fn max_receiving_block_txns_effective(&self) -> u64 {
+        if self.quorum_store_enabled {
+            self.max_receiving_block_txns_quorum_store_override
+        } else {
+            self.max_receiving_block_txns
+        }
+    }
+
+    pub fn max_receiving_block_bytes_effective(&self) -> u64 {
+        if self.quorum_store_enabled {
+            self.max_receiving_block_bytes_quorum_store_override
+        } else {
+            self.max_receiving_block_bytes
+        }
+    }
 }
 
 impl ConfigSanitizer for ConsensusConfig {
@@ -255,6 +330,9 @@ impl ConfigSanitizer for ConsensusConfig {
         let sanitizer_name = Self::get_sanitizer_name();
         SafetyRulesConfig::sanitize(node_config, node_role, chain_id)?;
 
+        // Verify that the quorum store config is valid
+        QuorumStoreConfig::sanitize(node_config, node_role, chain_id)?;
+
         // Verify that the consensus-only feature is not enabled in mainnet
         if chain_id.is_mainnet()? && is_consensus_only_perf_test_enabled() {
             return Err(Error::ConfigSanitizerFailed(
@@ -263,6 +341,177 @@ impl ConfigSanitizer for ConsensusConfig {
             ));
         }
 
+        // Verify that the chain health round timeout multiplier neither shortens rounds nor
+        // overflows the round timeout. NaN fails the range check too.
+        let chain_health_round_timeout_multiplier =
+            node_config.consensus.chain_health_round_timeout_multiplier;
+        if !(1.0..=MAX_CHAIN_HEALTH_ROUND_TIMEOUT_MULTIPLIER)
+            .contains(&chain_health_round_timeout_multiplier)
+        {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                format!(
+                    "chain_health_round_timeout_multiplier must be within [1.0, {}], got {}",
+                    MAX_CHAIN_HEALTH_ROUND_TIMEOUT_MULTIPLIER,
+                    chain_health_round_timeout_multiplier
+                ),
+            ));
+        }
+
+        // Verify that the quorum store overrides are set, as quorum store can be enabled on
+        // chain at any epoch
+        let consensus_config = &node_config.consensus;
+        for (name, value) in [
+            (
+                "max_sending_block_txns_quorum_store_override",
+                consensus_config.max_sending_block_txns_quorum_store_override,
+            ),
+            (
+                "max_sending_block_bytes_quorum_store_override",
+                consensus_config.max_sending_block_bytes_quorum_store_override,
+            ),
+            (
+                "max_receiving_block_txns_quorum_store_override",
+                consensus_config.max_receiving_block_txns_quorum_store_override,
+            ),
+            (
+                "max_receiving_block_bytes_quorum_store_override",
+                consensus_config.max_receiving_block_bytes_quorum_store_override,
+            ),
+        ] {
+            if value == 0 {
+                return Err(Error::ConfigSanitizerFailed(
+                    sanitizer_name,
+                    format!("{} must be > 0", name),
+                ));
+            }
+        }
+
+        // Verify that the sending limits don't exceed the receiving limits of the same mode, so
+        // that peers never reject a proposal within them. The overrides below only ever lower
+        // the sending limits.
+        for (mode, sending_txns, sending_bytes, receiving_txns, receiving_bytes) in [
+            (
+                "without quorum store",
+                consensus_config.max_sending_block_txns,
+                consensus_config.max_sending_block_bytes,
+                consensus_config.max_receiving_block_txns,
+                consensus_config.max_receiving_block_bytes,
+            ),
+            (
+                "with quorum store",
+                consensus_config.max_sending_block_txns_quorum_store_override,
+                consensus_config.max_sending_block_bytes_quorum_store_override,
+                consensus_config.max_receiving_block_txns_quorum_store_override,
+                consensus_config.max_receiving_block_bytes_quorum_store_override,
+            ),
+        ] {
+            if sending_txns > receiving_txns || sending_bytes > receiving_bytes {
+                return Err(Error::ConfigSanitizerFailed(
+                    sanitizer_name,
+                    format!(
+                        "The max sending block size {} ({} txns, {} bytes) exceeds the max \
+                        receiving block size ({} txns, {} bytes)",
+                        mode, sending_txns, sending_bytes, receiving_txns, receiving_bytes
+                    ),
+                ));
+            }
+        }
+
+        // Verify that the sending limits, with or without quorum store, don't exceed the
+        // absolute limits
+        let max_sending_block_txns = consensus_config
+            .max_sending_block_txns
+            .max(consensus_config.max_sending_block_txns_quorum_store_override);
+        let max_sending_block_bytes = consensus_config
+            .max_sending_block_bytes
+            .max(consensus_config.max_sending_block_bytes_quorum_store_override);
+        let absolute_max_sending_block_txns = consensus_config.absolute_max_sending_block_txns;
+        let absolute_max_sending_block_bytes = consensus_config.absolute_max_sending_block_bytes;
+        if max_sending_block_txns > absolute_max_sending_block_txns
+            || max_sending_block_bytes > absolute_max_sending_block_bytes
+        {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                format!(
+                    "The max sending block size ({} txns, {} bytes) exceeds the absolute max \
+                    sending block size ({} txns, {} bytes)",
+                    max_sending_block_txns,
+                    max_sending_block_bytes,
+                    absolute_max_sending_block_txns,
+                    absolute_max_sending_block_bytes
+                ),
+            ));
+        }
+
+        // Verify that the backpressure overrides don't exceed the absolute limits
+        let overrides = consensus_config
+            .pipeline_backpressure
+            .iter()
+            .map(|values| {
+                (
+                    "pipeline_backpressure",
+                    values.max_sending_block_txns_override,
+                    values.max_sending_block_bytes_override,
+                )
+            })
+            .chain(consensus_config.chain_health_backoff.iter().map(|values| {
+                (
+                    "chain_health_backoff",
+                    values.max_sending_block_txns_override,
+                    values.max_sending_block_bytes_override,
+                )
+            }));
+        for (name, txns_override, bytes_override) in overrides {
+            if txns_override > consensus_config.absolute_max_sending_block_txns
+                || bytes_override > consensus_config.absolute_max_sending_block_bytes
+            {
+                return Err(Error::ConfigSanitizerFailed(
+                    sanitizer_name,
+                    format!(
+                        "{} overrides ({} txns, {} bytes) exceed the absolute max sending block \
+                        size ({} txns, {} bytes)",
+                        name,
+                        txns_override,
+                        bytes_override,
+                        consensus_config.absolute_max_sending_block_txns,
+                        consensus_config.absolute_max_sending_block_bytes
+                    ),
+                ));
+            }
+        }
+
+        // Verify that the recent fill window is not empty
+        if node_config.consensus.recent_fill_window_blocks < 1 {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "recent_fill_window_blocks must be >= 1".to_string(),
+            ));
+        }
+
+        // Verify that falling back to state sync only happens after backpressure
+        if let Some(max_pipeline_latency_before_sync_ms) =
+            node_config.consensus.max_pipeline_latency_before_sync_ms
+        {
+            let max_backpressure_latency_limit_ms = node_config
+                .consensus
+                .pipeline_backpressure
+                .iter()
+                .map(|values| values.back_pressure_pipeline_latency_limit_ms)
+                .max()
+                .unwrap_or(0);
+            if max_pipeline_latency_before_sync_ms <= max_backpressure_latency_limit_ms {
+                return Err(Error::ConfigSanitizerFailed(
+                    sanitizer_name,
+                    format!(
+                        "max_pipeline_latency_before_sync_ms must be greater than the largest \
+                        back_pressure_pipeline_latency_limit_ms {}, got {}",
+                        max_backpressure_latency_limit_ms, max_pipeline_latency_before_sync_ms
+                    ),
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -289,4 +538,304 @@ mod test {
 
         serde_yaml::from_str::<ConsensusConfig>(&s).unwrap();
     }
+
+    #[test]
+    fn test_sanitize_valid_chain_health_round_timeout_multiplier() {
+        // Create a node config that lengthens rounds when chain health is poor
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                chain_health_round_timeout_multiplier: 1.5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it succeeds
+        ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_invalid_chain_health_round_timeout_multiplier() {
+        // Create a node config that shortens rounds when chain health is poor
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                chain_health_round_timeout_multiplier: 0.5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails because
+        // the multiplier is below 1.0.
+        let error =
+            ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_sanitize_non_finite_chain_health_round_timeout_multiplier() {
+        for multiplier in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            // Create a node config with a multiplier that isn't a finite number
+            let mut node_config = NodeConfig {
+                consensus: ConsensusConfig {
+                    chain_health_round_timeout_multiplier: multiplier,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            // Sanitize the config and verify that it fails
+            let error =
+                ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                    .unwrap_err();
+            assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+        }
+    }
+
+    #[test]
+    fn test_sanitize_too_large_chain_health_round_timeout_multiplier() {
+        // Create a node config that lengthens rounds far too much when chain health is poor
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                chain_health_round_timeout_multiplier: 1e20,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails because
+        // the multiplier is above the max.
+        let error =
+            ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // The max itself is allowed
+        node_config.consensus.chain_health_round_timeout_multiplier =
+            MAX_CHAIN_HEALTH_ROUND_TIMEOUT_MULTIPLIER;
+        ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_invalid_recent_fill_window_blocks() {
+        // Create a node config with an empty recent fill window
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                recent_fill_window_blocks: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails because
+        // the window is empty.
+        let error =
+            ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_sanitize_valid_quorum_store_overrides() {
+        // Create a node config with the default overrides
+        let mut node_config = NodeConfig::default();
+
+        // Sanitize the config and verify that it succeeds
+        ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+            .unwrap();
+
+        // Verify that the overrides apply once consensus enables quorum store
+        let consensus_config = &mut node_config.consensus;
+        consensus_config.quorum_store_enabled = true;
+        assert_eq!(
+            consensus_config.max_sending_block_txns_effective(),
+            MAX_SENDING_BLOCK_TXNS_QUORUM_STORE_OVERRIDE
+        );
+    }
+
+    #[test]
+    fn test_sanitize_invalid_quorum_store_overrides() {
+        // Create a node config with a zero override
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                max_receiving_block_bytes_quorum_store_override: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails because the override is
+        // zero, even though quorum store isn't enabled yet.
+        let error =
+            ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_quorum_store_enabled_not_serialized() {
+        // Verify that the runtime flag isn't written to the config file
+        let config = ConsensusConfig {
+            quorum_store_enabled: true,
+            ..Default::default()
+        };
+        let s = serde_yaml::to_string(&config).unwrap();
+        assert!(!s.contains("quorum_store_enabled"));
+        let deserialized_config: ConsensusConfig = serde_yaml::from_str(&s).unwrap();
+        assert!(!deserialized_config.quorum_store_enabled);
+
+        // Verify that it can't be set from the config file either
+        let s = format!("{}quorum_store_enabled: true\n", s);
+        serde_yaml::from_str::<ConsensusConfig>(&s).unwrap_err();
+    }
+
+    #[test]
+    fn test_sanitize_default_absolute_max_sending_block_size() {
+        // Create a node config with the default block sizes
+        let mut node_config = NodeConfig::default();
+
+        // Sanitize the config and verify that it succeeds
+        ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_max_sending_block_size_above_receiving_limit() {
+        // Create a node config with a byte sending limit above the receiving limit without
+        // quorum store, but within the ceiling
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                max_sending_block_bytes: 4 * 1024 * 1024,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails because
+        // the sending limit exceeds the receiving limit.
+        let error =
+            ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Create a node config with a quorum store sending limit above the quorum store
+        // receiving limit, but within the ceiling and the receiving limit without quorum store
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                max_sending_block_txns_quorum_store_override: 9000,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails
+        let error =
+            ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_sanitize_max_sending_block_size_above_absolute_limit() {
+        // Create a node config with a sending limit above the ceiling, but within the receiving
+        // limit
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                max_sending_block_txns: 11000,
+                max_receiving_block_txns: 12000,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails because
+        // the sending limit exceeds the ceiling.
+        let error =
+            ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Create a node config with a quorum store sending limit above the byte ceiling
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                max_sending_block_bytes_quorum_store_override: 6 * 1024 * 1024,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails
+        let error =
+            ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_sanitize_invalid_absolute_max_sending_block_size() {
+        // Create a node config with a ceiling below the largest pipeline backpressure tier
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                absolute_max_sending_block_txns: 5000,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails because
+        // a pipeline backpressure override exceeds the ceiling.
+        let error =
+            ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Create a node config with a chain health override above the byte ceiling
+        let mut node_config = NodeConfig::default();
+        node_config.consensus.chain_health_backoff[0].max_sending_block_bytes_override =
+            6 * 1024 * 1024;
+
+        // Sanitize the config and verify that it fails
+        let error =
+            ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_sanitize_valid_max_pipeline_latency_before_sync() {
+        // Create a node config that only syncs after the largest backpressure latency limit
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                max_pipeline_latency_before_sync_ms: Some(10_000),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it succeeds
+        ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_invalid_max_pipeline_latency_before_sync() {
+        // Create a node config that syncs before backpressure kicks in
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                max_pipeline_latency_before_sync_ms: Some(1000),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails because the
+        // latency is below the largest backpressure latency limit.
+        let error =
+            ConsensusConfig::sanitize(&mut node_config, RoleType::FullNode, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
 }
@@ -1,7 +1,12 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::config::MAX_SENDING_BLOCK_TXNS_QUORUM_STORE_OVERRIDE;
+use crate::config::{
+    config_sanitizer::ConfigSanitizer, Error, NodeConfig, RoleType,
+    MAX_SENDING_BLOCK_TXNS_QUORUM_STORE_OVERRIDE,
+};
+use aptos_logger::warn;
+use aptos_types::chain_id::ChainId;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -80,3 +85,152 @@ impl Default for QuorumStoreConfig {
         }
     }
 }
+
+impl QuorumStoreConfig {
+    /// Whether batch_quota batches of up to max_batch_bytes can take more than db_quota
+    fn batch_quota_exceeds_db_quota(&self) -> bool {
+        self.batch_quota.saturating_mul(self.max_batch_bytes) > self.db_quota
+    }
+}
+
+impl ConfigSanitizer for QuorumStoreConfig {
+    /// Validate and process the quorum store config according to the given node role and chain ID
+    fn sanitize(
+        node_config: &mut NodeConfig,
+        _node_role: RoleType,
+        _chain_id: ChainId,
+    ) -> Result<(), Error> {
+        let sanitizer_name = Self::get_sanitizer_name();
+        let quorum_store_config = &node_config.consensus.quorum_store_configs;
+
+        // Verify that a single batch fits in the memory quota
+        if quorum_store_config.max_batch_bytes > quorum_store_config.memory_quota {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                format!(
+                    "max_batch_bytes {} must not exceed memory_quota {}",
+                    quorum_store_config.max_batch_bytes, quorum_store_config.memory_quota
+                ),
+            ));
+        }
+
+        // Verify that the memory quota fits in the db quota, as the batch store requires
+        if quorum_store_config.memory_quota > quorum_store_config.db_quota {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                format!(
+                    "memory_quota {} must not exceed db_quota {}",
+                    quorum_store_config.memory_quota, quorum_store_config.db_quota
+                ),
+            ));
+        }
+
+        // Warn if the batch quota allows more bytes than the db quota, so the db quota is the
+        // limit that's hit first
+        if quorum_store_config.batch_quota_exceeds_db_quota() {
+            warn!(
+                "batch_quota {} of batches of up to max_batch_bytes {} exceeds db_quota {}, so \
+                the db quota is reached before the batch quota.",
+                quorum_store_config.batch_quota,
+                quorum_store_config.max_batch_bytes,
+                quorum_store_config.db_quota
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConsensusConfig;
+
+    #[test]
+    fn test_sanitize_default_config() {
+        let mut node_config = NodeConfig::default();
+        QuorumStoreConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::mainnet())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_batch_exceeding_memory_quota() {
+        // Create a node config with a max batch size larger than the memory quota
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                quorum_store_configs: QuorumStoreConfig {
+                    max_batch_bytes: 10_000_000,
+                    memory_quota: 5_000_000,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Verify that the config sanitizer fails
+        let error =
+            QuorumStoreConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::mainnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_sanitize_default_config_warning() {
+        // Verify that the default quotas trigger the warning, as the default batch quota is
+        // sized for small batches rather than max_batch_bytes ones
+        assert!(QuorumStoreConfig::default().batch_quota_exceeds_db_quota());
+    }
+
+    #[test]
+    fn test_sanitize_memory_quota_exceeding_db_quota() {
+        // Create a node config with a memory quota larger than the db quota
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                quorum_store_configs: QuorumStoreConfig {
+                    memory_quota: 200_000_000,
+                    db_quota: 100_000_000,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Verify that the config sanitizer fails
+        let error =
+            QuorumStoreConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::mainnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_sanitize_batch_quota_exceeding_db_quota() {
+        // Create a node config with a batch quota that allows more bytes than the db quota
+        let quorum_store_config = QuorumStoreConfig {
+            max_batch_bytes: 1_000_000,
+            batch_quota: 1_000,
+            ..Default::default()
+        };
+        assert!(quorum_store_config.batch_quota_exceeds_db_quota());
+        let mut node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                quorum_store_configs: quorum_store_config,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Verify that the config sanitizer only warns
+        QuorumStoreConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::mainnet())
+            .unwrap();
+
+        // Verify that a batch quota within the db quota doesn't trigger the warning
+        let quorum_store_config = QuorumStoreConfig {
+            max_batch_bytes: 1_000_000,
+            batch_quota: 10,
+            ..Default::default()
+        };
+        assert!(!quorum_store_config.batch_quota_exceeds_db_quota());
+    }
+}
@@ -20,6 +20,10 @@ use std::{
     path::PathBuf,
 };
 
+// The minimum network timeout for safety rules services that rely on it. Lower timeouts cause
+// requests to time out (and flap) under normal load.
+const MIN_NETWORK_TIMEOUT_MS: u64 = 1000;
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct SafetyRulesConfig {
@@ -31,6 +35,9 @@ pub struct SafetyRulesConfig {
     pub network_timeout_ms: u64,
     pub enable_cached_safety_data: bool,
     pub initial_safety_rules_config: InitialSafetyRulesConfig,
+    // Whether to check at startup that the identity blob of the initial safety rules config can
+    // be loaded and contains the keys needed by safety rules.
+    pub validate_initial_safety_rules_config: bool,
 }
 
 impl Default for SafetyRulesConfig {
@@ -44,6 +51,7 @@ impl Default for SafetyRulesConfig {
             network_timeout_ms: 30_000,
             enable_cached_safety_data: true,
             initial_safety_rules_config: InitialSafetyRulesConfig::None,
+            validate_initial_safety_rules_config: false,
         }
     }
 }
@@ -117,6 +125,21 @@ impl ConfigSanitizer for SafetyRulesConfig {
             ));
         }
 
+        // Verify that the network timeout is reasonable for services that rely on it
+        if safety_rules_config.service.uses_network_timeout()
+            && safety_rules_config.network_timeout_ms < MIN_NETWORK_TIMEOUT_MS
+        {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                format!(
+                    "The safety rules network timeout must be at least {}ms for the {:?} service! Given timeout: {}ms",
+                    MIN_NETWORK_TIMEOUT_MS,
+                    &safety_rules_config.service,
+                    safety_rules_config.network_timeout_ms
+                ),
+            ));
+        }
+
         // Verify that the initial safety rules config is set for validators
         if node_role.is_validator() {
             if let InitialSafetyRulesConfig::None = safety_rules_config.initial_safety_rules_config
@@ -128,6 +151,11 @@ impl ConfigSanitizer for SafetyRulesConfig {
             }
         }
 
+        // Verify that the identity blob is usable by safety rules (if enabled)
+        if safety_rules_config.validate_initial_safety_rules_config {
+            safety_rules_config.initial_safety_rules_config.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -166,6 +194,48 @@ impl InitialSafetyRulesConfig {
             InitialSafetyRulesConfig::None => panic!("Must have an identity blob"),
         }
     }
+
+    /// Verifies that the identity blob can be loaded and contains the account address and
+    /// consensus key that safety rules needs to initialize its storage
+    pub fn validate(&self) -> Result<(), Error> {
+        let identity_blob_path = match self {
+            InitialSafetyRulesConfig::FromFile {
+                identity_blob_path, ..
+            } => identity_blob_path,
+            InitialSafetyRulesConfig::None => return Ok(()),
+        };
+
+        let sanitizer_name = SafetyRulesConfig::get_sanitizer_name();
+        let identity_blob = IdentityBlob::from_file(identity_blob_path).map_err(|error| {
+            Error::ConfigSanitizerFailed(
+                sanitizer_name.clone(),
+                format!(
+                    "Failed to load the identity blob at {:?}! Error: {}",
+                    identity_blob_path, error
+                ),
+            )
+        })?;
+        if identity_blob.account_address.is_none() {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                format!(
+                    "The identity blob at {:?} is missing the account address!",
+                    identity_blob_path
+                ),
+            ));
+        }
+        if identity_blob.consensus_private_key.is_none() {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                format!(
+                    "The identity blob at {:?} is missing the consensus private key!",
+                    identity_blob_path
+                ),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 /// Defines how safety rules should be executed
@@ -188,6 +258,15 @@ impl SafetyRulesService {
     fn is_local(&self) -> bool {
         matches!(self, SafetyRulesService::Local)
     }
+
+    /// Returns true iff the network timeout applies to the service. The local and thread
+    /// services ignore it.
+    fn uses_network_timeout(&self) -> bool {
+        matches!(
+            self,
+            SafetyRulesService::Process(_) | SafetyRulesService::Serializer
+        )
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
@@ -236,6 +315,10 @@ impl SafetyRulesTestConfig {
 mod tests {
     use super::*;
     use crate::config::ConsensusConfig;
+    use aptos_crypto::x25519;
+    use aptos_temppath::TempPath;
+    use rand::SeedableRng;
+    use std::{fs, path::Path};
 
     #[test]
     fn test_sanitize_invalid_backend_for_mainnet() {
@@ -319,6 +402,59 @@ mod tests {
         assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
     }
 
+    #[test]
+    fn test_sanitize_network_timeout_for_process_service() {
+        // Create a node config with a process service and a tiny network timeout
+        let service = SafetyRulesService::Process(RemoteService {
+            server_address: "/ip4/127.0.0.1/tcp/5555".parse().unwrap(),
+        });
+        let mut node_config = create_config_with_network_timeout(service.clone(), 50);
+
+        // Verify that the config sanitizer fails
+        let error =
+            SafetyRulesConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Verify that the config sanitizer passes with the minimum timeout
+        let mut node_config = create_config_with_network_timeout(service, MIN_NETWORK_TIMEOUT_MS);
+        SafetyRulesConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_network_timeout_for_serializer_service() {
+        // Create a node config with a serializer service and a tiny network timeout
+        let mut node_config =
+            create_config_with_network_timeout(SafetyRulesService::Serializer, 50);
+
+        // Verify that the config sanitizer fails
+        let error =
+            SafetyRulesConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_sanitize_network_timeout_for_local_service() {
+        // Create a node config with a local service and a tiny network timeout
+        let mut node_config = create_config_with_network_timeout(SafetyRulesService::Local, 50);
+
+        // Verify that the config sanitizer passes because the timeout is ignored
+        SafetyRulesConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_network_timeout_for_thread_service() {
+        // Create a node config with a thread service and a tiny network timeout
+        let mut node_config = create_config_with_network_timeout(SafetyRulesService::Thread, 50);
+
+        // Verify that the config sanitizer passes because the timeout is ignored
+        SafetyRulesConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+            .unwrap();
+    }
+
     #[test]
     fn test_sanitize_missing_initial_safety_rules() {
         // Create a node config with a test config
@@ -339,4 +475,104 @@ mod tests {
                 .unwrap_err();
         assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
     }
+
+    #[test]
+    fn test_sanitize_identity_blob() {
+        // Create an identity blob with all the keys safety rules needs
+        let mut rng = StdRng::from_seed([0u8; 32]);
+        let mut identity_blob = IdentityBlob {
+            account_address: Some(PeerId::random()),
+            account_private_key: None,
+            consensus_private_key: Some(bls12381::PrivateKey::generate(&mut rng)),
+            network_private_key: x25519::PrivateKey::generate(&mut rng),
+        };
+        let identity_blob_path = TempPath::new();
+        write_identity_blob(&identity_blob, &identity_blob_path);
+
+        // Verify that the config sanitizer passes
+        let mut node_config = create_config_with_identity_blob(identity_blob_path.path());
+        SafetyRulesConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+            .unwrap();
+
+        // Verify that the config sanitizer fails if the consensus key is missing
+        identity_blob.consensus_private_key = None;
+        write_identity_blob(&identity_blob, &identity_blob_path);
+        let error =
+            SafetyRulesConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Verify that the config sanitizer fails if the blob is malformed
+        fs::write(identity_blob_path.path(), "not an identity blob").unwrap();
+        let error =
+            SafetyRulesConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Verify that the config sanitizer passes if validation is disabled
+        node_config
+            .consensus
+            .safety_rules
+            .validate_initial_safety_rules_config = false;
+        SafetyRulesConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_missing_identity_blob() {
+        // Create a node config with an identity blob that doesn't exist
+        let identity_blob_path = TempPath::new();
+        let mut node_config = create_config_with_identity_blob(identity_blob_path.path());
+
+        // Verify that the config sanitizer fails
+        let error =
+            SafetyRulesConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    /// Creates a validator node config that validates the identity blob at the given path
+    fn create_config_with_identity_blob(identity_blob_path: &Path) -> NodeConfig {
+        NodeConfig {
+            consensus: ConsensusConfig {
+                safety_rules: SafetyRulesConfig {
+                    initial_safety_rules_config: InitialSafetyRulesConfig::from_file(
+                        identity_blob_path.to_path_buf(),
+                        WaypointConfig::None,
+                    ),
+                    validate_initial_safety_rules_config: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Writes the given identity blob to the given path
+    fn write_identity_blob(identity_blob: &IdentityBlob, path: &TempPath) {
+        fs::write(path.path(), serde_yaml::to_string(identity_blob).unwrap()).unwrap();
+    }
+
+    /// Creates a validator node config with the given safety rules service and network timeout
+    fn create_config_with_network_timeout(
+        service: SafetyRulesService,
+        network_timeout_ms: u64,
+    ) -> NodeConfig {
+        NodeConfig {
+            consensus: ConsensusConfig {
+                safety_rules: SafetyRulesConfig {
+                    service,
+                    network_timeout_ms,
+                    initial_safety_rules_config: InitialSafetyRulesConfig::from_file(
+                        PathBuf::new(),
+                        WaypointConfig::None,
+                    ),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
 }
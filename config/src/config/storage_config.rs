@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr},
     path::PathBuf,
+    time::Duration,
 };
 
 // Lru cache will consume about 2G RAM based on this default value.
@@ -18,6 +19,70 @@ pub const DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD: usize = 1 << 13;
 
 pub const BUFFERED_STATE_TARGET_ITEMS: usize = 100_000;
 
+// Well above the number of distinct keys touched by a regular block.
+pub const DEFAULT_MAX_NUM_KEYS_IN_STATE_STATS_CACHE: usize = 1 << 20;
+
+// Well above the number of state values in a regular state snapshot chunk.
+pub const DEFAULT_MAX_STATE_VALUES_PER_RESTORE_BATCH: usize = 1 << 20;
+
+/// Decides when the buffered state updates are dumped into a snapshot. Regardless of the policy,
+/// a snapshot is also triggered once too many transactions have been processed since the last one.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotTriggerPolicy {
+    /// Once the number of buffered state updates reaches the given number.
+    ByItems(usize),
+    /// Once the given time has passed since the last snapshot, bounding how much has to be
+    /// replayed on recovery when writes are bursty.
+    ByTime { interval_ms: u64 },
+    /// Whichever of the above happens first.
+    Either { items: usize, interval_ms: u64 },
+}
+
+impl Default for SnapshotTriggerPolicy {
+    fn default() -> Self {
+        SnapshotTriggerPolicy::ByItems(BUFFERED_STATE_TARGET_ITEMS)
+    }
+}
+
+/// What to do when the state storage usage calculated while committing doesn't match the one
+/// expected by the executor.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageMismatchPolicy {
+    /// Fail the commit, which halts the node.
+    Abort,
+    /// Log the mismatch and persist the calculated usage. Only meant for recovering a DB known to
+    /// be inconsistent.
+    LogAndUseComputed,
+}
+
+impl Default for UsageMismatchPolicy {
+    fn default() -> Self {
+        UsageMismatchPolicy::Abort
+    }
+}
+
+/// What to do when a snapshot is due but the async commit thread is still busy with the previous
+/// ones, i.e., the async commit channel is full.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsyncCommitChannelFullPolicy {
+    /// Block the commit until the channel has room.
+    Block,
+    /// Block the commit for at most the given time. If the channel is still full, the snapshot is
+    /// held back and sent with a later commit, which reports the channel as full so that the
+    /// caller can slow down. A snapshot spanning too many versions is always waited for, bounding
+    /// what's replayed on start up.
+    BlockWithTimeout(Duration),
+}
+
+impl Default for AsyncCommitChannelFullPolicy {
+    fn default() -> Self {
+        AsyncCommitChannelFullPolicy::Block
+    }
+}
+
 /// Port selected RocksDB options for tuning underlying rocksdb instance of AptosDB.
 /// see <https://github.com/facebook/rocksdb/blob/master/include/rocksdb/options.h>
 /// for detailed explanations.
@@ -97,8 +162,51 @@ pub struct StorageConfig {
     /// transactions have been processed since last dump, a new dump is processed
     /// as well.)
     pub buffered_state_target_items: usize,
+    /// Overrides when a snapshot is triggered. If not set, it's triggered by the number of
+    /// buffered state updates, i.e. `buffered_state_target_items`.
+    pub snapshot_trigger_policy: Option<SnapshotTriggerPolicy>,
     /// The max # of nodes for a lru cache shard.
     pub max_num_nodes_per_lru_cache_shard: usize,
+    /// The max # of distinct state keys whose previous values are cached while calculating
+    /// state storage usage and stale indices for a batch of write sets. Larger batches are
+    /// processed in sub-batches so the cache stays bounded.
+    pub max_num_keys_in_state_stats_cache: usize,
+    /// Skip syncing the commit progress of the sub DBs to the overall commit progress on start
+    /// up, which truncates data written after the last full commit. Only meant for operators
+    /// doing controlled recovery who run their own repair afterwards.
+    pub skip_commit_progress_sync: bool,
+    /// Minimum free space in bytes on the volume holding the state K/V DB. Writes are refused
+    /// with an error once free space drops below it. 0 disables the check.
+    pub min_free_disk_space_bytes: u64,
+    /// The max # of state snapshot restores allowed to run at the same time. Acquiring another
+    /// snapshot receiver fails, so e.g. a retrying orchestrator can't interleave restores.
+    pub max_concurrent_restores: usize,
+    /// Count the rows and bytes written to each state K/V shard, labeled by shard id, to spot hot
+    /// shards. Off by default as it adds a pass over every write set.
+    pub enable_state_kv_shard_metrics: bool,
+    /// Verify on start up that the state replayed from the latest snapshot matches the state
+    /// checkpoint hash recorded in the ledger, to catch a silent divergence early.
+    pub verify_buffered_state_on_startup: bool,
+    /// What to do when the calculated state storage usage doesn't match the expected one on
+    /// commit.
+    pub usage_mismatch_policy: UsageMismatchPolicy,
+    /// The max # of state values committed in a single write batch while restoring a state
+    /// snapshot. Larger chunks are committed in several batches to bound the memory used, with
+    /// the restore progress only written along with the last one. Must be positive.
+    pub max_state_values_per_restore_batch: usize,
+    /// Report failures of the state merkle DB on reads with proofs as `MerkleUnavailable` errors,
+    /// counted by a metric, so that a node can keep serving reads without proofs, which only use
+    /// the state K/V DB, during a partial storage incident.
+    pub degrade_on_state_merkle_errors: bool,
+    /// Fail reads of state values at versions already pruned with a `VersionPruned` error, so that
+    /// callers can't mistake a value pruned away for one that doesn't exist.
+    pub error_on_pruned_state_value_reads: bool,
+    /// What to do when a snapshot is due while the async commit channel is full.
+    pub async_commit_channel_full_policy: AsyncCommitChannelFullPolicy,
+    /// Pin the values of all keys under the framework address in memory on start up, so that the
+    /// most read resources are served without touching the DB. They are kept up to date as new
+    /// versions are committed.
+    pub pin_framework_resources: bool,
     /// Rocksdb-specific configurations
     pub rocksdb_configs: RocksdbConfigs,
     /// Try to enable the internal indexer. The indexer expects to have seen all transactions
@@ -123,6 +231,7 @@ pub const NO_OP_STORAGE_PRUNER_CONFIG: PrunerConfig = PrunerConfig {
         enable: false,
         prune_window: 0,
         batch_size: 0,
+        num_snapshots_to_retain: 0,
     },
 };
 
@@ -165,6 +274,9 @@ pub struct EpochSnapshotPrunerConfig {
     pub prune_window: u64,
     /// Number of stale nodes to prune a time.
     pub batch_size: usize,
+    /// Min number of the latest epoch snapshots to retain even if they are out of the window, so
+    /// that a slow network doesn't end up with too few of them. 0 means only the window applies.
+    pub num_snapshots_to_retain: usize,
 }
 
 // Config for the epoch ending state pruner is actually in the same format as the state merkle
@@ -232,6 +344,7 @@ impl Default for EpochSnapshotPrunerConfig {
             // A 10k transaction block (touching 60k state values, in the case of the account
             // creation benchmark) on a 4B items DB (or 1.33B accounts) yields 300k JMT nodes
             batch_size: 1_000,
+            num_snapshots_to_retain: 0,
         }
     }
 }
@@ -252,12 +365,32 @@ impl Default for StorageConfig {
             rocksdb_configs: RocksdbConfigs::default(),
             enable_indexer: false,
             buffered_state_target_items: BUFFERED_STATE_TARGET_ITEMS,
+            snapshot_trigger_policy: None,
             max_num_nodes_per_lru_cache_shard: DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            max_num_keys_in_state_stats_cache: DEFAULT_MAX_NUM_KEYS_IN_STATE_STATS_CACHE,
+            skip_commit_progress_sync: false,
+            min_free_disk_space_bytes: 0,
+            max_concurrent_restores: 1,
+            enable_state_kv_shard_metrics: false,
+            verify_buffered_state_on_startup: false,
+            usage_mismatch_policy: UsageMismatchPolicy::default(),
+            max_state_values_per_restore_batch: DEFAULT_MAX_STATE_VALUES_PER_RESTORE_BATCH,
+            degrade_on_state_merkle_errors: false,
+            error_on_pruned_state_value_reads: false,
+            async_commit_channel_full_policy: AsyncCommitChannelFullPolicy::default(),
+            pin_framework_resources: false,
         }
     }
 }
 
 impl StorageConfig {
+    pub fn snapshot_trigger_policy(&self) -> SnapshotTriggerPolicy {
+        self.snapshot_trigger_policy
+            .unwrap_or(SnapshotTriggerPolicy::ByItems(
+                self.buffered_state_target_items,
+            ))
+    }
+
     pub fn dir(&self) -> PathBuf {
         if self.dir.is_relative() {
             self.data_dir.join(&self.dir)
@@ -279,17 +412,39 @@ impl StorageConfig {
 impl ConfigSanitizer for StorageConfig {
     /// Validate and process the storage config according to the given node role and chain ID
     fn sanitize(
-        _node_config: &mut NodeConfig,
+        node_config: &mut NodeConfig,
         _node_role: RoleType,
         _chain_id: ChainId,
     ) -> Result<(), Error> {
+        let sanitizer_name = Self::get_sanitizer_name();
+
+        // Verify that state values can be committed during a restore
+        if node_config.storage.max_state_values_per_restore_batch == 0 {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "max_state_values_per_restore_batch must be > 0".to_string(),
+            ));
+        }
+
+        // Verify that state snapshots can be restored
+        if node_config.storage.max_concurrent_restores == 0 {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "max_concurrent_restores must be > 0".to_string(),
+            ));
+        }
+
         Ok(()) // TODO: add validation of higher-level properties once we have variable configs
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::config::PrunerConfig;
+    use crate::config::{
+        config_sanitizer::ConfigSanitizer, Error, NodeConfig, PrunerConfig, RoleType,
+        SnapshotTriggerPolicy, StorageConfig, BUFFERED_STATE_TARGET_ITEMS,
+    };
+    use aptos_types::chain_id::ChainId;
 
     #[test]
     pub fn test_default_prune_window() {
@@ -300,4 +455,67 @@ mod test {
         assert!(config.state_merkle_pruner_config.prune_window >= 100_000);
         assert!(config.epoch_snapshot_pruner_config.prune_window > 50_000_000);
     }
+
+    #[test]
+    pub fn test_snapshot_trigger_policy() {
+        // Without an override, snapshots are triggered by the number of items as before
+        let mut config = StorageConfig::default();
+        assert_eq!(
+            config.snapshot_trigger_policy(),
+            SnapshotTriggerPolicy::ByItems(BUFFERED_STATE_TARGET_ITEMS)
+        );
+        assert_eq!(
+            config.snapshot_trigger_policy(),
+            SnapshotTriggerPolicy::default()
+        );
+        config.buffered_state_target_items = 10;
+        assert_eq!(
+            config.snapshot_trigger_policy(),
+            SnapshotTriggerPolicy::ByItems(10)
+        );
+
+        // The override takes precedence
+        let policy = SnapshotTriggerPolicy::Either {
+            items: 20,
+            interval_ms: 10_000,
+        };
+        config.snapshot_trigger_policy = Some(policy);
+        assert_eq!(config.snapshot_trigger_policy(), policy);
+    }
+
+    #[test]
+    fn test_sanitize_invalid_max_state_values_per_restore_batch() {
+        // Create a node config that can't commit any state value during a restore
+        let mut node_config = NodeConfig {
+            storage: StorageConfig {
+                max_state_values_per_restore_batch: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails
+        let error =
+            StorageConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_sanitize_invalid_max_concurrent_restores() {
+        // Create a node config that can't run any restore
+        let mut node_config = NodeConfig {
+            storage: StorageConfig {
+                max_concurrent_restores: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails
+        let error =
+            StorageConfig::sanitize(&mut node_config, RoleType::Validator, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
 }
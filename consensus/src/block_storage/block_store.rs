@@ -509,6 +509,18 @@ impl BlockStore {
         ordered_round > self.vote_back_pressure_limit + commit_round
     }
 
+    /// Block time between the commit root and the ordered root, i.e., how far the execution
+    /// pipeline lags behind ordering.
+    pub fn pipeline_latency(&self) -> Duration {
+        let ordered_timestamp = Duration::from_micros(self.ordered_root().timestamp_usecs());
+        let committed_timestamp = Duration::from_micros(self.commit_root().timestamp_usecs());
+        if committed_timestamp.is_zero() {
+            // latency not known without non-genesis blocks
+            return Duration::ZERO;
+        }
+        ordered_timestamp.saturating_sub(committed_timestamp)
+    }
+
     pub fn pipeline_pending_latency(&self, proposal_timestamp: Duration) -> Duration {
         let ordered_round = self.ordered_root().round();
         let commit_round = self.commit_root().round();
@@ -72,6 +72,16 @@ pub static COMMITTED_TXNS_COUNT: Lazy<IntCounterVec> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Count of the received proposals rejected for exceeding the receiving limits, by reason.
+pub static BLOCK_REJECTED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_consensus_block_rejected",
+        "Count of the received proposals rejected for exceeding the receiving limits, by reason",
+        &["reason"]
+    )
+    .unwrap()
+});
+
 //////////////////////
 // PROPOSAL ELECTION
 //////////////////////
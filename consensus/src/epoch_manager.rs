@@ -729,14 +729,13 @@ impl EpochManager {
             Arc::new(payload_client),
             self.time_service.clone(),
             Duration::from_millis(self.config.quorum_store_poll_time_ms),
-            self.config
-                .max_sending_block_txns(self.quorum_store_enabled),
-            self.config
-                .max_sending_block_bytes(self.quorum_store_enabled),
+            self.config.max_sending_block_txns_effective(),
+            self.config.max_sending_block_bytes_effective(),
             onchain_consensus_config.max_failed_authors_to_store(),
             pipeline_backpressure_config,
             chain_health_backoff_config,
             self.quorum_store_enabled,
+            self.config.recent_fill_window_blocks,
         );
 
         let (round_manager_tx, round_manager_rx) = aptos_channel::new(
@@ -808,6 +807,7 @@ impl EpochManager {
                 let consensus_config = onchain_consensus_config.unwrap_or_default();
                 let execution_config = onchain_execution_config.unwrap_or_default();
                 self.quorum_store_enabled = self.enable_quorum_store(&consensus_config);
+                self.config.quorum_store_enabled = self.quorum_store_enabled;
                 self.recovery_mode = false;
                 self.start_round_manager(
                     initial_data,
@@ -167,6 +167,8 @@ pub struct ProposalGenerator {
     // Last round that a proposal was generated
     last_round_generated: Round,
     quorum_store_enabled: bool,
+    // Number of the most recent pending blocks the fill fraction is computed over
+    recent_fill_window_blocks: usize,
 }
 
 impl ProposalGenerator {
@@ -182,6 +184,7 @@ impl ProposalGenerator {
         pipeline_backpressure_config: PipelineBackpressureConfig,
         chain_health_backoff_config: ChainHealthBackoffConfig,
         quorum_store_enabled: bool,
+        recent_fill_window_blocks: usize,
     ) -> Self {
         Self {
             author,
@@ -196,6 +199,7 @@ impl ProposalGenerator {
             chain_health_backoff_config,
             last_round_generated: 0,
             quorum_store_enabled,
+            recent_fill_window_blocks,
         }
     }
 
@@ -203,6 +207,10 @@ impl ProposalGenerator {
         self.author
     }
 
+    pub fn chain_health_backoff_config(&self) -> &ChainHealthBackoffConfig {
+        &self.chain_health_backoff_config
+    }
+
     /// Creates a NIL block proposal extending the highest certified block from the block store.
     pub fn generate_nil_block(
         &self,
@@ -292,12 +300,14 @@ impl ProposalGenerator {
                 tokio::time::sleep(backpressure_proposal_delay).await;
             }
 
-            let max_pending_block_len = pending_blocks
+            let recent_blocks =
+                recent_pending_blocks(&pending_blocks, self.recent_fill_window_blocks);
+            let max_pending_block_len = recent_blocks
                 .iter()
                 .map(|block| block.payload().map_or(0, |p| p.len()))
                 .max()
                 .unwrap_or(0);
-            let max_pending_block_bytes = pending_blocks
+            let max_pending_block_bytes = recent_blocks
                 .iter()
                 .map(|block| block.payload().map_or(0, |p| p.size()))
                 .max()
@@ -435,3 +445,16 @@ impl ProposalGenerator {
         failed_authors
     }
 }
+
+/// Returns the `window` most recent of the pending blocks, from the newest to the oldest. The
+/// pending blocks are ordered from the oldest to the newest, followed by the commit root, which is
+/// older than all of them.
+fn recent_pending_blocks<T>(pending_blocks: &[T], window: usize) -> Vec<&T> {
+    pending_blocks
+        .iter()
+        .rev()
+        .skip(1)
+        .chain(pending_blocks.last())
+        .take(window)
+        .collect()
+}
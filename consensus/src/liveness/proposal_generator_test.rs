@@ -6,7 +6,8 @@ use crate::{
     block_storage::BlockReader,
     liveness::{
         proposal_generator::{
-            ChainHealthBackoffConfig, PipelineBackpressureConfig, ProposalGenerator,
+            recent_pending_blocks, ChainHealthBackoffConfig, PipelineBackpressureConfig,
+            ProposalGenerator,
         },
         rotating_proposer_election::RotatingProposer,
         unequivocal_proposer_election::UnequivocalProposerElection,
@@ -42,6 +43,7 @@ async fn test_proposal_generation_empty_tree() {
         PipelineBackpressureConfig::new_no_backoff(),
         ChainHealthBackoffConfig::new_no_backoff(),
         false,
+        100,
     );
     let mut proposer_election =
         UnequivocalProposerElection::new(Box::new(RotatingProposer::new(vec![signer.author()], 1)));
@@ -82,6 +84,7 @@ async fn test_proposal_generation_parent() {
         PipelineBackpressureConfig::new_no_backoff(),
         ChainHealthBackoffConfig::new_no_backoff(),
         false,
+        100,
     );
     let mut proposer_election = UnequivocalProposerElection::new(Box::new(RotatingProposer::new(
         vec![inserter.signer().author()],
@@ -154,6 +157,7 @@ async fn test_old_proposal_generation() {
         PipelineBackpressureConfig::new_no_backoff(),
         ChainHealthBackoffConfig::new_no_backoff(),
         false,
+        100,
     );
     let mut proposer_election = UnequivocalProposerElection::new(Box::new(RotatingProposer::new(
         vec![inserter.signer().author()],
@@ -191,6 +195,7 @@ async fn test_correct_failed_authors() {
         PipelineBackpressureConfig::new_no_backoff(),
         ChainHealthBackoffConfig::new_no_backoff(),
         false,
+        100,
     );
     let mut proposer_election = UnequivocalProposerElection::new(Box::new(RotatingProposer::new(
         vec![author, peer1, peer2],
@@ -213,3 +218,27 @@ async fn test_correct_failed_authors() {
     assert_eq!(result.failed_authors().unwrap()[3], (4, peer1));
     assert_eq!(result.failed_authors().unwrap()[4], (5, peer2));
 }
+
+#[test]
+fn test_recent_pending_blocks_window() {
+    // The payload lengths of the pending blocks from the oldest to the newest, followed by the
+    // commit root.
+    let pending_blocks = [100, 1, 2, 50];
+    let max_len = |window| {
+        recent_pending_blocks(&pending_blocks, window)
+            .into_iter()
+            .max()
+            .copied()
+            .unwrap_or(0)
+    };
+
+    assert!(recent_pending_blocks(&pending_blocks, 0).is_empty());
+    assert_eq!(recent_pending_blocks(&pending_blocks, 2), [&2, &1]);
+    // The oldest pending block only counts once the window reaches it.
+    assert_eq!(max_len(2), 2);
+    assert_eq!(max_len(3), 100);
+    // The commit root is the last block in the window.
+    let all_blocks = recent_pending_blocks(&pending_blocks, 4);
+    assert_eq!(all_blocks, [&2, &1, &100, &50]);
+    assert_eq!(recent_pending_blocks(&pending_blocks, 5), all_blocks);
+}
@@ -161,6 +161,8 @@ pub struct RoundState {
     vote_sent: Option<Vote>,
     // The handle to cancel previous timeout task when moving to next round.
     abort_handle: Option<AbortHandle>,
+    // Scales the round duration while chain health is poor, set by the caller for each new round.
+    chain_health_timeout_multiplier: f64,
 }
 
 #[derive(Default, Schema)]
@@ -206,9 +208,15 @@ impl RoundState {
             pending_votes: PendingVotes::new(),
             vote_sent: None,
             abort_handle: None,
+            chain_health_timeout_multiplier: 1.0,
         }
     }
 
+    /// Sets the multiplier applied to the duration of the rounds started from now on.
+    pub fn set_chain_health_timeout_multiplier(&mut self, multiplier: f64) {
+        self.chain_health_timeout_multiplier = multiplier;
+    }
+
     /// Return if already voted for timeout
     pub fn is_vote_timeout(&self) -> bool {
         self.vote_sent.as_ref().map_or(false, |v| v.is_timeout())
@@ -335,6 +343,7 @@ impl RoundState {
         let timeout = self
             .time_interval
             .get_round_duration(round_index_after_committed_round)
+            .mul_f64(self.chain_health_timeout_multiplier)
             * multiplier;
         let now = self.time_service.get_current_timestamp();
         debug!(
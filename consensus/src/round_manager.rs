@@ -56,6 +56,7 @@ use std::{
     sync::Arc,
     time::Duration,
 };
+use thiserror::Error;
 use tokio::{
     sync::oneshot as TokioOneshot,
     time::{sleep, Instant},
@@ -180,6 +181,24 @@ pub enum VerifiedEvent {
     Shutdown(TokioOneshot::Sender<()>),
 }
 
+/// Why a received proposal was rejected before voting.
+#[derive(Debug, Error)]
+pub enum BlockRejectReason {
+    #[error("Payload len {len} exceeds the limit {limit}")]
+    TooManyTxns { len: u64, limit: u64 },
+    #[error("Payload size {size} exceeds the limit {limit}")]
+    TooManyBytes { size: u64, limit: u64 },
+}
+
+impl BlockRejectReason {
+    pub fn as_label(&self) -> &'static str {
+        match self {
+            BlockRejectReason::TooManyTxns { .. } => "too_many_txns",
+            BlockRejectReason::TooManyBytes { .. } => "too_many_bytes",
+        }
+    }
+}
+
 #[cfg(test)]
 #[path = "round_manager_test.rs"]
 mod round_manager_test;
@@ -206,6 +225,8 @@ pub struct RoundManager {
     round_manager_tx:
         aptos_channel::Sender<(Author, Discriminant<VerifiedEvent>), (Author, VerifiedEvent)>,
     local_config: ConsensusConfig,
+    // Since when the pipeline latency has been above max_pipeline_latency_before_sync_ms.
+    pipeline_latency_exceeded_since: Option<Instant>,
 }
 
 impl RoundManager {
@@ -245,6 +266,7 @@ impl RoundManager {
             onchain_config,
             round_manager_tx,
             local_config,
+            pipeline_latency_exceeded_since: None,
         }
     }
 
@@ -540,9 +562,15 @@ impl RoundManager {
         Ok(())
     }
 
-    fn sync_only(&self) -> bool {
+    fn sync_only(&mut self) -> bool {
         if self.decoupled_execution() {
-            let sync_or_not = self.local_config.sync_only || self.block_store.vote_back_pressure();
+            // Evaluated first, so that the window keeps being tracked while the other conditions
+            // hold.
+            let pipeline_latency_exceeded =
+                self.pipeline_latency_exceeded(self.block_store.pipeline_latency(), Instant::now());
+            let sync_or_not = pipeline_latency_exceeded
+                || self.local_config.sync_only
+                || self.block_store.vote_back_pressure();
             counters::OP_COUNTERS
                 .gauge("sync_only")
                 .set(sync_or_not as i64);
@@ -553,6 +581,22 @@ impl RoundManager {
         }
     }
 
+    /// Whether the pipeline latency has stayed above max_pipeline_latency_before_sync_ms for at
+    /// least pipeline_latency_sync_window_ms, given the current pipeline latency.
+    fn pipeline_latency_exceeded(&mut self, pipeline_latency: Duration, now: Instant) -> bool {
+        let max_latency = match self.local_config.max_pipeline_latency_before_sync_ms {
+            Some(max_latency_ms) => Duration::from_millis(max_latency_ms),
+            None => return false,
+        };
+        if pipeline_latency <= max_latency {
+            self.pipeline_latency_exceeded_since = None;
+            return false;
+        }
+        let exceeded_since = *self.pipeline_latency_exceeded_since.get_or_insert(now);
+        now.saturating_duration_since(exceeded_since)
+            >= Duration::from_millis(self.local_config.pipeline_latency_sync_window_ms)
+    }
+
     /// The replica broadcasts a "timeout vote message", which includes the round signature, which
     /// can be aggregated to a TimeoutCertificate.
     /// The timeout vote message can be one of the following three options:
@@ -621,12 +665,39 @@ impl RoundManager {
     /// This function is called only after all the dependencies of the given QC have been retrieved.
     async fn process_certificates(&mut self) -> anyhow::Result<()> {
         let sync_info = self.block_store.sync_info();
+        let new_round = sync_info.highest_round() + 1;
+        if new_round > self.round_state.current_round() {
+            let multiplier = self.chain_health_timeout_multiplier(new_round);
+            self.round_state
+                .set_chain_health_timeout_multiplier(multiplier);
+        }
         if let Some(new_round_event) = self.round_state.process_certificates(sync_info) {
             self.process_new_round_event(new_round_event).await?;
         }
         Ok(())
     }
 
+    /// Checks the proposal's payload against the local max_receiving_block_* limits.
+    fn check_receiving_block_limits(&self, proposal: &Block) -> Result<(), BlockRejectReason> {
+        let payload_len = proposal.payload().map_or(0, |payload| payload.len()) as u64;
+        let max_txns = self.local_config.max_receiving_block_txns_effective();
+        if payload_len > max_txns {
+            return Err(BlockRejectReason::TooManyTxns {
+                len: payload_len,
+                limit: max_txns,
+            });
+        }
+        let payload_size = proposal.payload().map_or(0, |payload| payload.size()) as u64;
+        let max_bytes = self.local_config.max_receiving_block_bytes_effective();
+        if payload_size > max_bytes {
+            return Err(BlockRejectReason::TooManyBytes {
+                size: payload_size,
+                limit: max_bytes,
+            });
+        }
+        Ok(())
+    }
+
     /// This function processes a proposal for the current round:
     /// 1. Filter if it's proposed by valid proposer.
     /// 2. Execute and add it to a block store.
@@ -638,29 +709,12 @@ impl RoundManager {
             .author()
             .expect("Proposal should be verified having an author");
 
-        let payload_len = proposal.payload().map_or(0, |payload| payload.len());
-        let payload_size = proposal.payload().map_or(0, |payload| payload.size());
-        ensure!(
-            payload_len as u64
-                <= self
-                    .local_config
-                    .max_receiving_block_txns(self.onchain_config.quorum_store_enabled()),
-            "Payload len {} exceeds the limit {}",
-            payload_len,
-            self.local_config
-                .max_receiving_block_txns(self.onchain_config.quorum_store_enabled()),
-        );
-
-        ensure!(
-            payload_size as u64
-                <= self
-                    .local_config
-                    .max_receiving_block_bytes(self.onchain_config.quorum_store_enabled()),
-            "Payload size {} exceeds the limit {}",
-            payload_size,
-            self.local_config
-                .max_receiving_block_bytes(self.onchain_config.quorum_store_enabled()),
-        );
+        if let Err(reason) = self.check_receiving_block_limits(&proposal) {
+            counters::BLOCK_REJECTED
+                .with_label_values(&[reason.as_label()])
+                .inc();
+            bail!("[RoundManager] Rejecting proposal {}: {}", proposal, reason);
+        }
 
         ensure!(
             self.proposer_election.is_valid_proposal(&proposal),
@@ -931,11 +985,33 @@ impl RoundManager {
         result
     }
 
+    /// Returns the multiplier for the timeout of the given round, which lengthens the round if
+    /// the participating voting power is low enough to trigger a chain health backoff.
+    fn chain_health_timeout_multiplier(&self, round: Round) -> f64 {
+        let voting_power_ratio = self
+            .proposer_election
+            .get_voting_power_participation_ratio(round);
+        if self
+            .proposal_generator
+            .chain_health_backoff_config()
+            .get_backoff(voting_power_ratio)
+            .is_some()
+        {
+            self.local_config.chain_health_round_timeout_multiplier
+        } else {
+            1.0
+        }
+    }
+
     /// To jump start new round with the current certificates we have.
     pub async fn init(&mut self, last_vote_sent: Option<Vote>) {
+        let sync_info = self.block_store.sync_info();
+        let multiplier = self.chain_health_timeout_multiplier(sync_info.highest_round() + 1);
+        self.round_state
+            .set_chain_health_timeout_multiplier(multiplier);
         let new_round_event = self
             .round_state
-            .process_certificates(self.block_store.sync_info())
+            .process_certificates(sync_info)
             .expect("Can not jump start a round_state from existing certificates.");
         if let Some(vote) = last_vote_sent {
             self.round_state.record_vote(vote);
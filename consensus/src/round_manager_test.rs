@@ -19,7 +19,7 @@ use crate::{
     network_tests::{NetworkPlayground, TwinId},
     payload_manager::PayloadManager,
     persistent_liveness_storage::RecoveryData,
-    round_manager::RoundManager,
+    round_manager::{BlockRejectReason, RoundManager},
     test_utils::{
         consensus_runtime, timed_block_on, MockPayloadManager, MockStateComputer, MockStorage,
         TreeInserter,
@@ -44,7 +44,10 @@ use aptos_consensus_types::{
     timeout_2chain::{TwoChainTimeout, TwoChainTimeoutWithPartialSignatures},
     vote_msg::VoteMsg,
 };
-use aptos_crypto::HashValue;
+use aptos_crypto::{
+    ed25519::{Ed25519PrivateKey, Ed25519Signature},
+    HashValue, PrivateKey, Uniform,
+};
 use aptos_infallible::Mutex;
 use aptos_logger::prelude::info;
 use aptos_network::{
@@ -61,10 +64,12 @@ use aptos_network::{
 use aptos_safety_rules::{PersistentSafetyStorage, SafetyRulesManager};
 use aptos_secure_storage::Storage;
 use aptos_types::{
+    account_address::AccountAddress,
+    chain_id::ChainId,
     epoch_state::EpochState,
     ledger_info::LedgerInfo,
     on_chain_config::OnChainConsensusConfig,
-    transaction::SignedTransaction,
+    transaction::{RawTransaction, Script, SignedTransaction, TransactionPayload},
     validator_signer::ValidatorSigner,
     validator_verifier::{generate_validator_verifier, random_validator_verifier},
     waypoint::Waypoint,
@@ -87,7 +92,7 @@ use std::{
 use tokio::{
     runtime::{Handle, Runtime},
     task::JoinHandle,
-    time::timeout,
+    time::{timeout, Instant},
 };
 
 /// Auxiliary struct that is setting up node environment for the test.
@@ -252,6 +257,7 @@ impl NodeSetup {
             PipelineBackpressureConfig::new_no_backoff(),
             ChainHealthBackoffConfig::new_no_backoff(),
             false,
+            100,
         );
 
         let round_state = Self::create_round_state(time_service);
@@ -653,6 +659,121 @@ fn vote_on_successful_proposal() {
     });
 }
 
+#[test]
+/// If the proposal exceeds the receiving limits, it should be rejected without a vote
+fn no_vote_on_oversized_proposal() {
+    let runtime = consensus_runtime();
+    let mut playground = NetworkPlayground::new(runtime.handle().clone());
+    let mut nodes = NodeSetup::create_nodes(&mut playground, runtime.handle().clone(), 1, None);
+    let node = &mut nodes[0];
+
+    let genesis_qc = certificate_for_genesis();
+    let private_key = Ed25519PrivateKey::generate_for_testing();
+    let txns: Vec<_> = (0..2)
+        .map(|_| {
+            SignedTransaction::new(
+                RawTransaction::new(
+                    AccountAddress::random(),
+                    0,
+                    TransactionPayload::Script(Script::new(vec![], vec![], vec![])),
+                    0,
+                    0,
+                    0,
+                    ChainId::test(),
+                ),
+                private_key.public_key(),
+                Ed25519Signature::dummy_signature(),
+            )
+        })
+        .collect();
+    let proposal = Block::new_proposal(
+        Payload::DirectMempool(txns),
+        1,
+        1,
+        genesis_qc,
+        &node.signer,
+        Vec::new(),
+    )
+    .unwrap();
+
+    // The proposal has too many transactions
+    node.round_manager.local_config.max_receiving_block_txns = 1;
+    assert!(matches!(
+        node.round_manager.check_receiving_block_limits(&proposal),
+        Err(BlockRejectReason::TooManyTxns { len: 2, limit: 1 })
+    ));
+
+    // The proposal has too many bytes
+    node.round_manager.local_config.max_receiving_block_txns = 2;
+    node.round_manager.local_config.max_receiving_block_bytes = 1;
+    assert!(matches!(
+        node.round_manager.check_receiving_block_limits(&proposal),
+        Err(BlockRejectReason::TooManyBytes { limit: 1, .. })
+    ));
+
+    timed_block_on(&runtime, async {
+        // Start round 1 and clear the message queue
+        node.next_proposal().await;
+
+        // The proposal is rejected, and no vote is sent
+        assert!(node.round_manager.process_proposal(proposal).await.is_err());
+        node.no_next_msg();
+        assert_eq!(node.round_manager.consensus_state().last_voted_round(), 0);
+    });
+}
+
+#[test]
+/// The node only stops voting once the pipeline latency stays above the limit for the whole window
+fn pipeline_latency_exceeded_after_window() {
+    let runtime = consensus_runtime();
+    let mut playground = NetworkPlayground::new(runtime.handle().clone());
+    let mut nodes = NodeSetup::create_nodes(&mut playground, runtime.handle().clone(), 1, None);
+    let round_manager = &mut nodes[0].round_manager;
+
+    let max_latency = Duration::from_millis(10_000);
+    let window = Duration::from_millis(round_manager.local_config.pipeline_latency_sync_window_ms);
+    let high_latency = max_latency + Duration::from_millis(1);
+    let start = Instant::now();
+
+    // Disabled by default
+    assert!(!round_manager.pipeline_latency_exceeded(high_latency, start + window));
+
+    round_manager
+        .local_config
+        .max_pipeline_latency_before_sync_ms = Some(max_latency.as_millis() as u64);
+    assert!(!round_manager.pipeline_latency_exceeded(max_latency, start));
+
+    // The latency has to stay above the limit for the whole window
+    assert!(!round_manager.pipeline_latency_exceeded(high_latency, start));
+    assert!(!round_manager
+        .pipeline_latency_exceeded(high_latency, start + window - Duration::from_millis(1)));
+    assert!(round_manager.pipeline_latency_exceeded(high_latency, start + window));
+
+    // Dropping below the limit restarts the window
+    assert!(!round_manager.pipeline_latency_exceeded(max_latency, start + window));
+    assert!(!round_manager.pipeline_latency_exceeded(high_latency, start + window));
+    assert!(round_manager.pipeline_latency_exceeded(high_latency, start + window * 2));
+}
+
+#[test]
+/// The pipeline latency window is tracked even while the node stops voting for other reasons
+fn pipeline_latency_tracked_in_sync_only() {
+    let runtime = consensus_runtime();
+    let mut playground = NetworkPlayground::new(runtime.handle().clone());
+    let mut nodes = NodeSetup::create_nodes(&mut playground, runtime.handle().clone(), 1, None);
+    let round_manager = &mut nodes[0].round_manager;
+
+    round_manager
+        .local_config
+        .max_pipeline_latency_before_sync_ms = Some(10_000);
+    round_manager.local_config.sync_only = true;
+    round_manager.pipeline_latency_exceeded_since = Some(Instant::now());
+
+    // The pipeline latency is below the limit, so the window is reset
+    assert!(round_manager.sync_only());
+    assert!(round_manager.pipeline_latency_exceeded_since.is_none());
+}
+
 #[test]
 /// In back pressure mode, verify that the proposals are processed after we get out of back pressure.
 fn delay_proposal_processing_in_sync_only() {
@@ -1,6 +1,190 @@
 // Copyright © Aptos Foundation
 
 // @generated
+impl serde::Serialize for AckDatastreamRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.stream_id.is_empty() {
+            len += 1;
+        }
+        if self.version != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aptos.datastream.v1.AckDatastreamRequest", len)?;
+        if !self.stream_id.is_empty() {
+            struct_ser.serialize_field("streamId", &self.stream_id)?;
+        }
+        if self.version != 0 {
+            struct_ser.serialize_field("version", ToString::to_string(&self.version).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AckDatastreamRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "streamId",
+            "version",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StreamId,
+            Version,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "streamId" => Ok(GeneratedField::StreamId),
+                            "version" => Ok(GeneratedField::Version),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AckDatastreamRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aptos.datastream.v1.AckDatastreamRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<AckDatastreamRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut stream_id__ = None;
+                let mut version__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::StreamId => {
+                            if stream_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("streamId"));
+                            }
+                            stream_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(
+                                map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
+                            );
+                        }
+                    }
+                }
+                Ok(AckDatastreamRequest {
+                    stream_id: stream_id__.unwrap_or_default(),
+                    version: version__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aptos.datastream.v1.AckDatastreamRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AckDatastreamResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        let mut struct_ser = serializer.serialize_struct("aptos.datastream.v1.AckDatastreamResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AckDatastreamResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AckDatastreamResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aptos.datastream.v1.AckDatastreamResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<AckDatastreamResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while let Some(k) = map.next_key()? {
+                    match k {
+                    }
+                }
+                Ok(AckDatastreamResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("aptos.datastream.v1.AckDatastreamResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for RawDatastreamRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -15,6 +199,33 @@ impl serde::Serialize for RawDatastreamRequest {
         if self.transactions_count.is_some() {
             len += 1;
         }
+        if self.json_output.is_some() {
+            len += 1;
+        }
+        if self.deadline_ms.is_some() {
+            len += 1;
+        }
+        if !self.transaction_types.is_empty() {
+            len += 1;
+        }
+        if self.replay_end_version.is_some() {
+            len += 1;
+        }
+        if self.max_tps.is_some() {
+            len += 1;
+        }
+        if self.delivery_semantics != 0 {
+            len += 1;
+        }
+        if self.resume_stream_id.is_some() {
+            len += 1;
+        }
+        if self.ending_version.is_some() {
+            len += 1;
+        }
+        if self.include_source.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("aptos.datastream.v1.RawDatastreamRequest", len)?;
         if let Some(v) = self.starting_version.as_ref() {
             struct_ser.serialize_field("startingVersion", ToString::to_string(&v).as_str())?;
@@ -22,6 +233,35 @@ impl serde::Serialize for RawDatastreamRequest {
         if let Some(v) = self.transactions_count.as_ref() {
             struct_ser.serialize_field("transactionsCount", ToString::to_string(&v).as_str())?;
         }
+        if let Some(v) = self.json_output.as_ref() {
+            struct_ser.serialize_field("jsonOutput", v)?;
+        }
+        if let Some(v) = self.deadline_ms.as_ref() {
+            struct_ser.serialize_field("deadlineMs", ToString::to_string(&v).as_str())?;
+        }
+        if !self.transaction_types.is_empty() {
+            struct_ser.serialize_field("transactionTypes", &self.transaction_types)?;
+        }
+        if let Some(v) = self.replay_end_version.as_ref() {
+            struct_ser.serialize_field("replayEndVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.max_tps.as_ref() {
+            struct_ser.serialize_field("maxTps", ToString::to_string(&v).as_str())?;
+        }
+        if self.delivery_semantics != 0 {
+            let v = raw_datastream_request::DeliverySemantics::from_i32(self.delivery_semantics)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.delivery_semantics)))?;
+            struct_ser.serialize_field("deliverySemantics", &v)?;
+        }
+        if let Some(v) = self.resume_stream_id.as_ref() {
+            struct_ser.serialize_field("resumeStreamId", v)?;
+        }
+        if let Some(v) = self.ending_version.as_ref() {
+            struct_ser.serialize_field("endingVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.include_source.as_ref() {
+            struct_ser.serialize_field("includeSource", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -34,12 +274,30 @@ impl<'de> serde::Deserialize<'de> for RawDatastreamRequest {
         const FIELDS: &[&str] = &[
             "startingVersion",
             "transactionsCount",
+            "jsonOutput",
+            "deadlineMs",
+            "transactionTypes",
+            "replayEndVersion",
+            "maxTps",
+            "deliverySemantics",
+            "resumeStreamId",
+            "endingVersion",
+            "includeSource",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             StartingVersion,
             TransactionsCount,
+            JsonOutput,
+            DeadlineMs,
+            TransactionTypes,
+            ReplayEndVersion,
+            MaxTps,
+            DeliverySemantics,
+            ResumeStreamId,
+            EndingVersion,
+            IncludeSource,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -63,6 +321,15 @@ impl<'de> serde::Deserialize<'de> for RawDatastreamRequest {
                         match value {
                             "startingVersion" => Ok(GeneratedField::StartingVersion),
                             "transactionsCount" => Ok(GeneratedField::TransactionsCount),
+                            "jsonOutput" => Ok(GeneratedField::JsonOutput),
+                            "deadlineMs" => Ok(GeneratedField::DeadlineMs),
+                            "transactionTypes" => Ok(GeneratedField::TransactionTypes),
+                            "replayEndVersion" => Ok(GeneratedField::ReplayEndVersion),
+                            "maxTps" => Ok(GeneratedField::MaxTps),
+                            "deliverySemantics" => Ok(GeneratedField::DeliverySemantics),
+                            "resumeStreamId" => Ok(GeneratedField::ResumeStreamId),
+                            "endingVersion" => Ok(GeneratedField::EndingVersion),
+                            "includeSource" => Ok(GeneratedField::IncludeSource),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -84,6 +351,15 @@ impl<'de> serde::Deserialize<'de> for RawDatastreamRequest {
             {
                 let mut starting_version__ = None;
                 let mut transactions_count__ = None;
+                let mut json_output__ = None;
+                let mut deadline_ms__ = None;
+                let mut transaction_types__ = None;
+                let mut replay_end_version__ = None;
+                let mut max_tps__ = None;
+                let mut delivery_semantics__ = None;
+                let mut resume_stream_id__ = None;
+                let mut ending_version__ = None;
+                let mut include_source__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::StartingVersion => {
@@ -102,17 +378,167 @@ impl<'de> serde::Deserialize<'de> for RawDatastreamRequest {
                                 map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
                             );
                         }
+                        GeneratedField::JsonOutput => {
+                            if json_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jsonOutput"));
+                            }
+                            json_output__ = map.next_value()?;
+                        }
+                        GeneratedField::DeadlineMs => {
+                            if deadline_ms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deadlineMs"));
+                            }
+                            deadline_ms__ = Some(
+                                map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
+                            );
+                        }
+                        GeneratedField::TransactionTypes => {
+                            if transaction_types__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionTypes"));
+                            }
+                            transaction_types__ = Some(
+                                map.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect()
+                            );
+                        }
+                        GeneratedField::ReplayEndVersion => {
+                            if replay_end_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("replayEndVersion"));
+                            }
+                            replay_end_version__ = Some(
+                                map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
+                            );
+                        }
+                        GeneratedField::MaxTps => {
+                            if max_tps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxTps"));
+                            }
+                            max_tps__ = Some(
+                                map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
+                            );
+                        }
+                        GeneratedField::DeliverySemantics => {
+                            if delivery_semantics__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deliverySemantics"));
+                            }
+                            delivery_semantics__ = Some(map.next_value::<raw_datastream_request::DeliverySemantics>()? as i32);
+                        }
+                        GeneratedField::ResumeStreamId => {
+                            if resume_stream_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resumeStreamId"));
+                            }
+                            resume_stream_id__ = map.next_value()?;
+                        }
+                        GeneratedField::EndingVersion => {
+                            if ending_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endingVersion"));
+                            }
+                            ending_version__ = Some(
+                                map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
+                            );
+                        }
+                        GeneratedField::IncludeSource => {
+                            if include_source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeSource"));
+                            }
+                            include_source__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(RawDatastreamRequest {
                     starting_version: starting_version__,
                     transactions_count: transactions_count__,
+                    json_output: json_output__,
+                    deadline_ms: deadline_ms__,
+                    transaction_types: transaction_types__.unwrap_or_default(),
+                    replay_end_version: replay_end_version__,
+                    max_tps: max_tps__,
+                    delivery_semantics: delivery_semantics__.unwrap_or_default(),
+                    resume_stream_id: resume_stream_id__,
+                    ending_version: ending_version__,
+                    include_source: include_source__,
                 })
             }
         }
         deserializer.deserialize_struct("aptos.datastream.v1.RawDatastreamRequest", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for raw_datastream_request::DeliverySemantics {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "DELIVERY_SEMANTICS_UNSPECIFIED",
+            Self::AtMostOnce => "DELIVERY_SEMANTICS_AT_MOST_ONCE",
+            Self::AtLeastOnce => "DELIVERY_SEMANTICS_AT_LEAST_ONCE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for raw_datastream_request::DeliverySemantics {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "DELIVERY_SEMANTICS_UNSPECIFIED",
+            "DELIVERY_SEMANTICS_AT_MOST_ONCE",
+            "DELIVERY_SEMANTICS_AT_LEAST_ONCE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = raw_datastream_request::DeliverySemantics;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(raw_datastream_request::DeliverySemantics::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(raw_datastream_request::DeliverySemantics::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "DELIVERY_SEMANTICS_UNSPECIFIED" => Ok(raw_datastream_request::DeliverySemantics::Unspecified),
+                    "DELIVERY_SEMANTICS_AT_MOST_ONCE" => Ok(raw_datastream_request::DeliverySemantics::AtMostOnce),
+                    "DELIVERY_SEMANTICS_AT_LEAST_ONCE" => Ok(raw_datastream_request::DeliverySemantics::AtLeastOnce),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for RawDatastreamResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -256,6 +682,30 @@ impl serde::Serialize for StreamStatus {
         if self.end_version.is_some() {
             len += 1;
         }
+        if self.json_output {
+            len += 1;
+        }
+        if self.file_store_min_version.is_some() {
+            len += 1;
+        }
+        if self.file_store_max_version.is_some() {
+            len += 1;
+        }
+        if self.stream_id.is_some() {
+            len += 1;
+        }
+        if self.total_transactions.is_some() {
+            len += 1;
+        }
+        if self.total_bytes.is_some() {
+            len += 1;
+        }
+        if self.processed_version.is_some() {
+            len += 1;
+        }
+        if self.cache_latest_version.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("aptos.datastream.v1.StreamStatus", len)?;
         if self.r#type != 0 {
             let v = stream_status::StatusType::from_i32(self.r#type)
@@ -268,6 +718,30 @@ impl serde::Serialize for StreamStatus {
         if let Some(v) = self.end_version.as_ref() {
             struct_ser.serialize_field("endVersion", ToString::to_string(&v).as_str())?;
         }
+        if self.json_output {
+            struct_ser.serialize_field("jsonOutput", &self.json_output)?;
+        }
+        if let Some(v) = self.file_store_min_version.as_ref() {
+            struct_ser.serialize_field("fileStoreMinVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.file_store_max_version.as_ref() {
+            struct_ser.serialize_field("fileStoreMaxVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.stream_id.as_ref() {
+            struct_ser.serialize_field("streamId", v)?;
+        }
+        if let Some(v) = self.total_transactions.as_ref() {
+            struct_ser.serialize_field("totalTransactions", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.total_bytes.as_ref() {
+            struct_ser.serialize_field("totalBytes", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.processed_version.as_ref() {
+            struct_ser.serialize_field("processedVersion", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.cache_latest_version.as_ref() {
+            struct_ser.serialize_field("cacheLatestVersion", ToString::to_string(&v).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -281,6 +755,14 @@ impl<'de> serde::Deserialize<'de> for StreamStatus {
             "type",
             "startVersion",
             "endVersion",
+            "jsonOutput",
+            "fileStoreMinVersion",
+            "fileStoreMaxVersion",
+            "streamId",
+            "totalTransactions",
+            "totalBytes",
+            "processedVersion",
+            "cacheLatestVersion",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -288,6 +770,14 @@ impl<'de> serde::Deserialize<'de> for StreamStatus {
             Type,
             StartVersion,
             EndVersion,
+            JsonOutput,
+            FileStoreMinVersion,
+            FileStoreMaxVersion,
+            StreamId,
+            TotalTransactions,
+            TotalBytes,
+            ProcessedVersion,
+            CacheLatestVersion,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -312,6 +802,14 @@ impl<'de> serde::Deserialize<'de> for StreamStatus {
                             "type" => Ok(GeneratedField::Type),
                             "startVersion" => Ok(GeneratedField::StartVersion),
                             "endVersion" => Ok(GeneratedField::EndVersion),
+                            "jsonOutput" => Ok(GeneratedField::JsonOutput),
+                            "fileStoreMinVersion" => Ok(GeneratedField::FileStoreMinVersion),
+                            "fileStoreMaxVersion" => Ok(GeneratedField::FileStoreMaxVersion),
+                            "streamId" => Ok(GeneratedField::StreamId),
+                            "totalTransactions" => Ok(GeneratedField::TotalTransactions),
+                            "totalBytes" => Ok(GeneratedField::TotalBytes),
+                            "processedVersion" => Ok(GeneratedField::ProcessedVersion),
+                            "cacheLatestVersion" => Ok(GeneratedField::CacheLatestVersion),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -334,6 +832,14 @@ impl<'de> serde::Deserialize<'de> for StreamStatus {
                 let mut r#type__ = None;
                 let mut start_version__ = None;
                 let mut end_version__ = None;
+                let mut json_output__ = None;
+                let mut file_store_min_version__ = None;
+                let mut file_store_max_version__ = None;
+                let mut stream_id__ = None;
+                let mut total_transactions__ = None;
+                let mut total_bytes__ = None;
+                let mut processed_version__ = None;
+                let mut cache_latest_version__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Type => {
@@ -358,12 +864,80 @@ impl<'de> serde::Deserialize<'de> for StreamStatus {
                                 map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
                             );
                         }
+                        GeneratedField::JsonOutput => {
+                            if json_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jsonOutput"));
+                            }
+                            json_output__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::FileStoreMinVersion => {
+                            if file_store_min_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fileStoreMinVersion"));
+                            }
+                            file_store_min_version__ = Some(
+                                map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
+                            );
+                        }
+                        GeneratedField::FileStoreMaxVersion => {
+                            if file_store_max_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fileStoreMaxVersion"));
+                            }
+                            file_store_max_version__ = Some(
+                                map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
+                            );
+                        }
+                        GeneratedField::StreamId => {
+                            if stream_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("streamId"));
+                            }
+                            stream_id__ = map.next_value()?;
+                        }
+                        GeneratedField::TotalTransactions => {
+                            if total_transactions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalTransactions"));
+                            }
+                            total_transactions__ = Some(
+                                map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
+                            );
+                        }
+                        GeneratedField::TotalBytes => {
+                            if total_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalBytes"));
+                            }
+                            total_bytes__ = Some(
+                                map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
+                            );
+                        }
+                        GeneratedField::ProcessedVersion => {
+                            if processed_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("processedVersion"));
+                            }
+                            processed_version__ = Some(
+                                map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
+                            );
+                        }
+                        GeneratedField::CacheLatestVersion => {
+                            if cache_latest_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cacheLatestVersion"));
+                            }
+                            cache_latest_version__ = Some(
+                                map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0
+                            );
+                        }
                     }
                 }
                 Ok(StreamStatus {
                     r#type: r#type__.unwrap_or_default(),
                     start_version: start_version__.unwrap_or_default(),
                     end_version: end_version__,
+                    json_output: json_output__.unwrap_or_default(),
+                    file_store_min_version: file_store_min_version__,
+                    file_store_max_version: file_store_max_version__,
+                    stream_id: stream_id__,
+                    total_transactions: total_transactions__,
+                    total_bytes: total_bytes__,
+                    processed_version: processed_version__,
+                    cache_latest_version: cache_latest_version__,
                 })
             }
         }
@@ -380,6 +954,9 @@ impl serde::Serialize for stream_status::StatusType {
             Self::Unspecified => "STATUS_TYPE_UNSPECIFIED",
             Self::Init => "STATUS_TYPE_INIT",
             Self::BatchEnd => "STATUS_TYPE_BATCH_END",
+            Self::DeadlineExceeded => "STATUS_TYPE_DEADLINE_EXCEEDED",
+            Self::Summary => "STATUS_TYPE_SUMMARY",
+            Self::Progress => "STATUS_TYPE_PROGRESS",
         };
         serializer.serialize_str(variant)
     }
@@ -394,6 +971,9 @@ impl<'de> serde::Deserialize<'de> for stream_status::StatusType {
             "STATUS_TYPE_UNSPECIFIED",
             "STATUS_TYPE_INIT",
             "STATUS_TYPE_BATCH_END",
+            "STATUS_TYPE_DEADLINE_EXCEEDED",
+            "STATUS_TYPE_SUMMARY",
+            "STATUS_TYPE_PROGRESS",
         ];
 
         struct GeneratedVisitor;
@@ -439,6 +1019,9 @@ impl<'de> serde::Deserialize<'de> for stream_status::StatusType {
                     "STATUS_TYPE_UNSPECIFIED" => Ok(stream_status::StatusType::Unspecified),
                     "STATUS_TYPE_INIT" => Ok(stream_status::StatusType::Init),
                     "STATUS_TYPE_BATCH_END" => Ok(stream_status::StatusType::BatchEnd),
+                    "STATUS_TYPE_DEADLINE_EXCEEDED" => Ok(stream_status::StatusType::DeadlineExceeded),
+                    "STATUS_TYPE_SUMMARY" => Ok(stream_status::StatusType::Summary),
+                    "STATUS_TYPE_PROGRESS" => Ok(stream_status::StatusType::Progress),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -463,6 +1046,12 @@ impl serde::Serialize for TransactionOutput {
         if self.timestamp.is_some() {
             len += 1;
         }
+        if !self.json_data.is_empty() {
+            len += 1;
+        }
+        if self.source != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("aptos.datastream.v1.TransactionOutput", len)?;
         if !self.encoded_proto_data.is_empty() {
             struct_ser.serialize_field("encodedProtoData", &self.encoded_proto_data)?;
@@ -473,6 +1062,14 @@ impl serde::Serialize for TransactionOutput {
         if let Some(v) = self.timestamp.as_ref() {
             struct_ser.serialize_field("timestamp", v)?;
         }
+        if !self.json_data.is_empty() {
+            struct_ser.serialize_field("jsonData", &self.json_data)?;
+        }
+        if self.source != 0 {
+            let v = transaction_output::Source::from_i32(self.source)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.source)))?;
+            struct_ser.serialize_field("source", &v)?;
+        }
         struct_ser.end()
     }
 }
@@ -486,6 +1083,8 @@ impl<'de> serde::Deserialize<'de> for TransactionOutput {
             "encodedProtoData",
             "version",
             "timestamp",
+            "jsonData",
+            "source",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -493,6 +1092,8 @@ impl<'de> serde::Deserialize<'de> for TransactionOutput {
             EncodedProtoData,
             Version,
             Timestamp,
+            JsonData,
+            Source,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -517,6 +1118,8 @@ impl<'de> serde::Deserialize<'de> for TransactionOutput {
                             "encodedProtoData" => Ok(GeneratedField::EncodedProtoData),
                             "version" => Ok(GeneratedField::Version),
                             "timestamp" => Ok(GeneratedField::Timestamp),
+                            "jsonData" => Ok(GeneratedField::JsonData),
+                            "source" => Ok(GeneratedField::Source),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -539,6 +1142,8 @@ impl<'de> serde::Deserialize<'de> for TransactionOutput {
                 let mut encoded_proto_data__ = None;
                 let mut version__ = None;
                 let mut timestamp__ = None;
+                let mut json_data__ = None;
+                let mut source__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::EncodedProtoData => {
@@ -561,18 +1166,108 @@ impl<'de> serde::Deserialize<'de> for TransactionOutput {
                             }
                             timestamp__ = Some(map.next_value()?);
                         }
+                        GeneratedField::JsonData => {
+                            if json_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("jsonData"));
+                            }
+                            json_data__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = Some(map.next_value::<transaction_output::Source>()? as i32);
+                        }
                     }
                 }
                 Ok(TransactionOutput {
                     encoded_proto_data: encoded_proto_data__.unwrap_or_default(),
                     version: version__.unwrap_or_default(),
                     timestamp: timestamp__,
+                    json_data: json_data__.unwrap_or_default(),
+                    source: source__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("aptos.datastream.v1.TransactionOutput", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for transaction_output::Source {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SOURCE_UNSPECIFIED",
+            Self::Cache => "SOURCE_CACHE",
+            Self::FileStore => "SOURCE_FILE_STORE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_output::Source {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SOURCE_UNSPECIFIED",
+            "SOURCE_CACHE",
+            "SOURCE_FILE_STORE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_output::Source;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(transaction_output::Source::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(transaction_output::Source::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SOURCE_UNSPECIFIED" => Ok(transaction_output::Source::Unspecified),
+                    "SOURCE_CACHE" => Ok(transaction_output::Source::Cache),
+                    "SOURCE_FILE_STORE" => Ok(transaction_output::Source::FileStore),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for TransactionsOutput {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
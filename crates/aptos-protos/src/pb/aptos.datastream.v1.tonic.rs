@@ -94,6 +94,26 @@ pub mod indexer_stream_client {
             );
             self.inner.server_streaming(request.into_request(), path, codec).await
         }
+        ///
+        pub async fn ack_datastream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AckDatastreamRequest>,
+        ) -> Result<tonic::Response<super::AckDatastreamResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/aptos.datastream.v1.IndexerStream/AckDatastream",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -114,6 +134,11 @@ pub mod indexer_stream_server {
             &self,
             request: tonic::Request<super::RawDatastreamRequest>,
         ) -> Result<tonic::Response<Self::RawDatastreamStream>, tonic::Status>;
+        ///
+        async fn ack_datastream(
+            &self,
+            request: tonic::Request<super::AckDatastreamRequest>,
+        ) -> Result<tonic::Response<super::AckDatastreamResponse>, tonic::Status>;
     }
     ///
     #[derive(Debug)]
@@ -216,6 +241,46 @@ pub mod indexer_stream_server {
                     };
                     Box::pin(fut)
                 }
+                "/aptos.datastream.v1.IndexerStream/AckDatastream" => {
+                    #[allow(non_camel_case_types)]
+                    struct AckDatastreamSvc<T: IndexerStream>(pub Arc<T>);
+                    impl<
+                        T: IndexerStream,
+                    > tonic::server::UnaryService<super::AckDatastreamRequest>
+                    for AckDatastreamSvc<T> {
+                        type Response = super::AckDatastreamResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AckDatastreamRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).ack_datastream(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AckDatastreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
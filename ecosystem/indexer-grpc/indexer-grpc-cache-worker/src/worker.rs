@@ -141,6 +141,9 @@ async fn process_raw_datastream_response(
                         num_of_transactions,
                     })
                 },
+                StatusType::DeadlineExceeded | StatusType::Summary | StatusType::Progress => {
+                    unreachable!("Fullnode doesn't send data service status types.")
+                },
                 StatusType::Unspecified => unreachable!("Unspecified status type."),
             }
         },
@@ -1,8 +1,9 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::{bail, Context};
 use aptos_indexer_grpc_data_service::service::DatastreamServer;
-use aptos_indexer_grpc_utils::register_probes_and_metrics_handler;
+use aptos_indexer_grpc_utils::{config::IndexerGrpcConfig, register_probes_and_metrics_handler};
 use aptos_protos::{
     datastream::v1::{
         indexer_stream_server::IndexerStreamServer,
@@ -22,7 +23,7 @@ use std::{
 };
 use tonic::{
     metadata::{Ascii, MetadataValue},
-    transport::Server,
+    transport::{Certificate, Identity, Server, ServerTlsConfig},
     Request, Status,
 };
 
@@ -32,7 +33,7 @@ pub struct Args {
     pub config_path: String,
 }
 
-fn main() {
+fn main() -> anyhow::Result<()> {
     aptos_logger::Logger::new().init();
     aptos_crash_handler::setup_panic_handler();
 
@@ -40,14 +41,19 @@ fn main() {
     let args = Args::parse();
     let config = aptos_indexer_grpc_utils::config::IndexerGrpcConfig::load(
         std::path::PathBuf::from(args.config_path),
-    )
-    .unwrap();
+    )?;
 
     let grpc_address = config
         .data_service_grpc_listen_address
         .clone()
         .expect("grpc_address not set");
     let health_port = config.health_check_port;
+    let mut server_builder = Server::builder();
+    if let Some(tls_config) = build_tls_config(&config)? {
+        server_builder = server_builder
+            .tls_config(tls_config)
+            .context("Failed to set up TLS")?;
+    }
 
     let token_set = build_auth_token_set(config.whitelisted_auth_tokens.clone());
     let authentication_inceptor = move |req: Request<()>| {
@@ -82,7 +88,7 @@ fn main() {
     runtime.spawn(async move {
         let server = DatastreamServer::new(config);
         let svc = IndexerStreamServer::with_interceptor(server, authentication_inceptor);
-        Server::builder()
+        server_builder
             .add_service(reflection_service)
             .add_service(svc)
             .serve(grpc_address.to_socket_addrs().unwrap().next().unwrap())
@@ -99,6 +105,37 @@ fn main() {
     while !term.load(Ordering::Acquire) {
         std::thread::park();
     }
+    Ok(())
+}
+
+/// Build the TLS config of the server if TLS is enabled. If the client CA is set, clients are
+/// required to present a certificate signed by it, i.e., mutual TLS.
+pub fn build_tls_config(config: &IndexerGrpcConfig) -> anyhow::Result<Option<ServerTlsConfig>> {
+    let (cert_path, key_path) = match (
+        &config.data_service_tls_cert_path,
+        &config.data_service_tls_key_path,
+    ) {
+        (Some(cert_path), Some(key_path)) => (cert_path, key_path),
+        (None, None) => {
+            if config.data_service_tls_client_ca_path.is_some() {
+                bail!("data_service_tls_client_ca_path is set but TLS is not enabled");
+            }
+            return Ok(None);
+        },
+        _ => bail!("data_service_tls_cert_path and data_service_tls_key_path must be set together"),
+    };
+    let identity = Identity::from_pem(
+        std::fs::read(cert_path).context("Failed to read the TLS certificate")?,
+        std::fs::read(key_path).context("Failed to read the TLS key")?,
+    );
+    let tls_config = ServerTlsConfig::new().identity(identity);
+    Ok(Some(match &config.data_service_tls_client_ca_path {
+        Some(client_ca_path) => tls_config.client_ca_root(Certificate::from_pem(
+            std::fs::read(client_ca_path)
+                .context("Failed to read the TLS client CA certificate")?,
+        )),
+        None => tls_config,
+    }))
 }
 
 /// Build a set of whitelisted auth tokens. Invalid tokens are ignored.
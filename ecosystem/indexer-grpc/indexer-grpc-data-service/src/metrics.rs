@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use aptos_metrics_core::{
-    register_gauge_vec, register_histogram_vec, register_int_counter_vec, register_int_gauge_vec,
-    GaugeVec, HistogramVec, IntCounterVec, IntGaugeVec,
+    register_gauge, register_gauge_vec, register_histogram_vec, register_int_counter,
+    register_int_counter_vec, register_int_gauge, register_int_gauge_vec, Gauge, GaugeVec,
+    HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec,
 };
 use once_cell::sync::Lazy;
 
@@ -27,6 +28,16 @@ pub static OBSERVED_LATEST_PROCESSED_VERSION: Lazy<IntGaugeVec> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Number of streams currently open, by request token.
+pub static ACTIVE_STREAMS: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "indexer_grpc_data_service_active_streams",
+        "Number of streams currently open for the request token",
+        &["request_token"],
+    )
+    .unwrap()
+});
+
 /// Number of transactions that served by data service.
 pub static PROCESSED_VERSIONS_COUNT: Lazy<IntCounterVec> = Lazy::new(|| {
     register_int_counter_vec!(
@@ -76,3 +87,60 @@ pub static PROCESSED_BATCH_SIZE: Lazy<IntGaugeVec> = Lazy::new(|| {
     )
     .unwrap()
 });
+
+/// Batch size currently used for a stream, reduced while its client falls behind.
+pub static ADAPTIVE_BATCH_SIZE: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "indexer_grpc_data_service_adaptive_batch_size",
+        "Batch size currently used by data service for the request token",
+        &["request_token"],
+    )
+    .unwrap()
+});
+
+/// Number of data gaps that data service has encountered, by version bucket.
+pub static DATA_GAP_COUNT: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "indexer_grpc_data_service_data_gap_count",
+        "Number of data gaps that data service has encountered",
+        &["version_bucket"],
+    )
+    .unwrap()
+});
+
+/// Version of the latest data gap.
+pub static DATA_GAP_VERSION: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "indexer_grpc_data_service_data_gap_version",
+        "Version of the latest data gap that data service has encountered",
+    )
+    .unwrap()
+});
+
+/// Age of the latest transaction in cache, as last checked by any stream.
+pub static CACHE_STALENESS_SECONDS: Lazy<Gauge> = Lazy::new(|| {
+    register_gauge!(
+        "indexer_grpc_data_service_cache_staleness_in_secs",
+        "Age of the latest transaction in cache in seconds",
+    )
+    .unwrap()
+});
+
+/// Number of data fetches served with the result of an identical fetch already in flight.
+pub static COALESCED_DATA_FETCH_COUNT: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "indexer_grpc_data_service_coalesced_data_fetch_count",
+        "Number of data fetches that shared the result of an identical fetch in flight",
+    )
+    .unwrap()
+});
+
+/// Whether data service is healthy, i.e., 1 if the data gap rate is within the threshold and the
+/// cache is not stale.
+pub static DATA_SERVICE_HEALTHY: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "indexer_grpc_data_service_healthy",
+        "Whether data service is healthy, 1 for healthy and 0 otherwise",
+    )
+    .unwrap()
+});
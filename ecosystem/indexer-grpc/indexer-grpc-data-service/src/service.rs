@@ -2,35 +2,51 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::metrics::{
-    ERROR_COUNT, LATEST_PROCESSED_VERSION, OBSERVED_LATEST_PROCESSED_VERSION, PROCESSED_BATCH_SIZE,
-    PROCESSED_LATENCY_IN_SECS, PROCESSED_LATENCY_IN_SECS_ALL, PROCESSED_VERSIONS_COUNT,
+    ACTIVE_STREAMS, ADAPTIVE_BATCH_SIZE, CACHE_STALENESS_SECONDS, COALESCED_DATA_FETCH_COUNT,
+    DATA_GAP_COUNT, DATA_GAP_VERSION, DATA_SERVICE_HEALTHY, ERROR_COUNT, LATEST_PROCESSED_VERSION,
+    OBSERVED_LATEST_PROCESSED_VERSION, PROCESSED_BATCH_SIZE, PROCESSED_LATENCY_IN_SECS,
+    PROCESSED_LATENCY_IN_SECS_ALL, PROCESSED_VERSIONS_COUNT,
 };
 use aptos_indexer_grpc_utils::{
     build_protobuf_encoded_transaction_wrappers,
     cache_operator::{CacheBatchGetStatus, CacheOperator},
-    config::IndexerGrpcConfig,
-    constants::{GRPC_AUTH_TOKEN_HEADER, GRPC_REQUEST_NAME_HEADER},
-    decode_transaction_bytes,
+    config::{FileStoreExperimentConfig, IndexerGrpcConfig},
+    constants::{BLOB_STORAGE_SIZE, GRPC_AUTH_TOKEN_HEADER, GRPC_REQUEST_NAME_HEADER},
+    decode_transaction_bytes, encode_transaction,
     file_store_operator::FileStoreOperator,
     time_diff_since_pb_timestamp_in_secs, EncodedTransactionWithVersion,
 };
 use aptos_logger::{error, info, warn};
 use aptos_moving_average::MovingAverage;
 use aptos_protos::datastream::v1::{
-    indexer_stream_server::IndexerStream,
-    raw_datastream_response::Response as DatastreamProtoResponse, RawDatastreamRequest,
-    RawDatastreamResponse, StreamStatus, TransactionOutput, TransactionsOutput,
+    indexer_stream_server::IndexerStream, raw_datastream_request::DeliverySemantics,
+    raw_datastream_response::Response as DatastreamProtoResponse, stream_status::StatusType,
+    transaction_output::Source as TransactionSource, AckDatastreamRequest, AckDatastreamResponse,
+    RawDatastreamRequest, RawDatastreamResponse, StreamStatus, TransactionOutput,
+    TransactionsOutput,
 };
 use futures::Stream;
 use serde::{Deserialize, Serialize};
-use std::{pin::Pin, sync::Arc, time::Duration};
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
 use tokio::sync::{
-    mpsc::{channel, error::TrySendError},
+    mpsc::{channel, error::TrySendError, Sender},
+    oneshot,
     watch::channel as watch_channel,
 };
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
+use tracing::{info_span, Instrument, Span};
 use uuid::Uuid;
+use x509_parser::{certificate::X509Certificate, prelude::FromDer};
 
 type ResponseStream = Pin<Box<dyn Stream<Item = Result<RawDatastreamResponse, Status>> + Send>>;
 
@@ -41,6 +57,11 @@ struct RequestMetadata {
     pub request_token: String,
     pub request_name: String,
     pub request_source: String,
+    // Subject of the verified client certificate if mutual TLS is enabled, empty otherwise.
+    pub request_client_cert_subject: String,
+    // File store bucket the stream reads from, so that the buckets of a file store experiment
+    // can be compared; empty for requests other than streams.
+    pub file_store_bucket_name: String,
 }
 
 const MOVING_AVERAGE_WINDOW_SIZE: u64 = 10_000;
@@ -56,34 +77,695 @@ const RESPONSE_CHANNEL_FULL_BACKOFF_DURATION_MS: u64 = 1000;
 // Up to MAX_RESPONSE_CHANNEL_SIZE response can be buffered in the channel. If the channel is full,
 // the server will not fetch more data from the cache and file store until the channel is not full.
 const MAX_RESPONSE_CHANNEL_SIZE: usize = 40;
+// Data gaps are counted in buckets of this many versions.
+const DATA_GAP_VERSION_BUCKET_SIZE: u64 = 1_000_000;
+// The data gap rate is measured over this sliding window.
+const DATA_GAP_RATE_WINDOW_SECS: u64 = 60;
+// With adaptive batch size, batches never shrink below this many transactions.
+const MIN_ADAPTIVE_BATCH_SIZE: usize = 50;
+// Time constant of the exponentially weighted fraction of time a response channel is full.
+const ADAPTIVE_BATCH_SIZE_DECAY_SECS: f64 = 10.0;
+// With a cache staleness threshold, each stream checks the freshness of the cache this often.
+const CACHE_STALENESS_CHECK_INTERVAL_SECS: u64 = 10;
+// With a TPS cap, a stream at or above the cap waits this long before checking again.
+const MAX_TPS_BACKOFF_DURATION_MS: u64 = 100;
+// An at-least-once stream can be resumed until this long after it was last active.
+const ACKED_STREAM_TTL_SECS: u64 = 600;
+// Parameters of the 64-bit FNV-1a hash request tokens are selected for a file store experiment by.
+const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01B3;
 
 pub struct DatastreamServer {
     pub redis_client: Arc<redis::Client>,
     pub server_config: IndexerGrpcConfig,
+    data_gap_tracker: Arc<DataGapTracker>,
+    file_store_range_cache: Option<Arc<FileStoreRangeCache>>,
+    ack_tracker: Arc<AckTracker>,
+    data_fetch_coalescer: Option<Arc<DataFetchCoalescer>>,
 }
 
 impl DatastreamServer {
     pub fn new(config: IndexerGrpcConfig) -> Self {
+        DATA_SERVICE_HEALTHY.set(1);
         Self {
             redis_client: Arc::new(
                 redis::Client::open(format!("redis://{}", config.redis_address))
                     .expect("Create redis client failed."),
             ),
+            data_gap_tracker: Arc::new(DataGapTracker::new(
+                config.data_gap_alert_threshold_per_minute,
+            )),
+            file_store_range_cache: config
+                .file_store_range_cache_ttl_secs
+                .map(|ttl_secs| Arc::new(FileStoreRangeCache::new(ttl_secs))),
+            ack_tracker: Arc::new(AckTracker::new()),
+            data_fetch_coalescer: config
+                .coalesce_data_fetches
+                .unwrap_or(false)
+                .then(|| Arc::new(DataFetchCoalescer::new())),
             server_config: config,
         }
     }
+
+    fn is_json_output_allowed(&self, request_token: &str) -> bool {
+        self.server_config
+            .json_output_auth_tokens
+            .as_ref()
+            .map_or(false, |tokens| {
+                tokens.iter().any(|token| token == request_token)
+            })
+    }
+
+    fn is_replay_allowed(&self, request_token: &str) -> bool {
+        self.server_config
+            .replay_auth_tokens
+            .as_ref()
+            .map_or(false, |tokens| {
+                tokens.iter().any(|token| token == request_token)
+            })
+    }
+
+    /// Returns the file store bucket the streams of `request_token` read from.
+    fn select_file_store_bucket_name(&self, request_token: &str) -> String {
+        match &self.server_config.file_store_experiment {
+            Some(experiment) if is_selected_for_experiment(experiment, request_token) => {
+                experiment.bucket_name.clone()
+            },
+            _ => self.server_config.file_store_bucket_name.clone(),
+        }
+    }
+}
+
+/// Returns whether `request_token` is selected for the file store experiment. Tokens are selected
+/// by percentage with FNV-1a, as opposed to `DefaultHasher`, whose hashes may change across Rust
+/// releases, so that a token stays in the same bucket across restarts and upgrades.
+fn is_selected_for_experiment(experiment: &FileStoreExperimentConfig, request_token: &str) -> bool {
+    let allowlisted = experiment
+        .allowlisted_tokens
+        .as_ref()
+        .map_or(false, |tokens| {
+            tokens.iter().any(|token| token == request_token)
+        });
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in request_token.bytes() {
+        hash = (hash ^ byte as u64).wrapping_mul(FNV_PRIME);
+    }
+    allowlisted || hash % 100 < experiment.traffic_percentage.unwrap_or(0) as u64
+}
+
+/// Tracks data gaps across all streams and flips the health gauge when they exceed the threshold
+/// or the cache is stale.
+struct DataGapTracker {
+    threshold_per_minute: Option<u64>,
+    // When the data gaps within the current window were detected, oldest first.
+    recent_gaps: Mutex<VecDeque<Instant>>,
+    cache_stale: AtomicBool,
+}
+
+impl DataGapTracker {
+    fn new(threshold_per_minute: Option<u64>) -> Self {
+        Self {
+            threshold_per_minute,
+            recent_gaps: Mutex::new(VecDeque::new()),
+            cache_stale: AtomicBool::new(false),
+        }
+    }
+
+    fn set_cache_stale(&self, cache_stale: bool) {
+        self.cache_stale.store(cache_stale, Ordering::Relaxed);
+        self.update_health(&mut self.recent_gaps.lock().unwrap());
+    }
+
+    fn record_gap(&self) {
+        if self.threshold_per_minute.is_none() {
+            return;
+        }
+        let mut recent_gaps = self.recent_gaps.lock().unwrap();
+        recent_gaps.push_back(Instant::now());
+        self.update_health(&mut recent_gaps);
+    }
+
+    /// Re-evaluates the health gauge so that it recovers once old gaps fall out of the window.
+    fn refresh(&self) {
+        if self.threshold_per_minute.is_none() {
+            return;
+        }
+        self.update_health(&mut self.recent_gaps.lock().unwrap());
+    }
+
+    fn update_health(&self, recent_gaps: &mut VecDeque<Instant>) {
+        let window = Duration::from_secs(DATA_GAP_RATE_WINDOW_SECS);
+        while let Some(detected_at) = recent_gaps.front() {
+            if detected_at.elapsed() < window {
+                break;
+            }
+            recent_gaps.pop_front();
+        }
+        DATA_SERVICE_HEALTHY.set(self.is_healthy(recent_gaps.len()) as i64);
+    }
+
+    fn is_healthy(&self, num_recent_gaps: usize) -> bool {
+        let healthy = match self.threshold_per_minute {
+            Some(threshold) => num_recent_gaps as u64 <= threshold,
+            None => true,
+        };
+        healthy && !self.cache_stale.load(Ordering::Relaxed)
+    }
+}
+
+/// Caches the range of versions archived in each file store bucket across all streams, as it only
+/// advances as new files are uploaded.
+struct FileStoreRangeCache {
+    ttl: Duration,
+    // When the range was fetched, and the range if the file store is not empty, by bucket name.
+    cached: Mutex<HashMap<String, (Instant, Option<(u64, u64)>)>>,
+}
+
+impl FileStoreRangeCache {
+    fn new(ttl_secs: u64) -> Self {
+        Self {
+            ttl: Duration::from_secs(ttl_secs),
+            cached: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the archived range of the bucket, inclusive, re-fetching it if the cached one
+    /// expired. Streams starting at the same time may all re-fetch it, which is harmless.
+    async fn get(
+        &self,
+        bucket_name: &str,
+        file_store_operator: &FileStoreOperator,
+    ) -> Option<(u64, u64)> {
+        let cached = self.cached.lock().unwrap().get(bucket_name).copied();
+        if let Some((fetched_at, range)) = cached {
+            if fetched_at.elapsed() < self.ttl {
+                return range;
+            }
+        }
+        let range = file_store_operator.get_archived_version_range().await;
+        self.cached
+            .lock()
+            .unwrap()
+            .insert(bucket_name.to_string(), (Instant::now(), range));
+        range
+    }
+}
+
+/// Tracks the versions acknowledged for the at-least-once streams, so that a stream resumed after
+/// a disconnect restarts right after the last version its client processed.
+struct AckTracker {
+    streams: Mutex<HashMap<String, AckedStream>>,
+}
+
+struct AckedStream {
+    request_token: String,
+    // Version to restart at if the stream is resumed, i.e., right after the last one acknowledged.
+    resume_version: u64,
+    // Version the stream continues at; the versions before it can be acknowledged.
+    next_version: u64,
+    last_active_at: Instant,
+}
+
+impl AckTracker {
+    fn new() -> Self {
+        Self {
+            streams: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Starts tracking a new stream starting at `starting_version` and returns its id.
+    fn register(&self, request_token: &str, starting_version: u64) -> String {
+        let stream_id = Uuid::new_v4().to_string();
+        let mut streams = self.streams.lock().unwrap();
+        Self::evict_expired(&mut streams, Instant::now());
+        streams.insert(stream_id.clone(), AckedStream {
+            request_token: request_token.to_string(),
+            resume_version: starting_version,
+            next_version: starting_version,
+            last_active_at: Instant::now(),
+        });
+        stream_id
+    }
+
+    /// Returns the version to resume the stream at.
+    fn resume(&self, stream_id: &str, request_token: &str) -> Result<u64, Status> {
+        let mut streams = self.streams.lock().unwrap();
+        Self::evict_expired(&mut streams, Instant::now());
+        let stream = Self::get_stream(&mut streams, stream_id, request_token)?;
+        stream.next_version = stream.resume_version;
+        stream.last_active_at = Instant::now();
+        Ok(stream.resume_version)
+    }
+
+    /// Records that the stream continues at `next_version`.
+    fn record_progress(&self, stream_id: &str, next_version: u64) {
+        if let Some(stream) = self.streams.lock().unwrap().get_mut(stream_id) {
+            stream.next_version = next_version;
+            stream.last_active_at = Instant::now();
+        }
+    }
+
+    /// Records that the client processed all the transactions of the stream up to `version`,
+    /// inclusive. Acknowledging a version that was not streamed yet is an error.
+    fn ack(&self, stream_id: &str, request_token: &str, version: u64) -> Result<(), Status> {
+        let mut streams = self.streams.lock().unwrap();
+        let stream = Self::get_stream(&mut streams, stream_id, request_token)?;
+        if version >= stream.next_version {
+            return Err(Status::invalid_argument("Version was not streamed yet"));
+        }
+        stream.resume_version = stream.resume_version.max(version + 1);
+        stream.last_active_at = Instant::now();
+        Ok(())
+    }
+
+    fn get_stream<'a>(
+        streams: &'a mut HashMap<String, AckedStream>,
+        stream_id: &str,
+        request_token: &str,
+    ) -> Result<&'a mut AckedStream, Status> {
+        match streams.get_mut(stream_id) {
+            Some(stream) if stream.request_token == request_token => Ok(stream),
+            Some(_) => Err(Status::permission_denied(
+                "Stream belongs to another request token",
+            )),
+            None => Err(Status::not_found("Unknown or expired stream id")),
+        }
+    }
+
+    fn evict_expired(streams: &mut HashMap<String, AckedStream>, now: Instant) {
+        let ttl = Duration::from_secs(ACKED_STREAM_TTL_SECS);
+        streams.retain(|_, stream| now.saturating_duration_since(stream.last_active_at) < ttl);
+    }
+}
+
+/// Lets concurrent streams fetching the same batch share a single fetch from the cache or file
+/// store, so that many clients starting at the same version don't multiply the load on them.
+struct DataFetchCoalescer {
+    // The streams waiting for each fetch in flight.
+    in_flight: Mutex<HashMap<DataFetchKey, Vec<oneshot::Sender<SharedDataFetchResult>>>>,
+}
+
+// The file store bucket, the starting version, if the cache is stale the version of the file
+// store, and the max number of transactions, which together decide the batch returned.
+type DataFetchKey = (String, u64, Option<u64>, usize);
+// Errors are shared as their messages since they can't be cloned.
+type SharedDataFetchResult = Result<TransactionsDataStatus, String>;
+
+impl DataFetchCoalescer {
+    fn new() -> Self {
+        Self {
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Runs `fetch`, unless an identical one is in flight, in which case its result is returned
+    /// instead. If the stream running that one goes away before it completes, `fetch` is run
+    /// after all.
+    async fn fetch(
+        &self,
+        key: DataFetchKey,
+        fetch: impl Future<Output = anyhow::Result<TransactionsDataStatus>>,
+    ) -> anyhow::Result<TransactionsDataStatus> {
+        let receiver = match self.in_flight.lock().unwrap().entry(key) {
+            Entry::Occupied(mut entry) => {
+                let (sender, receiver) = oneshot::channel();
+                entry.get_mut().push(sender);
+                Some(receiver)
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(vec![]);
+                None
+            },
+        };
+        if let Some(receiver) = receiver {
+            return match receiver.await {
+                Ok(result) => {
+                    COALESCED_DATA_FETCH_COUNT.inc();
+                    result.map_err(anyhow::Error::msg)
+                },
+                Err(_) => fetch.await,
+            };
+        }
+
+        let mut in_flight_fetch = InFlightDataFetch {
+            coalescer: self,
+            key: Some(key),
+        };
+        let result = fetch.await;
+        let shared_result = match &result {
+            Ok(status) => Ok(status.clone()),
+            Err(e) => Err(format!("{:#}", e)),
+        };
+        for waiter in in_flight_fetch.complete() {
+            // The waiting stream may be gone already.
+            let _ = waiter.send(shared_result.clone());
+        }
+        result
+    }
+}
+
+/// A fetch run by a `DataFetchCoalescer`. It's no longer in flight once completed or dropped, in
+/// which case the waiting streams are woken up to run the fetch themselves.
+struct InFlightDataFetch<'a> {
+    coalescer: &'a DataFetchCoalescer,
+    key: Option<DataFetchKey>,
+}
+
+impl InFlightDataFetch<'_> {
+    /// Returns the streams waiting for the result.
+    fn complete(&mut self) -> Vec<oneshot::Sender<SharedDataFetchResult>> {
+        self.key
+            .take()
+            .and_then(|key| self.coalescer.in_flight.lock().unwrap().remove(&key))
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for InFlightDataFetch<'_> {
+    fn drop(&mut self) {
+        self.complete();
+    }
+}
+
+/// A stream counted in the active streams of its request token until dropped.
+struct ActiveStream {
+    request_token: String,
+}
+
+impl ActiveStream {
+    fn new(request_token: &str) -> Self {
+        ACTIVE_STREAMS.with_label_values(&[request_token]).inc();
+        Self {
+            request_token: request_token.to_string(),
+        }
+    }
+}
+
+impl Drop for ActiveStream {
+    fn drop(&mut self) {
+        ACTIVE_STREAMS
+            .with_label_values(&[self.request_token.as_str()])
+            .dec();
+    }
 }
 
 /// Enum to represent the status of the data fetching overall.
+#[derive(Clone)]
 enum TransactionsDataStatus {
-    // Data fetching is successful.
-    Success(Vec<EncodedTransactionWithVersion>),
+    // Data fetching is successful, with where the data was read from.
+    Success(Vec<EncodedTransactionWithVersion>, TransactionSource),
     // Ahead of current head of cache.
     AheadOfCache,
     // Fatal error when gap detected between cache and file store.
     DataGap,
 }
 
+/// Periodically checks the age of the latest transaction in cache, so that a stream stops
+/// trusting the cache once the cache worker stalls.
+struct CacheStalenessChecker {
+    staleness_threshold_secs: u64,
+    last_checked_at: Option<Instant>,
+    // Version up to which the file store has data, set while the cache is stale.
+    file_store_version: Option<u64>,
+}
+
+impl CacheStalenessChecker {
+    fn new(staleness_threshold_secs: u64) -> Self {
+        Self {
+            staleness_threshold_secs,
+            last_checked_at: None,
+            file_store_version: None,
+        }
+    }
+
+    /// Re-checks the cache if due. Returns the version up to which the file store has data if
+    /// the cache is stale, `None` if the cache can be used.
+    async fn check(
+        &mut self,
+        cache_operator: &mut CacheOperator<redis::aio::Connection>,
+        file_store_operator: &FileStoreOperator,
+        data_gap_tracker: &DataGapTracker,
+    ) -> Option<u64> {
+        let now = Instant::now();
+        if !self.is_check_due(now) {
+            return self.file_store_version;
+        }
+        self.last_checked_at = Some(now);
+
+        let staleness_in_secs = match cache_operator.get_latest_encoded_proto_data().await {
+            Ok(encoded) => encoded
+                .as_deref()
+                .and_then(decode_transaction_bytes)
+                .and_then(|transaction| transaction.timestamp)
+                .map(|timestamp| time_diff_since_pb_timestamp_in_secs(&timestamp)),
+            Err(e) => {
+                ERROR_COUNT
+                    .with_label_values(&["cache_staleness_check_failed"])
+                    .inc();
+                warn!(
+                    error = e.to_string(),
+                    "[Indexer Data] Failed to check the cache staleness."
+                );
+                return self.file_store_version;
+            },
+        };
+        // An empty cache has nothing to serve, stale or not.
+        let staleness_in_secs = match staleness_in_secs {
+            Some(staleness_in_secs) => staleness_in_secs,
+            None => return self.file_store_version,
+        };
+        CACHE_STALENESS_SECONDS.set(staleness_in_secs);
+
+        let cache_stale = self.is_stale(staleness_in_secs);
+        data_gap_tracker.set_cache_stale(cache_stale);
+        self.file_store_version = if cache_stale {
+            warn!(
+                staleness_in_secs = staleness_in_secs,
+                "[Indexer Data] Cache is stale; serving from file store only."
+            );
+            Some(
+                file_store_operator
+                    .get_file_store_metadata()
+                    .await
+                    .map_or(0, |metadata| metadata.version),
+            )
+        } else {
+            None
+        };
+        self.file_store_version
+    }
+
+    fn is_check_due(&self, now: Instant) -> bool {
+        self.last_checked_at.map_or(true, |last_checked_at| {
+            now.saturating_duration_since(last_checked_at)
+                >= Duration::from_secs(CACHE_STALENESS_CHECK_INTERVAL_SECS)
+        })
+    }
+
+    fn is_stale(&self, staleness_in_secs: f64) -> bool {
+        staleness_in_secs > self.staleness_threshold_secs as f64
+    }
+}
+
+/// Periodically sends the client of a stream a PROGRESS status with the version the stream has
+/// been processed up to and the latest version in cache, so that the client can tell how far
+/// behind it is without computing it.
+struct ProgressReporter {
+    redis_client: Arc<redis::Client>,
+    tx: Sender<Result<RawDatastreamResponse, Status>>,
+    interval: Duration,
+    last_sent_at: Option<Instant>,
+    // Connected on the first report, and again after a failed one.
+    cache_operator: Option<CacheOperator<redis::aio::Connection>>,
+}
+
+impl ProgressReporter {
+    fn new(
+        redis_client: Arc<redis::Client>,
+        tx: Sender<Result<RawDatastreamResponse, Status>>,
+        interval_secs: u64,
+    ) -> Self {
+        Self {
+            redis_client,
+            tx,
+            interval: Duration::from_secs(interval_secs),
+            last_sent_at: None,
+            cache_operator: None,
+        }
+    }
+
+    /// Sends a PROGRESS status if due. It's dropped if the response channel is full, so that it
+    /// never holds up the data.
+    async fn report(&mut self, processed_version: u64) {
+        if let Some(last_sent_at) = self.last_sent_at {
+            if last_sent_at.elapsed() < self.interval {
+                return;
+            }
+        }
+        self.last_sent_at = Some(Instant::now());
+
+        let mut cache_operator = match self.cache_operator.take() {
+            Some(cache_operator) => cache_operator,
+            None => match self.redis_client.get_async_connection().await {
+                Ok(conn) => CacheOperator::new(conn),
+                Err(e) => {
+                    ERROR_COUNT
+                        .with_label_values(&["progress_status_redis_connection_failed"])
+                        .inc();
+                    warn!(
+                        error = e.to_string(),
+                        "[Indexer Data] Failed to get redis connection for the progress status."
+                    );
+                    return;
+                },
+            },
+        };
+        let (chain_id, latest_version) = match Self::get_cache_head(&mut cache_operator).await {
+            Ok(cache_head) => cache_head,
+            Err(e) => {
+                ERROR_COUNT
+                    .with_label_values(&["progress_status_cache_head_failed"])
+                    .inc();
+                warn!(
+                    error = e.to_string(),
+                    "[Indexer Data] Failed to get the cache head for the progress status."
+                );
+                return;
+            },
+        };
+        self.cache_operator = Some(cache_operator);
+        // Best effort; the client is behind already if the channel is full, or gone if it's
+        // closed.
+        let _ = self.tx.try_send(Ok(progress_status_builder(
+            processed_version,
+            latest_version,
+            chain_id as u32,
+        )));
+    }
+
+    /// Gets the chain id and the latest version in cache.
+    async fn get_cache_head(
+        cache_operator: &mut CacheOperator<redis::aio::Connection>,
+    ) -> anyhow::Result<(u64, u64)> {
+        let chain_id = cache_operator.get_chain_id().await?;
+        let latest_version = cache_operator.get_latest_version().await?;
+        Ok((chain_id, latest_version))
+    }
+}
+
+/// Paces a stream to the consumption rate of its client by shrinking the batch size while the
+/// response channel keeps filling up, and growing it back once the channel drains.
+struct AdaptiveBatchSizer {
+    request_token: String,
+    channel_full_threshold: f64,
+    // Exponentially weighted fraction of time the channel has been full.
+    channel_full_ratio: f64,
+    batch_size: usize,
+    // Whether the channel was full at the last send, and when that was.
+    last_channel_full: bool,
+    last_observed_at: Instant,
+}
+
+impl AdaptiveBatchSizer {
+    fn new(request_token: String, channel_full_threshold: f64) -> Self {
+        ADAPTIVE_BATCH_SIZE
+            .with_label_values(&[request_token.as_str()])
+            .set(BLOB_STORAGE_SIZE as i64);
+        Self {
+            request_token,
+            channel_full_threshold,
+            channel_full_ratio: 0.0,
+            batch_size: BLOB_STORAGE_SIZE,
+            last_channel_full: false,
+            last_observed_at: Instant::now(),
+        }
+    }
+
+    /// The max number of transactions to fetch for the next batch.
+    fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Records whether the channel was full when sending a batch and adjusts the batch size.
+    fn observe(&mut self, channel_full: bool) {
+        self.observe_at(channel_full, Instant::now());
+    }
+
+    fn observe_at(&mut self, channel_full: bool, now: Instant) {
+        // The time since the last send was spent in the state observed back then, e.g., backing
+        // off on a full channel.
+        let elapsed_secs = now
+            .saturating_duration_since(self.last_observed_at)
+            .as_secs_f64();
+        let weight = 1.0 - (-elapsed_secs / ADAPTIVE_BATCH_SIZE_DECAY_SECS).exp();
+        let sample = if self.last_channel_full { 1.0 } else { 0.0 };
+        self.channel_full_ratio += weight * (sample - self.channel_full_ratio);
+        self.last_channel_full = channel_full;
+        self.last_observed_at = now;
+
+        if self.channel_full_ratio > self.channel_full_threshold {
+            self.batch_size = (self.batch_size / 2).max(MIN_ADAPTIVE_BATCH_SIZE);
+        } else if self.channel_full_ratio < self.channel_full_threshold / 2.0 {
+            // Only grow back well below the threshold to avoid flapping around it.
+            self.batch_size = (self.batch_size * 2).min(BLOB_STORAGE_SIZE);
+        }
+        ADAPTIVE_BATCH_SIZE
+            .with_label_values(&[self.request_token.as_str()])
+            .set(self.batch_size as i64);
+    }
+}
+
+impl Drop for AdaptiveBatchSizer {
+    fn drop(&mut self) {
+        // Other streams of the request token set it again on their next send.
+        let _ = ADAPTIVE_BATCH_SIZE.remove_label_values(&[self.request_token.as_str()]);
+    }
+}
+
+/// Fixed range of versions a load testing stream replays over and over. Versions are relabeled
+/// to keep increasing across rounds, i.e., version `end_version + 1` replays `start_version`.
+#[derive(Clone, Copy)]
+struct ReplayRange {
+    start_version: u64,
+    end_version: u64,
+}
+
+impl ReplayRange {
+    /// Version in the range that the relabeled `version` replays.
+    fn source_version(&self, version: u64) -> u64 {
+        let len = self.end_version - self.start_version + 1;
+        self.start_version + (version - self.start_version) % len
+    }
+}
+
+/// Span of consecutive versions whose transactions were all filtered out.
+struct FilteredVersionSpan {
+    start_version: u64,
+    end_version: u64,
+    // When the first batch of the span was filtered out.
+    started_at: Instant,
+}
+
+impl FilteredVersionSpan {
+    fn new(start_version: u64, end_version: u64) -> Self {
+        Self {
+            start_version,
+            end_version,
+            started_at: Instant::now(),
+        }
+    }
+
+    fn extend(&mut self, end_version: u64) {
+        self.end_version = end_version;
+    }
+
+    /// Whether the span has been merging batches for `merge_interval` already and must be
+    /// reported.
+    fn is_due(&self, merge_interval: Duration) -> bool {
+        self.started_at.elapsed() >= merge_interval
+    }
+}
+
 /// DatastreamServer handles the raw datastream requests from cache and file store.
 #[tonic::async_trait]
 impl IndexerStream for DatastreamServer {
@@ -95,33 +777,147 @@ impl IndexerStream for DatastreamServer {
     ///    1.2. If the data is not in cache, fetch the data from file store.
     ///    1.3. If the data is not in file store, stream connection will break.
     ///    1.4  If error happens, retry after a short sleep.
+    ///    1.5. If transaction types are requested, drop the transactions of other types.
+    ///    1.6. In replay mode, data is fetched from the file store only and relabeled.
+    ///    1.7. If an ending version is requested, drop the transactions after it.
     /// 2. Push data into channel to stream to the client.
     ///    2.1. If the channel is full, do not fetch and retry after a short sleep.
+    ///    2.2. If a TPS cap is requested, do not fetch while the recent TPS is at or above it.
+    /// 3. For at-least-once delivery, record the progress so that acknowledgements can be checked
+    ///    against it. By default, delivery is at most once and batches are not tracked.
     async fn raw_datastream(
         &self,
         req: Request<RawDatastreamRequest>,
     ) -> Result<Response<Self::RawDatastreamStream>, Status> {
-        let request_metadata = match get_request_metadata(&req) {
+        let mut request_metadata = match get_request_metadata(&req) {
             Ok(request_metadata) => request_metadata,
             Err(e) => return Result::Err(e),
         };
+        request_metadata.file_store_bucket_name =
+            self.select_file_store_bucket_name(&request_metadata.request_token);
+        // Shared by the data and watcher tasks, so the stream is counted until both have exited.
+        let active_stream = self
+            .server_config
+            .enable_active_stream_counts
+            .unwrap_or(false)
+            .then(|| Arc::new(ActiveStream::new(&request_metadata.request_token)));
+
+        let request = req.into_inner();
+        let at_least_once = request.delivery_semantics() == DeliverySemantics::AtLeastOnce;
+        if request.resume_stream_id.is_some() && !at_least_once {
+            return Result::Err(Status::invalid_argument(
+                "Only at-least-once streams can be resumed",
+            ));
+        }
+        let json_output = request.json_output.unwrap_or(false);
+        let include_source = request.include_source.unwrap_or(false);
+        if json_output && !self.is_json_output_allowed(&request_metadata.request_token) {
+            return Result::Err(Status::permission_denied(
+                "JSON output is not allowed for this request token",
+            ));
+        }
 
         // Response channel to stream the data to the client.
         let (tx, rx) = channel(MAX_RESPONSE_CHANNEL_SIZE);
-        let mut current_version = match req.into_inner().starting_version {
-            Some(version) => version,
-            None => {
+        let mut current_version = match (&request.resume_stream_id, request.starting_version) {
+            (Some(stream_id), _) => self
+                .ack_tracker
+                .resume(stream_id, &request_metadata.request_token)?,
+            (None, Some(version)) => version,
+            (None, None) => {
                 return Result::Err(Status::aborted("Starting version is not set"));
             },
         };
+        let replay_range = match request.replay_end_version {
+            Some(replay_end_version) => {
+                if !self.is_replay_allowed(&request_metadata.request_token) {
+                    return Result::Err(Status::permission_denied(
+                        "Replay is not allowed for this request token",
+                    ));
+                }
+                if replay_end_version < current_version {
+                    return Result::Err(Status::invalid_argument(
+                        "Replay end version is before the starting version",
+                    ));
+                }
+                Some(ReplayRange {
+                    start_version: current_version,
+                    end_version: replay_end_version,
+                })
+            },
+            None => None,
+        };
+        // Only transactions of these types are sent if any; empty means no filtering.
+        let transaction_types = request.transaction_types;
+        if request.max_tps == Some(0) {
+            return Result::Err(Status::invalid_argument("Max TPS must be positive"));
+        }
+        let max_tps = request.max_tps;
+        // The stream ends with a summary once this version is sent, if any.
+        let ending_version = request.ending_version;
+        if ending_version.map_or(false, |ending_version| ending_version < current_version) {
+            return Result::Err(Status::invalid_argument(
+                "Ending version is before the starting version",
+            ));
+        }
+        // Id for the client to acknowledge versions with, only for at-least-once streams.
+        let stream_id = if at_least_once {
+            Some(request.resume_stream_id.unwrap_or_else(|| {
+                self.ack_tracker
+                    .register(&request_metadata.request_token, current_version)
+            }))
+        } else {
+            None
+        };
+        let ack_tracker = self.ack_tracker.clone();
         // This is to monitor the latest processed version.
         let (watch_sender, mut watch_receiver) = watch_channel(current_version);
+        // The stream ends once the deadline is reached, if any.
+        let deadline = request
+            .deadline_ms
+            .map(|deadline_ms| tokio::time::Instant::now() + Duration::from_millis(deadline_ms));
 
-        let file_store_bucket_name = self.server_config.file_store_bucket_name.clone();
+        let file_store_bucket_name = request_metadata.file_store_bucket_name.clone();
         let redis_client = self.redis_client.clone();
+        let data_gap_tracker = self.data_gap_tracker.clone();
+        let mut adaptive_batch_sizer = self
+            .server_config
+            .adaptive_batch_size_channel_full_threshold
+            .map(|threshold| {
+                AdaptiveBatchSizer::new(request_metadata.request_token.clone(), threshold)
+            });
+        let mut cache_staleness_checker = self
+            .server_config
+            .cache_staleness_threshold_secs
+            .map(CacheStalenessChecker::new);
+        let watcher_data_gap_tracker = self.data_gap_tracker.clone();
+        // Without merging, each batch with every transaction filtered out is reported right away.
+        let filtered_batch_merge_interval = Duration::from_millis(
+            self.server_config
+                .filtered_batch_merge_interval_ms
+                .unwrap_or(0),
+        );
+        // Only holds a sender of the response channel if enabled, since the stream doesn't end
+        // until every sender is dropped.
+        let mut progress_reporter =
+            self.server_config
+                .progress_status_interval_secs
+                .map(|interval_secs| {
+                    ProgressReporter::new(self.redis_client.clone(), tx.clone(), interval_secs)
+                });
+        let file_store_range_cache = self.file_store_range_cache.clone();
+        let data_fetch_coalescer = self.data_fetch_coalescer.clone();
+        let stream_span = if self.server_config.enable_request_tracing.unwrap_or(false) {
+            request_span(&request_metadata)
+        } else {
+            Span::none()
+        };
         let request_metadata_clone = request_metadata.clone();
-        tokio::spawn(async move {
+        let active_stream_clone = active_stream.clone();
+        // Per-batch tracing events of the stream are children of its span.
+        let stream = async move {
             let request_metadata = request_metadata_clone;
+            let _active_stream = active_stream_clone;
             let conn = match redis_client.get_async_connection().await {
                 Ok(conn) => conn,
                 Err(e) => {
@@ -142,7 +938,7 @@ impl IndexerStream for DatastreamServer {
                 },
             };
             let mut cache_operator = CacheOperator::new(conn);
-            let file_store_operator = FileStoreOperator::new(file_store_bucket_name);
+            let file_store_operator = FileStoreOperator::new(file_store_bucket_name.clone());
             file_store_operator.verify_storage_bucket_existence().await;
 
             let chain_id = match cache_operator.get_chain_id().await {
@@ -164,8 +960,22 @@ impl IndexerStream for DatastreamServer {
                     return;
                 },
             };
+            let file_store_range = match &file_store_range_cache {
+                Some(file_store_range_cache) => {
+                    file_store_range_cache
+                        .get(&file_store_bucket_name, &file_store_operator)
+                        .await
+                },
+                None => None,
+            };
             // Data service metrics.
             let mut tps_calculator = MovingAverage::new(MOVING_AVERAGE_WINDOW_SIZE);
+            // Versions skipped by filtering that are not yet reported to the client.
+            let mut filtered_span: Option<FilteredVersionSpan> = None;
+            // Reported in the summary status once the ending version is reached.
+            let start_version = current_version;
+            let mut total_transactions = 0;
+            let mut total_bytes = 0;
 
             info!(
                 chain_id = chain_id,
@@ -178,53 +988,270 @@ impl IndexerStream for DatastreamServer {
                 response: Some(DatastreamProtoResponse::Status(StreamStatus {
                     r#type: 1,
                     start_version: current_version,
+                    json_output,
+                    file_store_min_version: file_store_range.map(|(min_version, _)| min_version),
+                    file_store_max_version: file_store_range.map(|(_, max_version)| max_version),
+                    stream_id: stream_id.clone(),
                     ..StreamStatus::default()
                 })),
             }))
             .await
             .unwrap();
             loop {
+                if let Some(ending_version) =
+                    ending_version.filter(|ending_version| current_version > *ending_version)
+                {
+                    flush_filtered_span(&tx, filtered_span.take(), chain_id as u32).await;
+                    // Best effort since the client may be gone already.
+                    let _ = tx
+                        .send(Ok(summary_status_builder(
+                            start_version,
+                            ending_version,
+                            total_transactions,
+                            total_bytes,
+                            chain_id as u32,
+                        )))
+                        .await;
+                    info!(
+                        request_metadata = request_metadata,
+                        total_transactions = total_transactions,
+                        total_bytes = total_bytes,
+                        "[Indexer Data] Ending version reached; ending the stream."
+                    );
+                    break;
+                }
+                if deadline.map_or(false, |deadline| tokio::time::Instant::now() >= deadline) {
+                    flush_filtered_span(&tx, filtered_span.take(), chain_id as u32).await;
+                    // Best effort since the client may be gone already.
+                    let _ = tx
+                        .send(Ok(deadline_exceeded_status_builder(
+                            current_version,
+                            chain_id as u32,
+                        )))
+                        .await;
+                    info!(
+                        request_metadata = request_metadata,
+                        current_version = current_version,
+                        "[Indexer Data] Deadline exceeded; ending the stream."
+                    );
+                    break;
+                }
+                if let Some(max_tps) = max_tps {
+                    // Ticking with nothing sent lets the measured TPS decay while backing off.
+                    tps_calculator.tick_now(0);
+                    if is_at_tps_cap(&tps_calculator, max_tps) {
+                        sleep_before_deadline(
+                            Duration::from_millis(MAX_TPS_BACKOFF_DURATION_MS),
+                            deadline,
+                        )
+                        .await;
+                        continue;
+                    }
+                }
+                // Don't fetch more than the client has recently been able to consume, and fetch
+                // at most a second worth of transactions at once to avoid bursts.
+                let max_batch_size = adaptive_batch_sizer
+                    .as_ref()
+                    .map_or(BLOB_STORAGE_SIZE, AdaptiveBatchSizer::batch_size)
+                    .min(max_tps.map_or(BLOB_STORAGE_SIZE, |max_tps| max_tps as usize));
                 // 1. Fetch data from cache and file store.
-                let transaction_data =
-                    match data_fetch(current_version, &mut cache_operator, &file_store_operator)
+                let data_fetch_result = match &replay_range {
+                    Some(replay_range) => {
+                        replay_data_fetch(
+                            current_version,
+                            max_batch_size,
+                            replay_range,
+                            &file_store_operator,
+                        )
                         .await
-                    {
-                        Ok(TransactionsDataStatus::Success(transactions)) => transactions,
-                        Ok(TransactionsDataStatus::AheadOfCache) => {
-                            ahead_of_cache_data_handling().await;
-                            // Retry after a short sleep.
-                            continue;
-                        },
-                        Ok(TransactionsDataStatus::DataGap) => {
-                            data_gap_handling(current_version, &request_metadata);
-                            // End the data stream.
-                            break;
-                        },
-                        Err(e) => {
-                            ERROR_COUNT.with_label_values(&["data_fetch_failed"]).inc();
-                            data_fetch_error_handling(
-                                e,
-                                current_version,
-                                chain_id,
-                                &request_metadata,
-                            )
-                            .await;
-                            // Retry after a short sleep.
-                            continue;
-                        },
-                    };
+                    },
+                    None => {
+                        let stale_cache_file_store_version = match &mut cache_staleness_checker {
+                            Some(cache_staleness_checker) => {
+                                cache_staleness_checker
+                                    .check(
+                                        &mut cache_operator,
+                                        &file_store_operator,
+                                        &data_gap_tracker,
+                                    )
+                                    .await
+                            },
+                            None => None,
+                        };
+                        let fetch = data_fetch(
+                            current_version,
+                            max_batch_size,
+                            &mut cache_operator,
+                            &file_store_operator,
+                            stale_cache_file_store_version,
+                        );
+                        match &data_fetch_coalescer {
+                            Some(data_fetch_coalescer) => {
+                                let key = (
+                                    file_store_bucket_name.clone(),
+                                    current_version,
+                                    stale_cache_file_store_version,
+                                    max_batch_size,
+                                );
+                                data_fetch_coalescer.fetch(key, fetch).await
+                            },
+                            None => fetch.await,
+                        }
+                    },
+                };
+                let (mut transaction_data, source) = match data_fetch_result {
+                    Ok(TransactionsDataStatus::Success(transactions, source)) => {
+                        (transactions, source)
+                    },
+                    Ok(TransactionsDataStatus::AheadOfCache) => {
+                        ahead_of_cache_data_handling(deadline).await;
+                        // Retry after a short sleep.
+                        continue;
+                    },
+                    Ok(TransactionsDataStatus::DataGap) => {
+                        data_gap_handling(current_version, &request_metadata, &data_gap_tracker);
+                        flush_filtered_span(&tx, filtered_span.take(), chain_id as u32).await;
+                        // End the data stream.
+                        break;
+                    },
+                    Err(e) => {
+                        ERROR_COUNT.with_label_values(&["data_fetch_failed"]).inc();
+                        data_fetch_error_handling(
+                            e,
+                            current_version,
+                            chain_id,
+                            &request_metadata,
+                            deadline,
+                        )
+                        .await;
+                        // Retry after a short sleep.
+                        continue;
+                    },
+                };
+
+                if let Some(ending_version) = ending_version {
+                    transaction_data.retain(|(_, version)| *version <= ending_version);
+                }
+                let start_of_batch_version = current_version;
+                let end_of_batch_version = transaction_data.last().unwrap().1;
+                if !transaction_types.is_empty() {
+                    transaction_data.retain(|(encoded, _)| {
+                        is_transaction_type_requested(encoded, &transaction_types)
+                    });
+                }
 
                 // 2. Push the data to the response channel, i.e. stream the data to the client.
+                if transaction_data.is_empty() {
+                    // Everything in the batch is filtered out; fold it into the pending span
+                    // instead of sending an empty batch.
+                    let span = filtered_span.get_or_insert_with(|| {
+                        FilteredVersionSpan::new(start_of_batch_version, end_of_batch_version)
+                    });
+                    span.extend(end_of_batch_version);
+                    if span.is_due(filtered_batch_merge_interval) {
+                        match tx.try_send(Ok(filtered_span_status_builder(span, chain_id as u32))) {
+                            Ok(_) => filtered_span = None,
+                            // Keep the span and report it with the next batch.
+                            Err(TrySendError::Full(_)) => {},
+                            Err(TrySendError::Closed(_)) => {
+                                ERROR_COUNT
+                                    .with_label_values(&["response_channel_closed"])
+                                    .inc();
+                                warn!(
+                                    request_metadata = request_metadata,
+                                    "[Indexer Data] Receiver is closed; exiting."
+                                );
+                                break;
+                            },
+                        }
+                    }
+                    current_version = end_of_batch_version + 1;
+                    if watch_sender.send(current_version).is_err() {
+                        error!(
+                            request_metadata = request_metadata,
+                            "[Indexer Data] Failed to send the current version to the watch channel."
+                        );
+                        break;
+                    }
+                    if let Some(stream_id) = &stream_id {
+                        ack_tracker.record_progress(stream_id, current_version);
+                    }
+                    continue;
+                }
+                if let Some(span) = filtered_span.take() {
+                    // Data resumes; report the skipped versions first so the client's version
+                    // tracking stays contiguous.
+                    if let Err(e) =
+                        tx.try_send(Ok(filtered_span_status_builder(&span, chain_id as u32)))
+                    {
+                        filtered_span = Some(span);
+                        if let TrySendError::Closed(_) = e {
+                            ERROR_COUNT
+                                .with_label_values(&["response_channel_closed"])
+                                .inc();
+                            warn!(
+                                request_metadata = request_metadata,
+                                "[Indexer Data] Receiver is closed; exiting."
+                            );
+                            break;
+                        }
+                        sleep_before_deadline(
+                            Duration::from_millis(RESPONSE_CHANNEL_FULL_BACKOFF_DURATION_MS),
+                            deadline,
+                        )
+                        .await;
+                        continue;
+                    }
+                }
                 let current_batch_size = transaction_data.len();
-                let end_of_batch_version = transaction_data.last().unwrap().1;
+                let current_batch_bytes: usize = transaction_data
+                    .iter()
+                    .map(|(encoded, _)| encoded.len())
+                    .sum();
                 let first_transaction_in_batch =
                     decode_transaction_bytes(transaction_data.first().unwrap().0.as_ref()).unwrap();
                 let data_latency_in_secs = first_transaction_in_batch
                     .timestamp
                     .as_ref()
                     .map(time_diff_since_pb_timestamp_in_secs);
-                let resp_item = raw_datastream_response_builder(transaction_data, chain_id as u32);
-                match tx.try_send(Result::<RawDatastreamResponse, Status>::Ok(resp_item)) {
+                let source = if include_source {
+                    source
+                } else {
+                    TransactionSource::Unspecified
+                };
+                let resp_item = match raw_datastream_response_builder(
+                    transaction_data,
+                    chain_id as u32,
+                    json_output,
+                    source,
+                ) {
+                    Ok(resp_item) => resp_item,
+                    Err(e) => {
+                        ERROR_COUNT
+                            .with_label_values(&["response_build_failed"])
+                            .inc();
+                        error!(
+                            request_metadata = request_metadata,
+                            error = e.to_string(),
+                            "[Indexer Data] Failed to build the response."
+                        );
+                        // The receiver may be closed already, in which case there's no one to
+                        // tell.
+                        let _ = tx
+                            .send(Err(Status::internal(
+                                "[Indexer Data] Cannot build the response.",
+                            )))
+                            .await;
+                        // End the data stream.
+                        break;
+                    },
+                };
+                let send_result =
+                    tx.try_send(Result::<RawDatastreamResponse, Status>::Ok(resp_item));
+                if let Some(adaptive_batch_sizer) = &mut adaptive_batch_sizer {
+                    adaptive_batch_sizer.observe(matches!(send_result, Err(TrySendError::Full(_))));
+                }
+                match send_result {
                     Ok(_) => {
                         PROCESSED_BATCH_SIZE
                             .with_label_values(&[
@@ -261,9 +1288,10 @@ impl IndexerStream for DatastreamServer {
                             request_metadata = request_metadata,
                             "[Indexer Data] Receiver is full; retrying."
                         );
-                        tokio::time::sleep(Duration::from_millis(
-                            RESPONSE_CHANNEL_FULL_BACKOFF_DURATION_MS,
-                        ))
+                        sleep_before_deadline(
+                            Duration::from_millis(RESPONSE_CHANNEL_FULL_BACKOFF_DURATION_MS),
+                            deadline,
+                        )
                         .await;
                         continue;
                     },
@@ -280,6 +1308,8 @@ impl IndexerStream for DatastreamServer {
                 }
                 // 3. Update the current version and record current tps.
                 tps_calculator.tick_now(current_batch_size as u64);
+                total_transactions += current_batch_size as u64;
+                total_bytes += current_batch_bytes as u64;
                 current_version = end_of_batch_version + 1;
                 if watch_sender.send(current_version).is_err() {
                     error!(
@@ -288,6 +1318,9 @@ impl IndexerStream for DatastreamServer {
                     );
                     break;
                 }
+                if let Some(stream_id) = &stream_id {
+                    ack_tracker.record_progress(stream_id, current_version);
+                }
                 info!(
                     request_metadata = request_metadata,
                     current_version = current_version,
@@ -296,24 +1329,36 @@ impl IndexerStream for DatastreamServer {
                     tps = (tps_calculator.avg() * 1000.0) as u64,
                     "[Indexer Data] Sending batch."
                 );
+                tracing::debug!(
+                    start_version = start_of_batch_version,
+                    end_version = end_of_batch_version,
+                    batch_size = current_batch_size,
+                    "Batch sent"
+                );
             }
             info!(
                 request_metadata = request_metadata,
                 "[Indexer Data] Client disconnected."
             );
-        });
+        };
+        tokio::spawn(stream.instrument(stream_span));
 
         tokio::spawn(async move {
+            let _active_stream = active_stream;
             let request_token = request_metadata.request_token.as_str();
             let request_name = request_metadata.request_name.as_str();
             loop {
                 tokio::time::sleep(Duration::from_secs(1)).await;
+                watcher_data_gap_tracker.refresh();
                 match watch_receiver.changed().await.is_ok() {
                     true => {
                         let current_processed_version = *watch_receiver.borrow();
                         OBSERVED_LATEST_PROCESSED_VERSION
                             .with_label_values(&[request_token, request_name])
                             .set(current_processed_version as i64);
+                        if let Some(progress_reporter) = &mut progress_reporter {
+                            progress_reporter.report(current_processed_version).await;
+                        }
                     },
                     false => {
                         info!(
@@ -330,37 +1375,173 @@ impl IndexerStream for DatastreamServer {
             Box::pin(output_stream) as Self::RawDatastreamStream
         ))
     }
+
+    /// AckDatastream records the versions the client processed from an at-least-once stream.
+    async fn ack_datastream(
+        &self,
+        req: Request<AckDatastreamRequest>,
+    ) -> Result<Response<AckDatastreamResponse>, Status> {
+        let request_metadata = get_request_metadata(&req)?;
+        let request = req.into_inner();
+        self.ack_tracker.ack(
+            &request.stream_id,
+            &request_metadata.request_token,
+            request.version,
+        )?;
+        Ok(Response::new(AckDatastreamResponse {}))
+    }
 }
 
 /// Builds the response for the raw datastream request. Partial batch is ok, i.e., a batch with transactions < 1000.
+/// In JSON output mode, transactions are sent as JSON instead of encoded proto data, and it fails
+/// if one can't be converted. Each transaction is tagged with `source` unless it's unspecified.
 fn raw_datastream_response_builder(
     data: Vec<EncodedTransactionWithVersion>,
     chain_id: u32,
-) -> RawDatastreamResponse {
-    RawDatastreamResponse {
+    json_output: bool,
+    source: TransactionSource,
+) -> anyhow::Result<RawDatastreamResponse> {
+    Ok(RawDatastreamResponse {
         response: Some(DatastreamProtoResponse::Data(TransactionsOutput {
             transactions: data
                 .into_iter()
-                .map(|(encoded, version)| TransactionOutput {
-                    encoded_proto_data: encoded,
-                    version,
-                    ..TransactionOutput::default()
+                .map(|(encoded, version)| {
+                    Ok(if json_output {
+                        TransactionOutput {
+                            json_data: encoded_transaction_to_json(&encoded)?,
+                            version,
+                            source: source as i32,
+                            ..TransactionOutput::default()
+                        }
+                    } else {
+                        TransactionOutput {
+                            encoded_proto_data: encoded,
+                            version,
+                            source: source as i32,
+                            ..TransactionOutput::default()
+                        }
+                    })
                 })
-                .collect(),
+                .collect::<anyhow::Result<_>>()?,
+        })),
+        chain_id,
+    })
+}
+
+/// Converts the encoded transaction into its JSON representation.
+fn encoded_transaction_to_json(encoded: &str) -> anyhow::Result<String> {
+    let transaction = decode_transaction_bytes(encoded)
+        .ok_or_else(|| anyhow::anyhow!("Failed to decode the transaction."))?;
+    serde_json::to_string(&transaction)
+        .map_err(|e| anyhow::anyhow!("Failed to serialize the transaction to JSON: {}", e))
+}
+
+/// Whether the encoded transaction is of one of the requested types. This decodes the transaction.
+fn is_transaction_type_requested(encoded: &str, transaction_types: &[i32]) -> bool {
+    let transaction = decode_transaction_bytes(encoded).expect("Failed to decode the transaction.");
+    transaction_types.contains(&transaction.r#type)
+}
+
+/// Builds the progress update for a span of versions whose transactions were all filtered out.
+fn filtered_span_status_builder(
+    span: &FilteredVersionSpan,
+    chain_id: u32,
+) -> RawDatastreamResponse {
+    RawDatastreamResponse {
+        response: Some(DatastreamProtoResponse::Status(StreamStatus {
+            r#type: StatusType::BatchEnd as i32,
+            start_version: span.start_version,
+            end_version: Some(span.end_version),
+            ..StreamStatus::default()
+        })),
+        chain_id,
+    }
+}
+
+/// Reports the span of filtered out versions pending when the stream ends, if any, so that the
+/// client still learns about them. Best effort since the client may be gone already.
+async fn flush_filtered_span(
+    tx: &Sender<Result<RawDatastreamResponse, Status>>,
+    span: Option<FilteredVersionSpan>,
+    chain_id: u32,
+) {
+    if let Some(span) = span {
+        let _ = tx
+            .send(Ok(filtered_span_status_builder(&span, chain_id)))
+            .await;
+    }
+}
+
+fn deadline_exceeded_status_builder(current_version: u64, chain_id: u32) -> RawDatastreamResponse {
+    RawDatastreamResponse {
+        response: Some(DatastreamProtoResponse::Status(StreamStatus {
+            r#type: StatusType::DeadlineExceeded as i32,
+            start_version: current_version,
+            ..StreamStatus::default()
+        })),
+        chain_id,
+    }
+}
+
+/// Builds the status reporting how far a stream is behind the cache.
+fn progress_status_builder(
+    processed_version: u64,
+    cache_latest_version: u64,
+    chain_id: u32,
+) -> RawDatastreamResponse {
+    RawDatastreamResponse {
+        response: Some(DatastreamProtoResponse::Status(StreamStatus {
+            r#type: StatusType::Progress as i32,
+            processed_version: Some(processed_version),
+            cache_latest_version: Some(cache_latest_version),
+            ..StreamStatus::default()
+        })),
+        chain_id,
+    }
+}
+
+/// Builds the status summarizing a stream that reached its ending version.
+fn summary_status_builder(
+    start_version: u64,
+    ending_version: u64,
+    total_transactions: u64,
+    total_bytes: u64,
+    chain_id: u32,
+) -> RawDatastreamResponse {
+    RawDatastreamResponse {
+        response: Some(DatastreamProtoResponse::Status(StreamStatus {
+            r#type: StatusType::Summary as i32,
+            start_version,
+            end_version: Some(ending_version),
+            total_transactions: Some(total_transactions),
+            total_bytes: Some(total_bytes),
+            ..StreamStatus::default()
         })),
         chain_id,
     }
 }
 
 /// Fetches data from cache or the file store. It returns the data if it is ready in the cache or file store.
-/// Otherwise, it returns the status of the data fetching.
+/// Otherwise, it returns the status of the data fetching. At most `max_num_transactions` are
+/// returned.
 async fn data_fetch(
     starting_version: u64,
+    max_num_transactions: usize,
     cache_operator: &mut CacheOperator<redis::aio::Connection>,
     file_store_operator: &FileStoreOperator,
+    stale_cache_file_store_version: Option<u64>,
 ) -> anyhow::Result<TransactionsDataStatus> {
+    // The cache is stale; only serve what's in the file store and wait for the rest.
+    if let Some(file_store_version) = stale_cache_file_store_version {
+        return if starting_version < file_store_version {
+            file_store_data_fetch(starting_version, max_num_transactions, file_store_operator).await
+        } else {
+            Ok(TransactionsDataStatus::AheadOfCache)
+        };
+    }
+
     let batch_get_result = cache_operator
-        .batch_get_encoded_proto_data(starting_version)
+        .batch_get_encoded_proto_data(starting_version, max_num_transactions)
         .await;
 
     match batch_get_result {
@@ -368,40 +1549,104 @@ async fn data_fetch(
         Ok(CacheBatchGetStatus::NotReady) => Ok(TransactionsDataStatus::AheadOfCache),
         Ok(CacheBatchGetStatus::Ok(transactions)) => Ok(TransactionsDataStatus::Success(
             build_protobuf_encoded_transaction_wrappers(transactions, starting_version),
+            TransactionSource::Cache,
         )),
         Ok(CacheBatchGetStatus::EvictedFromCache) => {
             // Data is evicted from the cache. Fetch from file store.
-            let file_store_batch_get_result =
-                file_store_operator.get_transactions(starting_version).await;
-            match file_store_batch_get_result {
-                Ok(transactions) => Ok(TransactionsDataStatus::Success(
-                    build_protobuf_encoded_transaction_wrappers(transactions, starting_version),
-                )),
-                Err(e) => {
-                    if e.to_string().contains("Transactions file not found") {
-                        Ok(TransactionsDataStatus::DataGap)
-                    } else {
-                        Err(e)
-                    }
-                },
-            }
+            file_store_data_fetch(starting_version, max_num_transactions, file_store_operator).await
         },
         Err(e) => Err(e),
     }
 }
 
+/// Fetches data of the replayed range from the file store, relabeled to follow
+/// `starting_version`. A batch never goes past the end of the range, so each round starts with a
+/// new batch.
+async fn replay_data_fetch(
+    starting_version: u64,
+    max_num_transactions: usize,
+    replay_range: &ReplayRange,
+    file_store_operator: &FileStoreOperator,
+) -> anyhow::Result<TransactionsDataStatus> {
+    let source_version = replay_range.source_version(starting_version);
+    let (transactions, source) =
+        match file_store_data_fetch(source_version, max_num_transactions, file_store_operator)
+            .await?
+        {
+            TransactionsDataStatus::Success(transactions, source) => (transactions, source),
+            status => return Ok(status),
+        };
+    Ok(TransactionsDataStatus::Success(
+        transactions
+            .into_iter()
+            .take_while(|(_, version)| *version <= replay_range.end_version)
+            .map(|(encoded, version)| {
+                let relabeled_version = starting_version + (version - source_version);
+                (
+                    relabel_encoded_transaction(&encoded, relabeled_version),
+                    relabeled_version,
+                )
+            })
+            .collect(),
+        source,
+    ))
+}
+
+/// Rewrites the version of the encoded transaction.
+fn relabel_encoded_transaction(encoded: &str, version: u64) -> String {
+    let mut transaction =
+        decode_transaction_bytes(encoded).expect("Failed to decode the transaction.");
+    transaction.version = version;
+    encode_transaction(&transaction)
+}
+
+/// Fetches up to `max_num_transactions` from the file store. The file is downloaded whole
+/// regardless, as the file store can't serve part of one.
+async fn file_store_data_fetch(
+    starting_version: u64,
+    max_num_transactions: usize,
+    file_store_operator: &FileStoreOperator,
+) -> anyhow::Result<TransactionsDataStatus> {
+    let file_store_batch_get_result = file_store_operator.get_transactions(starting_version).await;
+    match file_store_batch_get_result {
+        Ok(mut transactions) => {
+            transactions.truncate(max_num_transactions);
+            Ok(TransactionsDataStatus::Success(
+                build_protobuf_encoded_transaction_wrappers(transactions, starting_version),
+                TransactionSource::FileStore,
+            ))
+        },
+        Err(e) => {
+            if e.to_string().contains("Transactions file not found") {
+                Ok(TransactionsDataStatus::DataGap)
+            } else {
+                Err(e)
+            }
+        },
+    }
+}
+
 /// Handles the case when the data is not ready in the cache, i.e., beyond the current head.
-async fn ahead_of_cache_data_handling() {
+async fn ahead_of_cache_data_handling(deadline: Option<tokio::time::Instant>) {
     // TODO: add exponential backoff.
-    tokio::time::sleep(Duration::from_millis(
-        AHEAD_OF_CACHE_RETRY_SLEEP_DURATION_MS,
-    ))
+    sleep_before_deadline(
+        Duration::from_millis(AHEAD_OF_CACHE_RETRY_SLEEP_DURATION_MS),
+        deadline,
+    )
     .await;
 }
 
 /// Handles data gap errors, i.e., the data is not present in the cache or file store.
-fn data_gap_handling(version: u64, request_metadata: &RequestMetadata) {
-    // TODO(larry): add metrics/alerts to track the gap.
+fn data_gap_handling(
+    version: u64,
+    request_metadata: &RequestMetadata,
+    data_gap_tracker: &DataGapTracker,
+) {
+    DATA_GAP_COUNT
+        .with_label_values(&[&data_gap_version_bucket(version).to_string()])
+        .inc();
+    DATA_GAP_VERSION.set(version as i64);
+    data_gap_tracker.record_gap();
     // Do not crash the server when gap detected since other clients may still be able to get data.
     error!(
         request_metadata = request_metadata,
@@ -410,12 +1655,18 @@ fn data_gap_handling(version: u64, request_metadata: &RequestMetadata) {
     );
 }
 
+/// First version of the bucket the data gaps at `version` are counted in.
+fn data_gap_version_bucket(version: u64) -> u64 {
+    version / DATA_GAP_VERSION_BUCKET_SIZE * DATA_GAP_VERSION_BUCKET_SIZE
+}
+
 /// Handles data fetch errors, including cache and file store related errors.
 async fn data_fetch_error_handling(
     err: anyhow::Error,
     current_version: u64,
     chain_id: u64,
     request_metadata: &RequestMetadata,
+    deadline: Option<tokio::time::Instant>,
 ) {
     error!(
         request_metadata = request_metadata,
@@ -424,14 +1675,44 @@ async fn data_fetch_error_handling(
         "[Indexer Data] Failed to fetch data from cache and file store. {:?}",
         err
     );
-    tokio::time::sleep(Duration::from_millis(
-        TRANSIENT_DATA_ERROR_RETRY_SLEEP_DURATION_MS,
-    ))
+    sleep_before_deadline(
+        Duration::from_millis(TRANSIENT_DATA_ERROR_RETRY_SLEEP_DURATION_MS),
+        deadline,
+    )
     .await;
 }
 
+/// Whether the recent TPS of a stream, measured in transactions per millisecond, is at or above
+/// `max_tps`.
+fn is_at_tps_cap(tps_calculator: &MovingAverage, max_tps: u64) -> bool {
+    tps_calculator.avg() * 1000.0 >= max_tps as f64
+}
+
+/// Sleeps for the given duration, waking up early if the deadline of the stream comes first.
+async fn sleep_before_deadline(duration: Duration, deadline: Option<tokio::time::Instant>) {
+    match deadline {
+        Some(deadline) => tokio::select! {
+            _ = tokio::time::sleep(duration) => {},
+            _ = tokio::time::sleep_until(deadline) => {},
+        },
+        None => tokio::time::sleep(duration).await,
+    }
+}
+
+/// Span of a stream, carrying the request metadata so the stream can be correlated across the data
+/// service and downstream in a tracing backend.
+fn request_span(request_metadata: &RequestMetadata) -> Span {
+    info_span!(
+        "raw_datastream",
+        request_id = request_metadata.request_id.as_str(),
+        request_token = request_metadata.request_token.as_str(),
+        request_name = request_metadata.request_name.as_str(),
+        request_source = request_metadata.request_source.as_str(),
+    )
+}
+
 /// Gets the request metadata. Useful for logging.
-fn get_request_metadata(req: &Request<RawDatastreamRequest>) -> tonic::Result<RequestMetadata> {
+fn get_request_metadata<T>(req: &Request<T>) -> tonic::Result<RequestMetadata> {
     // Request id.
     let request_id = Uuid::new_v4().to_string();
 
@@ -458,6 +1739,15 @@ fn get_request_metadata(req: &Request<RawDatastreamRequest>) -> tonic::Result<Re
         // If the request description is not provided, use "unknown".
         _ => "unknown".to_string(),
     };
+    // Client certificates are only present, and already verified, when mutual TLS is enabled.
+    let request_client_cert_subject = req
+        .peer_certs()
+        .and_then(|certs| {
+            certs
+                .first()
+                .and_then(|cert| get_certificate_subject(cert.get_ref()))
+        })
+        .unwrap_or_default();
     Ok(RequestMetadata {
         request_id,
         request_remote_addr,
@@ -465,5 +1755,503 @@ fn get_request_metadata(req: &Request<RawDatastreamRequest>) -> tonic::Result<Re
         request_name,
         // TODO: after launch, support 'core', 'partner', 'community' and remove 'testing_v1'.
         request_source: "testing_v1".to_string(),
+        request_client_cert_subject,
+        file_store_bucket_name: String::new(),
     })
 }
+
+/// Gets the subject of a DER encoded X.509 certificate, e.g., "O=Aptos, CN=client".
+fn get_certificate_subject(der: &[u8]) -> Option<String> {
+    X509Certificate::from_der(der)
+        .ok()
+        .map(|(_, certificate)| certificate.subject().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_protos::transaction::testing1::v1::{transaction::TransactionType, Transaction};
+
+    fn encoded_transaction(version: u64, transaction_type: TransactionType) -> String {
+        encode_transaction(&Transaction {
+            version,
+            r#type: transaction_type as i32,
+            ..Transaction::default()
+        })
+    }
+
+    fn get_status(response: RawDatastreamResponse) -> StreamStatus {
+        match response.response {
+            Some(DatastreamProtoResponse::Status(status)) => status,
+            _ => panic!("Expected a status"),
+        }
+    }
+
+    fn get_transactions(response: RawDatastreamResponse) -> Vec<TransactionOutput> {
+        match response.response {
+            Some(DatastreamProtoResponse::Data(output)) => output.transactions,
+            _ => panic!("Expected data"),
+        }
+    }
+
+    #[test]
+    fn test_data_gap_tracker() {
+        let tracker = DataGapTracker::new(None);
+        tracker.record_gap();
+        assert!(tracker.recent_gaps.lock().unwrap().is_empty());
+        assert!(tracker.is_healthy(100));
+
+        let tracker = DataGapTracker::new(Some(1));
+        tracker.record_gap();
+        tracker.record_gap();
+        assert_eq!(tracker.recent_gaps.lock().unwrap().len(), 2);
+        assert!(tracker.is_healthy(1));
+        assert!(!tracker.is_healthy(2));
+    }
+
+    #[test]
+    fn test_data_gap_version_bucket() {
+        assert_eq!(data_gap_version_bucket(0), 0);
+        assert_eq!(data_gap_version_bucket(999_999), 0);
+        assert_eq!(data_gap_version_bucket(1_000_000), 1_000_000);
+        assert_eq!(data_gap_version_bucket(2_345_678), 2_000_000);
+    }
+
+    #[test]
+    fn test_is_json_output_allowed() {
+        let server = DatastreamServer::new(IndexerGrpcConfig {
+            redis_address: "127.0.0.1:6379".to_string(),
+            json_output_auth_tokens: Some(vec!["debug_token".to_string()]),
+            ..IndexerGrpcConfig::default()
+        });
+        assert!(server.is_json_output_allowed("debug_token"));
+        assert!(!server.is_json_output_allowed("other_token"));
+
+        let server = DatastreamServer::new(IndexerGrpcConfig {
+            redis_address: "127.0.0.1:6379".to_string(),
+            ..IndexerGrpcConfig::default()
+        });
+        assert!(!server.is_json_output_allowed("debug_token"));
+    }
+
+    #[test]
+    fn test_json_output() {
+        let encoded = encoded_transaction(10, TransactionType::User);
+        let data = vec![(encoded.clone(), 10)];
+        let get_output = |json_output| {
+            let response = raw_datastream_response_builder(
+                data.clone(),
+                1,
+                json_output,
+                TransactionSource::Unspecified,
+            )
+            .unwrap();
+            get_transactions(response).remove(0)
+        };
+
+        let output = get_output(false);
+        assert_eq!(output.version, 10);
+        assert_eq!(output.encoded_proto_data, encoded);
+        assert!(output.json_data.is_empty());
+
+        let output = get_output(true);
+        assert_eq!(output.version, 10);
+        assert!(output.encoded_proto_data.is_empty());
+        let transaction: Transaction = serde_json::from_str(&output.json_data).unwrap();
+        assert_eq!(transaction, decode_transaction_bytes(&encoded).unwrap());
+
+        // A transaction that can't be decoded fails the response instead of panicking.
+        assert!(raw_datastream_response_builder(
+            vec![("not base64".to_string(), 11)],
+            1,
+            true,
+            TransactionSource::Unspecified,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_adaptive_batch_sizer() {
+        let mut sizer = AdaptiveBatchSizer::new("test_adaptive_batch_sizer".to_string(), 0.5);
+        let mut now = Instant::now();
+        assert_eq!(sizer.batch_size, BLOB_STORAGE_SIZE);
+
+        // The channel has been full for a while, i.e., a few decay periods, so the batch size
+        // halves on each send down to the minimum.
+        sizer.observe_at(true, now);
+        let mut expected_batch_size = BLOB_STORAGE_SIZE;
+        while expected_batch_size > MIN_ADAPTIVE_BATCH_SIZE {
+            now += Duration::from_secs(60);
+            sizer.observe_at(true, now);
+            expected_batch_size = (expected_batch_size / 2).max(MIN_ADAPTIVE_BATCH_SIZE);
+            assert_eq!(sizer.batch_size, expected_batch_size);
+            assert!(sizer.channel_full_ratio > 0.99);
+        }
+
+        // The channel is no longer full, but it was until now.
+        now += Duration::from_secs(60);
+        sizer.observe_at(false, now);
+        assert_eq!(sizer.batch_size, MIN_ADAPTIVE_BATCH_SIZE);
+        // A decay period later, the ratio is between half the threshold and the threshold, so the
+        // batch size stays put.
+        now += Duration::from_secs_f64(ADAPTIVE_BATCH_SIZE_DECAY_SECS);
+        sizer.observe_at(false, now);
+        assert!(sizer.channel_full_ratio > 0.25 && sizer.channel_full_ratio < 0.5);
+        assert_eq!(sizer.batch_size, MIN_ADAPTIVE_BATCH_SIZE);
+
+        // Once the channel drains, the batch size doubles on each send up to the maximum.
+        while expected_batch_size < BLOB_STORAGE_SIZE {
+            now += Duration::from_secs(60);
+            sizer.observe_at(false, now);
+            expected_batch_size = (expected_batch_size * 2).min(BLOB_STORAGE_SIZE);
+            assert_eq!(sizer.batch_size, expected_batch_size);
+        }
+
+        // The gauge of the request token is removed once the stream ends.
+        drop(sizer);
+        assert!(ADAPTIVE_BATCH_SIZE
+            .remove_label_values(&["test_adaptive_batch_sizer"])
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sleep_before_deadline() {
+        let start = tokio::time::Instant::now();
+        sleep_before_deadline(
+            Duration::from_secs(3600),
+            Some(start + Duration::from_millis(10)),
+        )
+        .await;
+        assert!(start.elapsed() < Duration::from_secs(60));
+
+        let start = tokio::time::Instant::now();
+        sleep_before_deadline(
+            Duration::from_millis(10),
+            Some(start + Duration::from_secs(3600)),
+        )
+        .await;
+        assert!(start.elapsed() < Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_deadline_exceeded_status() {
+        let response = deadline_exceeded_status_builder(10, 1);
+        assert_eq!(response.chain_id, 1);
+        let status = get_status(response);
+        assert_eq!(status.r#type, StatusType::DeadlineExceeded as i32);
+        assert_eq!(status.start_version, 10);
+    }
+
+    #[test]
+    fn test_cache_staleness_checker() {
+        let mut checker = CacheStalenessChecker::new(60);
+        let now = Instant::now();
+        assert!(checker.is_check_due(now));
+        checker.last_checked_at = Some(now);
+        assert!(!checker.is_check_due(now + Duration::from_secs(1)));
+        let check_interval = Duration::from_secs(CACHE_STALENESS_CHECK_INTERVAL_SECS);
+        assert!(checker.is_check_due(now + check_interval));
+
+        assert!(!checker.is_stale(30.0));
+        assert!(!checker.is_stale(60.0));
+        assert!(checker.is_stale(60.5));
+    }
+
+    #[test]
+    fn test_is_transaction_type_requested() {
+        let user_transaction = encoded_transaction(10, TransactionType::User);
+        let block_metadata = encoded_transaction(11, TransactionType::BlockMetadata);
+        let user = [TransactionType::User as i32];
+        assert!(is_transaction_type_requested(&user_transaction, &user));
+        assert!(!is_transaction_type_requested(&block_metadata, &user));
+
+        let user_or_block_metadata = [
+            TransactionType::User as i32,
+            TransactionType::BlockMetadata as i32,
+        ];
+        assert!(is_transaction_type_requested(
+            &block_metadata,
+            &user_or_block_metadata
+        ));
+    }
+
+    #[test]
+    fn test_replay_range() {
+        let replay_range = ReplayRange {
+            start_version: 100,
+            end_version: 109,
+        };
+        assert_eq!(replay_range.source_version(100), 100);
+        assert_eq!(replay_range.source_version(109), 109);
+        assert_eq!(replay_range.source_version(110), 100);
+        assert_eq!(replay_range.source_version(125), 105);
+
+        let single_version_range = ReplayRange {
+            start_version: 100,
+            end_version: 100,
+        };
+        assert_eq!(single_version_range.source_version(105), 100);
+    }
+
+    #[test]
+    fn test_relabel_encoded_transaction() {
+        let encoded = encoded_transaction(100, TransactionType::User);
+        let relabeled = relabel_encoded_transaction(&encoded, 110);
+        let relabeled = decode_transaction_bytes(&relabeled).unwrap();
+        assert_eq!(relabeled.version, 110);
+        assert_eq!(relabeled.r#type, TransactionType::User as i32);
+    }
+
+    #[test]
+    fn test_request_span() {
+        let request_metadata = RequestMetadata {
+            request_id: "request_id".to_string(),
+            request_remote_addr: "127.0.0.1:12345".to_string(),
+            request_token: "request_token".to_string(),
+            request_name: "request_name".to_string(),
+            request_source: "request_source".to_string(),
+            request_client_cert_subject: String::new(),
+            file_store_bucket_name: String::new(),
+        };
+        tracing::subscriber::with_default(tracing_subscriber::registry(), || {
+            let span = request_span(&request_metadata);
+            let metadata = span.metadata().unwrap();
+            assert_eq!(metadata.name(), "raw_datastream");
+            for field in [
+                "request_id",
+                "request_token",
+                "request_name",
+                "request_source",
+            ] {
+                assert!(metadata.fields().field(field).is_some());
+            }
+        });
+    }
+
+    #[test]
+    fn test_is_at_tps_cap() {
+        let mut tps_calculator = MovingAverage::new(MOVING_AVERAGE_WINDOW_SIZE);
+        // Nothing measured yet.
+        assert!(!is_at_tps_cap(&tps_calculator, 1));
+
+        // 500 transactions over a second.
+        tps_calculator.tick(0, 0);
+        tps_calculator.tick(1000, 500);
+        assert!(is_at_tps_cap(&tps_calculator, 400));
+        assert!(is_at_tps_cap(&tps_calculator, 500));
+        assert!(!is_at_tps_cap(&tps_calculator, 501));
+
+        // Backing off without sending lets the measured TPS decay.
+        tps_calculator.tick(2000, 0);
+        assert!(is_at_tps_cap(&tps_calculator, 250));
+        assert!(!is_at_tps_cap(&tps_calculator, 251));
+    }
+
+    #[tokio::test]
+    async fn test_file_store_range_cache_hit() {
+        let cache = FileStoreRangeCache::new(3600);
+        let file_store_operator = FileStoreOperator::new("bucket".to_string());
+        {
+            let mut cached = cache.cached.lock().unwrap();
+            cached.insert("bucket".to_string(), (Instant::now(), Some((0, 99))));
+            cached.insert("empty_bucket".to_string(), (Instant::now(), None));
+        }
+        // Within the TTL, the ranges are served without reaching the file store.
+        assert_eq!(
+            cache.get("bucket", &file_store_operator).await,
+            Some((0, 99))
+        );
+        assert_eq!(cache.get("empty_bucket", &file_store_operator).await, None);
+    }
+
+    #[test]
+    fn test_ack_tracker() {
+        let tracker = AckTracker::new();
+        let stream_id = tracker.register("token", 100);
+        // Nothing was streamed yet.
+        let err = tracker.ack(&stream_id, "token", 100).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+        tracker.record_progress(&stream_id, 200);
+        tracker.ack(&stream_id, "token", 149).unwrap();
+        // Acknowledging an older version doesn't move the resume version back.
+        tracker.ack(&stream_id, "token", 120).unwrap();
+        assert_eq!(tracker.resume(&stream_id, "token").unwrap(), 150);
+        // Resuming rewinds the stream, so the versions after the resume version were not streamed.
+        let err = tracker.ack(&stream_id, "token", 150).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+        let err = tracker.resume(&stream_id, "other_token").unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+        let err = tracker.ack(&stream_id, "other_token", 100).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+        let err = tracker.resume("unknown", "token").unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+    }
+
+    #[test]
+    fn test_ack_tracker_evicts_expired_streams() {
+        let tracker = AckTracker::new();
+        let stream_id = tracker.register("token", 100);
+        let ttl = Duration::from_secs(ACKED_STREAM_TTL_SECS);
+        let mut streams = tracker.streams.lock().unwrap();
+        AckTracker::evict_expired(&mut streams, Instant::now() + ttl / 2);
+        assert!(streams.contains_key(&stream_id));
+        AckTracker::evict_expired(&mut streams, Instant::now() + ttl);
+        assert!(streams.is_empty());
+    }
+
+    #[test]
+    fn test_summary_status() {
+        let response = summary_status_builder(100, 199, 80, 4096, 1);
+        assert_eq!(response.chain_id, 1);
+        let status = get_status(response);
+        assert_eq!(status.r#type, StatusType::Summary as i32);
+        assert_eq!(status.start_version, 100);
+        assert_eq!(status.end_version, Some(199));
+        assert_eq!(status.total_transactions, Some(80));
+        assert_eq!(status.total_bytes, Some(4096));
+    }
+
+    fn fetched(version: u64) -> anyhow::Result<TransactionsDataStatus> {
+        Ok(TransactionsDataStatus::Success(
+            vec![(encoded_transaction(version, TransactionType::User), version)],
+            TransactionSource::Cache,
+        ))
+    }
+
+    fn get_fetched_versions(result: anyhow::Result<TransactionsDataStatus>) -> Vec<u64> {
+        match result.unwrap() {
+            TransactionsDataStatus::Success(transactions, _) => transactions
+                .into_iter()
+                .map(|(_, version)| version)
+                .collect(),
+            _ => panic!("Expected data"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_data_fetch_coalescer() {
+        let coalescer = DataFetchCoalescer::new();
+        let key = ("bucket".to_string(), 100, None, BLOB_STORAGE_SIZE);
+        let (release_tx, release_rx) = oneshot::channel();
+        let first_fetch = coalescer.fetch(key.clone(), async move {
+            release_rx.await.unwrap();
+            fetched(100)
+        });
+        // Identical to the first one in flight, so it gets the result of the first one instead.
+        let second_fetch = coalescer.fetch(key.clone(), async { fetched(0) });
+        // A different batch.
+        let other_fetch = coalescer.fetch(
+            ("bucket".to_string(), 200, None, BLOB_STORAGE_SIZE),
+            async { fetched(200) },
+        );
+        let (first_result, second_result, other_result, _) =
+            tokio::join!(first_fetch, second_fetch, other_fetch, async move {
+                release_tx.send(()).unwrap()
+            });
+        assert_eq!(get_fetched_versions(first_result), vec![100]);
+        assert_eq!(get_fetched_versions(second_result), vec![100]);
+        assert_eq!(get_fetched_versions(other_result), vec![200]);
+        assert!(coalescer.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_data_fetch_coalescer_first_fetch_dropped() {
+        let coalescer = DataFetchCoalescer::new();
+        let key = ("bucket".to_string(), 100, None, BLOB_STORAGE_SIZE);
+        let mut first_fetch = Box::pin(coalescer.fetch(key.clone(), futures::future::pending()));
+        assert!(futures::poll!(&mut first_fetch).is_pending());
+        let mut second_fetch = Box::pin(coalescer.fetch(key, async { fetched(100) }));
+        assert!(futures::poll!(&mut second_fetch).is_pending());
+        // The second stream runs the fetch itself once the first one goes away.
+        drop(first_fetch);
+        assert_eq!(get_fetched_versions(second_fetch.await), vec![100]);
+    }
+
+    #[test]
+    fn test_transaction_source() {
+        let data = vec![
+            (encoded_transaction(10, TransactionType::User), 10),
+            (encoded_transaction(11, TransactionType::User), 11),
+        ];
+        for source in [
+            TransactionSource::Cache,
+            TransactionSource::FileStore,
+            TransactionSource::Unspecified,
+        ] {
+            let response = raw_datastream_response_builder(data.clone(), 1, false, source).unwrap();
+            let transactions = get_transactions(response);
+            assert_eq!(transactions.len(), 2);
+            assert!(transactions.iter().all(|t| t.source() == source));
+        }
+    }
+
+    #[test]
+    fn test_active_stream() {
+        let request_token = "test_active_stream_token";
+        let active_streams = || ACTIVE_STREAMS.with_label_values(&[request_token]).get();
+        let stream = ActiveStream::new(request_token);
+        let other_stream = Arc::new(ActiveStream::new(request_token));
+        assert_eq!(active_streams(), 2);
+
+        drop(stream);
+        assert_eq!(active_streams(), 1);
+        // Shared by the tasks of a stream, so it's only dropped with the last of them.
+        let shared = other_stream.clone();
+        drop(other_stream);
+        assert_eq!(active_streams(), 1);
+        drop(shared);
+        assert_eq!(active_streams(), 0);
+    }
+
+    #[test]
+    fn test_filtered_version_span() {
+        let mut span = FilteredVersionSpan::new(10, 19);
+        span.extend(29);
+        assert!(span.is_due(Duration::ZERO));
+        assert!(!span.is_due(Duration::from_secs(3600)));
+
+        let response = filtered_span_status_builder(&span, 1);
+        assert_eq!(response.chain_id, 1);
+        let status = get_status(response);
+        assert_eq!(status.r#type, StatusType::BatchEnd as i32);
+        assert_eq!(status.start_version, 10);
+        assert_eq!(status.end_version, Some(29));
+    }
+
+    #[test]
+    fn test_get_certificate_subject() {
+        let pem = b"-----BEGIN CERTIFICATE-----
+MIIBmTCCAT+gAwIBAgIUMPg++9Krivht4aUEk4VPHl0Y6N8wCgYIKoZIzj0EAwIw
+ITEOMAwGA1UECgwFQXB0b3MxDzANBgNVBAMMBmNsaWVudDAgFw0yNjEwMTYxNzE4
+NTZaGA8yMTI2MDkyMjE3MTg1NlowITEOMAwGA1UECgwFQXB0b3MxDzANBgNVBAMM
+BmNsaWVudDBZMBMGByqGSM49AgEGCCqGSM49AwEHA0IABJ/urGJYqXj+lSIm5rH0
+oknDONV9tYpl74qBirGWRen3idIfIYqprUVQzPoi3I/JJNmJvicnJCtVhwsb4tkz
+A0+jUzBRMB0GA1UdDgQWBBSpKibyiNXO05Q1dRwkl88wzWyrOzAfBgNVHSMEGDAW
+gBSpKibyiNXO05Q1dRwkl88wzWyrOzAPBgNVHRMBAf8EBTADAQH/MAoGCCqGSM49
+BAMCA0gAMEUCIA2oUNEeu3rX8dOq2U+Ukv/UhaOhIHkFFRSn5EVLcjVPAiEA8yOP
+uykwG98L7XNOFp3otSu7NDUtn0tvAOlqa6uUFFE=
+-----END CERTIFICATE-----
+";
+        let (_, pem) = x509_parser::pem::parse_x509_pem(pem).unwrap();
+        assert_eq!(
+            get_certificate_subject(&pem.contents),
+            Some("O=Aptos, CN=client".to_string())
+        );
+        assert_eq!(get_certificate_subject(&pem.contents[1..]), None);
+        assert_eq!(get_certificate_subject(b""), None);
+    }
+
+    #[tokio::test]
+    async fn test_flush_filtered_span() {
+        let (tx, mut rx) = channel(1);
+        flush_filtered_span(&tx, None, 1).await;
+        assert!(rx.try_recv().is_err());
+
+        flush_filtered_span(&tx, Some(FilteredVersionSpan::new(10, 19)), 1).await;
+        let status = get_status(rx.try_recv().unwrap().unwrap());
+        assert_eq!(status.start_version, 10);
+        assert_eq!(status.end_version, Some(19));
+    }
+}
@@ -94,7 +94,7 @@ impl Processor {
                 .cache_operator
                 .as_mut()
                 .unwrap()
-                .batch_get_encoded_proto_data(current_cache_version)
+                .batch_get_encoded_proto_data(current_cache_version, BLOB_STORAGE_SIZE)
                 .await;
 
             let batch_get_result =
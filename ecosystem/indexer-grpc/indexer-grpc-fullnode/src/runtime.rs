@@ -11,7 +11,8 @@ use aptos_protos::datastream::v1::{
     indexer_stream_server::{IndexerStream, IndexerStreamServer},
     raw_datastream_response,
     stream_status::StatusType,
-    RawDatastreamRequest, RawDatastreamResponse, StreamStatus,
+    AckDatastreamRequest, AckDatastreamResponse, RawDatastreamRequest, RawDatastreamResponse,
+    StreamStatus,
 };
 use aptos_storage_interface::DbReader;
 use aptos_types::chain_id::ChainId;
@@ -184,6 +185,16 @@ impl IndexerStream for IndexerStreamService {
             Box::pin(output_stream) as Self::RawDatastreamStream
         ))
     }
+
+    /// Streams from the fullnode are always at most once, so there is nothing to acknowledge.
+    async fn ack_datastream(
+        &self,
+        _req: Request<AckDatastreamRequest>,
+    ) -> Result<Response<AckDatastreamResponse>, Status> {
+        Err(Status::unimplemented(
+            "Streams from the fullnode don't support acknowledgements",
+        ))
+    }
 }
 
 impl IndexerStreamService {
@@ -198,6 +209,7 @@ impl IndexerStreamService {
                 r#type: status_type as i32,
                 start_version,
                 end_version,
+                ..StreamStatus::default()
             })),
             chain_id: ledger_chain_id as u32,
         }
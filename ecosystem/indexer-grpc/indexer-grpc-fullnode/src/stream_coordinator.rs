@@ -346,6 +346,7 @@ impl IndexerStreamCoordinator {
                     encoded_proto_data,
                     version: txn.version,
                     timestamp: txn.timestamp.clone(),
+                    ..TransactionOutput::default()
                 }
             })
             .collect()
@@ -449,6 +449,15 @@ pub fn grpc_request_builder(
     let mut request = tonic::Request::new(RawDatastreamRequest {
         starting_version: Some(starting_version),
         transactions_count: None,
+        json_output: None,
+        deadline_ms: None,
+        transaction_types: vec![],
+        replay_end_version: None,
+        max_tps: None,
+        delivery_semantics: 0,
+        resume_stream_id: None,
+        ending_version: None,
+        include_source: None,
     });
     request.metadata_mut().insert(
         aptos_indexer_grpc_utils::constants::GRPC_AUTH_TOKEN_HEADER,
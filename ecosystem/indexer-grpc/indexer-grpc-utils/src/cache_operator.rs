@@ -187,6 +187,35 @@ impl<T: redis::aio::ConnectionLike + Send> CacheOperator<T> {
         }
     }
 
+    // Gets the latest version in cache, exclusive, i.e., the version of the next transaction to
+    // be cached. Downstream system can tell how far behind the cache it is from it.
+    pub async fn get_latest_version(&mut self) -> anyhow::Result<u64> {
+        let latest_version: u64 = match self
+            .conn
+            .get::<&str, String>(CACHE_KEY_LATEST_VERSION)
+            .await
+        {
+            Ok(v) => v
+                .parse::<u64>()
+                .expect("Redis latest_version is not a number."),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(latest_version)
+    }
+
+    // Gets the encoded proto data of the latest transaction in cache, if any. Downstream system
+    // can tell how fresh the cache is from it.
+    pub async fn get_latest_encoded_proto_data(&mut self) -> anyhow::Result<Option<String>> {
+        let latest_version = self.get_latest_version().await?;
+        if latest_version == 0 {
+            return Ok(None);
+        }
+        Ok(self
+            .conn
+            .get::<u64, Option<String>>(latest_version - 1)
+            .await?)
+    }
+
     pub async fn update_cache_transactions(
         &mut self,
         transactions: Vec<(u64, String, u64)>,
@@ -248,13 +277,16 @@ impl<T: redis::aio::ConnectionLike + Send> CacheOperator<T> {
         }
     }
 
+    /// Gets up to `max_num_transactions` consecutive transactions starting at `start_version`.
     pub async fn batch_get_encoded_proto_data(
         &mut self,
         start_version: u64,
+        max_num_transactions: usize,
     ) -> anyhow::Result<CacheBatchGetStatus> {
         let cache_coverage_status = self.check_cache_coverage_status(start_version).await;
         match cache_coverage_status {
             Ok(CacheCoverageStatus::CacheHit(v)) => {
+                let v = v.min(max_num_transactions as u64);
                 let versions = (start_version..start_version + v)
                     .map(|e| e.to_string())
                     .collect::<Vec<String>>();
@@ -403,7 +435,7 @@ mod tests {
 
         assert_eq!(
             cache_operator
-                .batch_get_encoded_proto_data(0)
+                .batch_get_encoded_proto_data(0, BLOB_STORAGE_SIZE)
                 .await
                 .unwrap(),
             CacheBatchGetStatus::Ok(vec!["t0".to_string(), "t1".to_string(), "t2".to_string()])
@@ -428,13 +460,38 @@ mod tests {
 
         assert_eq!(
             cache_operator
-                .batch_get_encoded_proto_data(1)
+                .batch_get_encoded_proto_data(1, BLOB_STORAGE_SIZE)
                 .await
                 .unwrap(),
             CacheBatchGetStatus::Ok((1..1001).map(|e| format!("t{}", e)).collect())
         );
     }
 
+    #[tokio::test]
+    async fn cache_batch_get_status_ok_with_limit() {
+        let bulck_value = redis::Value::Bulk(
+            (1..11)
+                .map(|e| redis::Value::Data(format!("t{}", e).as_bytes().to_vec()))
+                .collect(),
+        );
+        let keys = (1..11).map(|e| e.to_string()).collect::<Vec<String>>();
+        let cmds = vec![
+            MockCmd::new(redis::cmd("GET").arg(CACHE_KEY_LATEST_VERSION), Ok("1003")),
+            MockCmd::new(redis::cmd("MGET").arg(keys), Ok(bulck_value)),
+        ];
+        let mock_connection = MockRedisConnection::new(cmds);
+        let mut cache_operator: CacheOperator<MockRedisConnection> =
+            CacheOperator::new(mock_connection);
+
+        assert_eq!(
+            cache_operator
+                .batch_get_encoded_proto_data(1, 10)
+                .await
+                .unwrap(),
+            CacheBatchGetStatus::Ok((1..11).map(|e| format!("t{}", e)).collect())
+        );
+    }
+
     #[tokio::test]
     async fn cache_batch_get_status_cache_evicted() {
         let cmds = vec![MockCmd::new(
@@ -447,7 +504,7 @@ mod tests {
 
         assert_eq!(
             cache_operator
-                .batch_get_encoded_proto_data(1)
+                .batch_get_encoded_proto_data(1, BLOB_STORAGE_SIZE)
                 .await
                 .unwrap(),
             CacheBatchGetStatus::EvictedFromCache
@@ -466,7 +523,7 @@ mod tests {
 
         assert_eq!(
             cache_operator
-                .batch_get_encoded_proto_data(100_000_000)
+                .batch_get_encoded_proto_data(100_000_000, BLOB_STORAGE_SIZE)
                 .await
                 .unwrap(),
             CacheBatchGetStatus::NotReady
@@ -488,4 +545,17 @@ mod tests {
 
         assert_eq!(cache_operator.get_chain_id().await.unwrap(), 123);
     }
+
+    #[tokio::test]
+    async fn cache_latest_version_ok() {
+        let cmds = vec![MockCmd::new(
+            redis::cmd("GET").arg(CACHE_KEY_LATEST_VERSION),
+            Ok("123"),
+        )];
+        let mock_connection = MockRedisConnection::new(cmds);
+        let mut cache_operator: CacheOperator<MockRedisConnection> =
+            CacheOperator::new(mock_connection);
+
+        assert_eq!(cache_operator.get_latest_version().await.unwrap(), 123);
+    }
 }
@@ -23,6 +23,77 @@ pub struct IndexerGrpcConfig {
     /// Whitelisted auth tokens, e.g., "token1,token2". Only used by Data Service.
     /// If this is not set, no tokens are allowed.
     pub whitelisted_auth_tokens: Option<Vec<String>>,
+    /// Max number of data gaps per minute before the data service reports itself unhealthy.
+    /// Only used by Data Service. If this is not set, data gaps never affect the health gauge.
+    pub data_gap_alert_threshold_per_minute: Option<u64>,
+    /// Auth tokens allowed to request JSON output, which is meant for low-volume debugging only.
+    /// Only used by Data Service. If this is not set, no tokens are allowed.
+    pub json_output_auth_tokens: Option<Vec<String>>,
+    /// Path to the PEM encoded TLS certificate of the data service, e.g., "/opt/tls/server.crt".
+    /// Only used by Data Service. TLS is enabled if this and `data_service_tls_key_path` are set.
+    pub data_service_tls_cert_path: Option<String>,
+    /// Path to the PEM encoded private key of the TLS certificate. Only used by Data Service.
+    pub data_service_tls_key_path: Option<String>,
+    /// Path to the PEM encoded CA certificate to verify client certificates with. Only used by
+    /// Data Service. If this is set, clients must present a valid certificate, i.e., mutual TLS.
+    pub data_service_tls_client_ca_path: Option<String>,
+    /// Exponentially weighted fraction of time, between 0 and 1, a stream's response channel may
+    /// spend full before its batch size is reduced to match the client's consumption rate. Only
+    /// used by Data Service. If this is not set, full batches are always sent.
+    pub adaptive_batch_size_channel_full_threshold: Option<f64>,
+    /// Max age in seconds of the latest transaction in cache before the cache is considered
+    /// stale, i.e., the cache worker stalled. Streams then only serve data from the file store and
+    /// the data service reports itself unhealthy. Only used by Data Service. If this is not set,
+    /// the cache is always trusted.
+    pub cache_staleness_threshold_secs: Option<u64>,
+    /// Auth tokens allowed to request a replay of a fixed version range, which is meant for load
+    /// testing only. Only used by Data Service. If this is not set, no tokens are allowed.
+    pub replay_auth_tokens: Option<Vec<String>>,
+    /// Whether to run each stream in a tracing span with the request id, token, name and source as
+    /// fields, so a client's stream can be followed end-to-end in a tracing backend. Only used by
+    /// Data Service. If this is not set, streams are not traced.
+    pub enable_request_tracing: Option<bool>,
+    /// Seconds to cache the range of versions archived in the file store for, which is reported in
+    /// the INIT status of each stream so clients can pick a starting version within it. Only used
+    /// by Data Service. If this is not set, the range is not reported.
+    pub file_store_range_cache_ttl_secs: Option<u64>,
+    /// Whether concurrent streams fetching the same batch share a single fetch from the cache or
+    /// file store, e.g., when many clients reconnect at the same version after a restart. Only
+    /// used by Data Service. If this is not set, each stream fetches on its own.
+    pub coalesce_data_fetches: Option<bool>,
+    /// Experimental file store bucket for some streams to read from instead of
+    /// `file_store_bucket_name`, e.g., to A/B test a new file store backend. Only used by Data
+    /// Service. If this is not set, all streams read from `file_store_bucket_name`.
+    pub file_store_experiment: Option<FileStoreExperimentConfig>,
+    /// Whether to report the number of streams each request token currently holds open, so that
+    /// tokens opening excessive connections can be spotted. Only used by Data Service. If this is
+    /// not set, the counts are not reported.
+    pub enable_active_stream_counts: Option<bool>,
+    /// Seconds between the PROGRESS statuses sent to the client of each stream, with the version
+    /// the stream has processed up to and the latest version in cache, so that clients can tell
+    /// how far behind they are without computing it. Only used by Data Service. If this is not
+    /// set, no PROGRESS statuses are sent.
+    pub progress_status_interval_secs: Option<u64>,
+    /// Max milliseconds to merge consecutive batches with every transaction filtered out for. The
+    /// merged batches are reported to the client as a single progress update covering their
+    /// versions, sent once data resumes or this long after the first of them. Only used by Data
+    /// Service. If this is not set, each such batch is reported on its own.
+    pub filtered_batch_merge_interval_ms: Option<u64>,
+}
+
+/// Selects the streams reading from an experimental file store bucket. A stream is selected if
+/// its request token is allowlisted or falls within the traffic percentage.
+#[derive(Clone, Debug, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FileStoreExperimentConfig {
+    /// Experimental file store bucket name, e.g., "indexer-grpc-file-store-v2".
+    pub bucket_name: String,
+    /// Percentage, between 0 and 100, of request tokens to select. Tokens are selected by their
+    /// hash, so the streams of a token always read from the same bucket. If this is not set, no
+    /// tokens are selected by percentage.
+    pub traffic_percentage: Option<u8>,
+    /// Request tokens to always select, e.g., for testing the experimental bucket first.
+    pub allowlisted_tokens: Option<Vec<String>>,
 }
 
 /// Indexer GRPC Processor configuration. This is to configure the processors,
@@ -50,7 +121,24 @@ pub struct IndexerGrpcProcessorConfig {
 
 impl IndexerGrpcConfig {
     pub fn load(path: PathBuf) -> Result<Self, anyhow::Error> {
-        load::<Self>(path)
+        let config = load::<Self>(path)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks the values whose valid range is narrower than their type's.
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        if let Some(experiment) = &self.file_store_experiment {
+            if let Some(traffic_percentage) = experiment.traffic_percentage {
+                if traffic_percentage > 100 {
+                    anyhow::bail!(
+                        "File store experiment traffic percentage must be at most 100, got {}",
+                        traffic_percentage
+                    );
+                }
+            }
+        }
+        Ok(())
     }
 }
 
@@ -85,3 +173,23 @@ pub fn load<T: for<'de> serde::Deserialize<'de>>(path: PathBuf) -> Result<T, any
         )
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_file_store_experiment_traffic_percentage() {
+        let config_with_percentage = |traffic_percentage| IndexerGrpcConfig {
+            file_store_experiment: Some(FileStoreExperimentConfig {
+                traffic_percentage,
+                ..FileStoreExperimentConfig::default()
+            }),
+            ..IndexerGrpcConfig::default()
+        };
+        assert!(IndexerGrpcConfig::default().validate().is_ok());
+        assert!(config_with_percentage(None).validate().is_ok());
+        assert!(config_with_percentage(Some(100)).validate().is_ok());
+        assert!(config_with_percentage(Some(101)).validate().is_err());
+    }
+}
@@ -138,6 +138,16 @@ impl FileStoreOperator {
         }
     }
 
+    /// Gets the range of versions archived in the file store, inclusive, or `None` if it's empty.
+    /// The file store is always filled from version 0 on.
+    pub async fn get_archived_version_range(&self) -> Option<(u64, u64)> {
+        let metadata = self.get_file_store_metadata().await?;
+        metadata
+            .version
+            .checked_sub(1)
+            .map(|max_version| (0, max_version))
+    }
+
     /// If the file store is empty, the metadata will be created; otherwise, return the existing metadata.
     pub async fn create_default_file_store_metadata_if_absent(
         &mut self,
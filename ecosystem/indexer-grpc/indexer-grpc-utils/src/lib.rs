@@ -79,6 +79,10 @@ pub fn decode_transaction_bytes(encoded_pb: &str) -> Option<Transaction> {
     Transaction::decode(transaction_pb.as_slice()).ok()
 }
 
+pub fn encode_transaction(transaction: &Transaction) -> String {
+    base64::encode(transaction.encode_to_vec())
+}
+
 pub fn time_diff_since_pb_timestamp_in_secs(timestamp: &Timestamp) -> f64 {
     let current_timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -5,12 +5,12 @@
 use crate::{add_accounts_impl, benchmark_transaction::BenchmarkTransaction};
 use aptos_config::{
     config::{
-        PrunerConfig, RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS,
-        DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD, NO_OP_STORAGE_PRUNER_CONFIG,
+        PrunerConfig, RocksdbConfigs, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+        NO_OP_STORAGE_PRUNER_CONFIG,
     },
     utils::get_genesis_txn,
 };
-use aptos_db::AptosDB;
+use aptos_db::{AptosDB, StateStoreOptions};
 use aptos_executor::{
     block_executor::TransactionBlockExecutor,
     db_bootstrapper::{generate_waypoint, maybe_bootstrap},
@@ -72,8 +72,8 @@ fn bootstrap_with_genesis(db_dir: impl AsRef<Path>, use_state_kv_db: bool) {
             NO_OP_STORAGE_PRUNER_CONFIG,
             rocksdb_configs,
             false, /* indexer */
-            BUFFERED_STATE_TARGET_ITEMS,
             DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            StateStoreOptions::default(),
         )
         .expect("DB should open."),
     );
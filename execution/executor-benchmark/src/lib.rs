@@ -18,7 +18,7 @@ use crate::{
     transaction_generator::TransactionGenerator,
 };
 use aptos_config::config::{NodeConfig, PrunerConfig};
-use aptos_db::AptosDB;
+use aptos_db::{AptosDB, StateStoreOptions};
 use aptos_executor::block_executor::{BlockExecutor, TransactionBlockExecutor};
 use aptos_jellyfish_merkle::metrics::{
     APTOS_JELLYFISH_INTERNAL_ENCODED_BYTES, APTOS_JELLYFISH_LEAF_ENCODED_BYTES,
@@ -40,8 +40,8 @@ where
             config.storage.storage_pruner_config,
             config.storage.rocksdb_configs,
             false,
-            config.storage.buffered_state_target_items,
             config.storage.max_num_nodes_per_lru_cache_shard,
+            StateStoreOptions::from(&config.storage),
         )
         .expect("DB should open."),
     );
@@ -70,6 +70,7 @@ impl PrunerOpt {
                 enable: self.enable_epoch_snapshot_pruner,
                 prune_window: self.epoch_snapshot_prune_window,
                 batch_size: self.epoch_snapshot_pruning_batch_size,
+                num_snapshots_to_retain: 0,
             },
             ledger_pruner_config: LedgerPrunerConfig {
                 enable: self.enable_ledger_pruner,
@@ -11,7 +11,8 @@ use aptos_crypto::{hash::SPARSE_MERKLE_PLACEHOLDER_HASH, HashValue};
 use aptos_executor_types::BlockExecutorTrait;
 use aptos_state_view::StateView;
 use aptos_storage_interface::{
-    cached_state_view::CachedStateView, state_delta::StateDelta, DbReader, DbReaderWriter, DbWriter,
+    cached_state_view::CachedStateView, state_delta::StateDelta, AsyncCommitChannelStatus,
+    DbReader, DbReaderWriter, DbWriter,
 };
 use aptos_types::{
     ledger_info::LedgerInfoWithSignatures,
@@ -89,7 +90,7 @@ impl DbWriter for FakeDb {
         _ledger_info_with_sigs: Option<&LedgerInfoWithSignatures>,
         _sync_commit: bool,
         _in_memory_state: StateDelta,
-    ) -> Result<()> {
-        Ok(())
+    ) -> Result<AsyncCommitChannelStatus> {
+        Ok(AsyncCommitChannelStatus::Available)
     }
 }
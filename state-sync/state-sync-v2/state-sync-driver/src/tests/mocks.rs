@@ -18,8 +18,8 @@ use aptos_data_streaming_service::{
 };
 use aptos_executor_types::{ChunkCommitNotification, ChunkExecutorTrait};
 use aptos_storage_interface::{
-    state_delta::StateDelta, DbReader, DbReaderWriter, DbWriter, ExecutedTrees, Order,
-    StateSnapshotReceiver,
+    state_delta::StateDelta, AsyncCommitChannelStatus, DbReader, DbReaderWriter, DbWriter,
+    ExecutedTrees, Order, StateSnapshotReceiver,
 };
 use aptos_types::{
     account_address::AccountAddress,
@@ -302,7 +302,7 @@ mock! {
             ledger_info_with_sigs: Option<&'a LedgerInfoWithSignatures>,
             sync_commit: bool,
             in_memory_state: StateDelta,
-        ) -> Result<()>;
+        ) -> Result<AsyncCommitChannelStatus>;
     }
 }
 
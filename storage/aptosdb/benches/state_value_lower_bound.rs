@@ -0,0 +1,48 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_db::{test_helper::put_state_value_versions, AptosDB};
+use aptos_storage_interface::DbReader;
+use aptos_temppath::TempPath;
+use aptos_types::state_store::{state_key::StateKey, state_value::StateValue};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Number of versions the key is written at.
+const NUM_WRITES: u64 = 5000;
+
+fn lower_bound_benches(c: &mut Criterion) {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let key = StateKey::raw(b"hot_key".to_vec());
+    // The key is written at every even version, so the read at the odd version below resolves to
+    // the write right before it.
+    put_state_value_versions(
+        &db,
+        &key,
+        &StateValue::from(vec![0u8; 100]),
+        (0..NUM_WRITES).map(|i| i * 2),
+    );
+    let version = NUM_WRITES * 2 - 1;
+
+    let mut group = c.benchmark_group("get_state_value_by_version");
+    group.bench_function("without_hint", |b| {
+        b.iter(|| db.get_state_value_by_version(&key, version).unwrap())
+    });
+    group.bench_function("with_hint", |b| {
+        b.iter(|| {
+            db.get_state_value_by_version_with_lower_bound(&key, version, Some(version - 10))
+                .unwrap()
+        })
+    });
+    // No version in the hinted range, so the read falls back to the unbounded one.
+    group.bench_function("with_missed_hint", |b| {
+        b.iter(|| {
+            db.get_state_value_by_version_with_lower_bound(&key, version, Some(version))
+                .unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, lower_bound_benches);
+criterion_main!(benches);
@@ -8,13 +8,14 @@ use crate::{
         state_merkle_pruner_manager::StateMerklePrunerManager,
     },
     test_helper,
-    test_helper::{arb_blocks_to_commit, put_as_state_root, put_transaction_info},
-    AptosDB, PrunerManager, StaleNodeIndexSchema,
+    test_helper::{
+        arb_blocks_to_commit, put_as_state_root, put_state_value_versions, put_transaction_info,
+    },
+    AptosDB, PrunerManager, StaleNodeIndexSchema, StateStoreOptions,
 };
 use aptos_config::config::{
     EpochSnapshotPrunerConfig, LedgerPrunerConfig, PrunerConfig, RocksdbConfigs,
-    StateMerklePrunerConfig, BUFFERED_STATE_TARGET_ITEMS,
-    DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+    StateMerklePrunerConfig, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
 };
 use aptos_crypto::{hash::CryptoHash, HashValue};
 use aptos_storage_interface::{DbReader, DbWriter, ExecutedTrees, Order};
@@ -175,6 +176,29 @@ fn test_get_latest_executed_trees() {
     );
 }
 
+#[test]
+fn test_get_state_value_by_version_with_lower_bound() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let key = StateKey::raw(b"key".to_vec());
+    let value = StateValue::from(b"value".to_vec());
+    put_state_value_versions(&db, &key, &value, [2, 4]);
+
+    // The hint doesn't change the result, whether or not the key was written at or after it.
+    for lower_bound_version in [None, Some(0), Some(3), Some(5)] {
+        assert_eq!(
+            db.get_state_value_by_version_with_lower_bound(&key, 1, lower_bound_version)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            db.get_state_value_by_version_with_lower_bound(&key, 5, lower_bound_version)
+                .unwrap(),
+            Some(value.clone())
+        );
+    }
+}
+
 pub fn test_state_merkle_pruning_impl(
     input: Vec<(Vec<TransactionToCommit>, LedgerInfoWithSignatures)>,
 ) {
@@ -199,12 +223,13 @@ pub fn test_state_merkle_pruning_impl(
                 enable: true,
                 prune_window: 10,
                 batch_size: 1,
+                num_snapshots_to_retain: 0,
             },
         },
         RocksdbConfigs::default(),
         false, /* enable_indexer */
-        BUFFERED_STATE_TARGET_ITEMS,
         DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+        StateStoreOptions::default(),
     )
     .unwrap();
 
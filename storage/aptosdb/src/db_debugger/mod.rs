@@ -4,6 +4,7 @@
 pub mod checkpoint;
 mod common;
 pub mod ledger;
+pub mod state_kv;
 pub mod state_tree;
 pub mod truncate;
 
@@ -15,6 +16,9 @@ pub enum Cmd {
     #[clap(subcommand)]
     StateTree(state_tree::Cmd),
 
+    #[clap(subcommand)]
+    StateKv(state_kv::Cmd),
+
     Checkpoint(checkpoint::Cmd),
 
     #[clap(subcommand)]
@@ -27,6 +31,7 @@ impl Cmd {
     pub fn run(self) -> Result<()> {
         match self {
             Cmd::StateTree(cmd) => cmd.run(),
+            Cmd::StateKv(cmd) => cmd.run(),
             Cmd::Checkpoint(cmd) => cmd.run(),
             Cmd::Ledger(cmd) => cmd.run(),
             Cmd::Truncate(cmd) => cmd.run(),
@@ -0,0 +1,56 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{AptosDB, StateStore};
+use anyhow::Result;
+use aptos_config::config::RocksdbConfigs;
+use aptos_types::transaction::Version;
+use clap::Parser;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[clap(about = "Count the stale state values pending pruning up to a version, in each shard.")]
+pub struct Cmd {
+    #[clap(long, parse(from_os_str))]
+    db_dir: PathBuf,
+
+    #[clap(long)]
+    up_to_version: Version,
+
+    #[clap(long)]
+    use_state_kv_db: bool,
+}
+
+impl Cmd {
+    pub fn run(self) -> Result<()> {
+        println!(
+            "* Counting stale state values pending pruning up to version {}. \n",
+            self.up_to_version
+        );
+
+        let rocksdb_config = RocksdbConfigs {
+            use_state_kv_db: self.use_state_kv_db,
+            ..Default::default()
+        };
+        let (_ledger_db, _state_merkle_db, state_kv_db) = AptosDB::open_dbs(
+            &self.db_dir,
+            rocksdb_config,
+            /*readonly=*/ true,
+            /*max_num_nodes_per_lru_cache_shard=*/ 0,
+        )?;
+
+        let counts = StateStore::count_pending_stale_state_values_by_shard(
+            &state_kv_db,
+            self.up_to_version,
+        )?;
+        for (shard_id, count) in &counts {
+            println!("shard {}: {}", shard_id, count);
+        }
+        println!(
+            "total: {}",
+            counts.iter().map(|(_, count)| count).sum::<u64>()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+mod count_pending_stale_values;
+
+use anyhow::Result;
+
+/// Tool supports inspecting the state K/V db, e.g. the backlog of the state K/V pruner
+#[derive(clap::Subcommand)]
+pub enum Cmd {
+    CountPendingStaleValues(count_pending_stale_values::Cmd),
+}
+
+impl Cmd {
+    pub fn run(self) -> Result<()> {
+        match self {
+            Self::CountPendingStaleValues(cmd) => cmd.run(),
+        }
+    }
+}
@@ -4,6 +4,7 @@
 
 //! This module defines error types used by [`AptosDB`](crate::AptosDB).
 
+use aptos_crypto::HashValue;
 use thiserror::Error;
 
 /// This enum defines errors commonly used among [`AptosDB`](crate::AptosDB) APIs.
@@ -15,4 +16,62 @@ pub enum AptosDbError {
     /// Requested too many items.
     #[error("Too many items requested: at least {0} requested, max is {1}")]
     TooManyRequested(u64, u64),
+    /// Free disk space is below the configured minimum, so writes are refused.
+    #[error("Insufficient disk space: {0} bytes available, min is {1}")]
+    InsufficientDiskSpace(u64, u64),
+    /// A state value read with a min version was last written before it, if ever.
+    #[error("State value last written at version {0:?} is older than min version {1}")]
+    StaleStateValue(Option<u64>, u64),
+    /// The state merkle DB failed, so no proof can be served, while values still can be.
+    #[error("State merkle DB unavailable: {0}")]
+    MerkleUnavailable(String),
+    /// A resource group value can't be decoded, because it is corrupted or not a resource group.
+    #[error("Resource group {0} is invalid: {1}")]
+    InvalidResourceGroup(String, String),
+    /// A requested version is pruned, so the data at it may have existed but can't be read.
+    #[error("Version {0} is pruned, min readable version is {1}")]
+    VersionPruned(u64, u64),
+}
+
+/// This enum defines the inconsistencies found when verifying a
+/// [`StateValueChunkWithProof`](aptos_types::state_store::state_value::StateValueChunkWithProof).
+#[derive(Debug, Error)]
+pub enum StateValueChunkError {
+    /// The chunk has no values.
+    #[error("Chunk is empty.")]
+    Empty,
+    /// The chunk is for a different root hash.
+    #[error("Chunk root hash {actual:x} doesn't match expected root hash {expected:x}.")]
+    RootHashMismatch {
+        actual: HashValue,
+        expected: HashValue,
+    },
+    /// The index range of the chunk doesn't match the number of values in it.
+    #[error("Chunk indices [{first_index}, {last_index}] don't match its {num_values} values.")]
+    IndexRangeMismatch {
+        first_index: u64,
+        last_index: u64,
+        num_values: usize,
+    },
+    /// The hashed key of the value at this position in the chunk is not after the one before it.
+    #[error("Chunk value {0} is out of order.")]
+    KeysOutOfOrder(usize),
+    /// The first key of the chunk is not the hashed key of its first value.
+    #[error("Chunk first key {claimed:x} doesn't match the key of its first value {actual:x}.")]
+    FirstKeyMismatch {
+        claimed: HashValue,
+        actual: HashValue,
+    },
+    /// The last key of the chunk is not the hashed key of its last value.
+    #[error("Chunk last key {claimed:x} doesn't match the key of its last value {actual:x}.")]
+    LastKeyMismatch {
+        claimed: HashValue,
+        actual: HashValue,
+    },
+    /// The proof of a chunk not starting at index 0 depends on the values before it.
+    #[error("Chunk starting at index {0} can't be verified without the values before it.")]
+    PrecedingValuesUnknown(u64),
+    /// The range proof doesn't verify against the root hash.
+    #[error("Chunk range proof is invalid: {0}")]
+    InvalidProof(String),
 }
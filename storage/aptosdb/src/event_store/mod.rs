@@ -435,6 +435,43 @@ impl EventStore {
             .ok_or_else(|| format_err!("A block with non-zero seq num started at version 0."))
     }
 
+    /// Gets the latest version at or before `ledger_version` whose block started at or before
+    /// `timestamp`, or `None` if no block started by then. If no block started after `timestamp`
+    /// yet, that's `ledger_version` itself.
+    pub(crate) fn get_last_version_at_or_before_timestamp(
+        &self,
+        timestamp: u64,
+        ledger_version: Version,
+    ) -> Result<Option<Version>> {
+        let event_key = new_block_event_key();
+        if self
+            .get_latest_sequence_number(ledger_version, &event_key)?
+            .is_none()
+        {
+            return Ok(None);
+        }
+        let seq_after_ts = match self.search_for_event_lower_bound(
+            &event_key,
+            |event| {
+                let new_block_event: NewBlockEvent = event.try_into()?;
+                Ok(new_block_event.proposed_time() <= timestamp)
+            },
+            ledger_version,
+        )? {
+            Some(seq_after_ts) => seq_after_ts,
+            None => return Ok(Some(ledger_version)),
+        };
+        if seq_after_ts == 0 {
+            return Ok(None);
+        }
+
+        let (version, _idx) = self.lookup_event_by_key(&event_key, seq_after_ts, ledger_version)?;
+        version
+            .checked_sub(1)
+            .map(Some)
+            .ok_or_else(|| format_err!("A block with non-zero seq num started at version 0."))
+    }
+
     /// Prunes events by accumulator store for a range of version in [begin, end)
     fn prune_event_accumulator(
         &self,
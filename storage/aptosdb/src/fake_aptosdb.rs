@@ -15,7 +15,8 @@ use aptos_crypto::{
 };
 use aptos_infallible::Mutex;
 use aptos_storage_interface::{
-    state_delta::StateDelta, DbReader, DbWriter, ExecutedTrees, MAX_REQUEST_LIMIT,
+    state_delta::StateDelta, AsyncCommitChannelStatus, DbReader, DbWriter, ExecutedTrees,
+    MAX_REQUEST_LIMIT,
 };
 use aptos_types::{
     access_path::AccessPath,
@@ -230,7 +231,7 @@ impl DbWriter for FakeAptosDB {
         ledger_info_with_sigs: Option<&LedgerInfoWithSignatures>,
         sync_commit: bool,
         latest_in_memory_state: StateDelta,
-    ) -> Result<()> {
+    ) -> Result<AsyncCommitChannelStatus> {
         gauged_api("save_transactions", || {
             // Executing and committing from more than one threads not allowed -- consensus and
             // state sync must hand over to each other after all pending execution and committing
@@ -380,7 +381,8 @@ impl DbWriter for FakeAptosDB {
                 LEDGER_VERSION.set(x.ledger_info().version() as i64);
                 NEXT_BLOCK_EPOCH.set(x.ledger_info().next_block_epoch() as i64);
             }
-            Ok(())
+            // Nothing is committed asynchronously.
+            Ok(AsyncCommitChannelStatus::Available)
         })
     }
 }
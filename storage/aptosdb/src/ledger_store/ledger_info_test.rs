@@ -75,6 +75,27 @@ proptest! {
         prop_assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_iter_epoch_version_mapping(ledger_infos_with_sigs in arb_ledger_infos_with_sigs()) {
+        let tmp_dir = TempPath::new();
+        let db = set_up(&tmp_dir, &ledger_infos_with_sigs);
+
+        let actual = db
+            .ledger_store
+            .iter_epoch_version_mapping()
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        let expected: Vec<_> = ledger_infos_with_sigs
+            .iter()
+            .map(|ledger_info_with_sigs| ledger_info_with_sigs.ledger_info())
+            .filter(|li| li.ends_epoch())
+            .map(|li| (li.epoch(), li.version()))
+            .collect();
+        prop_assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_get_epoch_state(ledger_infos_with_sigs in arb_ledger_infos_with_sigs()) {
         let tmp_dir = TempPath::new();
@@ -209,6 +209,18 @@ impl LedgerStore {
         Ok(EpochEndingLedgerInfoIter::new(iter, start_epoch, end_epoch))
     }
 
+    /// Gets an iterator that yields `(epoch, epoch_ending_version)` for all the ended epochs, in
+    /// ascending order. Epoch `e + 1` starts at the version right after the one `e` ends at.
+    pub fn iter_epoch_version_mapping(
+        &self,
+    ) -> Result<impl Iterator<Item = Result<(u64, Version)>> + '_> {
+        let mut iter = self
+            .db
+            .iter::<EpochByVersionSchema>(ReadOptions::default())?;
+        iter.seek_to_first();
+        Ok(iter.map(|res| res.map(|(version, epoch)| (epoch, version))))
+    }
+
     pub fn ensure_epoch_ending(&self, version: Version) -> Result<()> {
         self.db
             .get::<EpochByVersionSchema>(&version)?
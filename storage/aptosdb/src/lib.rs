@@ -34,6 +34,8 @@ mod transaction_store;
 mod utils;
 mod versioned_node_cache;
 
+pub use state_store::StateStoreOptions;
+
 #[cfg(test)]
 mod aptosdb_test;
 
@@ -66,7 +68,7 @@ use crate::{
     stale_node_index_cross_epoch::StaleNodeIndexCrossEpochSchema,
     state_kv_db::StateKvDb,
     state_merkle_db::StateMerkleDb,
-    state_store::StateStore,
+    state_store::{disk_space_guard::DiskSpaceGuard, StateStore},
     transaction_store::TransactionStore,
 };
 use anyhow::{bail, ensure, Result};
@@ -75,7 +77,8 @@ use aptos_config::config::{
 };
 #[cfg(any(test, feature = "fuzzing"))]
 use aptos_config::config::{
-    BUFFERED_STATE_TARGET_ITEMS, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+    SnapshotTriggerPolicy, DEFAULT_MAX_NUM_KEYS_IN_STATE_STATS_CACHE,
+    DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
 };
 use aptos_crypto::hash::HashValue;
 use aptos_db_indexer::Indexer;
@@ -84,8 +87,8 @@ use aptos_logger::prelude::*;
 use aptos_rocksdb_options::gen_rocksdb_options;
 use aptos_schemadb::{SchemaBatch, DB};
 use aptos_storage_interface::{
-    state_delta::StateDelta, state_view::DbStateView, DbReader, DbWriter, ExecutedTrees, Order,
-    StateSnapshotReceiver, MAX_REQUEST_LIMIT,
+    state_delta::StateDelta, state_view::DbStateView, AsyncCommitChannelStatus, DbReader, DbWriter,
+    ExecutedTrees, Order, StateSnapshotReceiver, MAX_REQUEST_LIMIT,
 };
 use aptos_types::{
     account_address::AccountAddress,
@@ -299,7 +302,8 @@ impl AptosDB {
         state_merkle_db: StateMerkleDb,
         state_kv_db: StateKvDb,
         pruner_config: PrunerConfig,
-        buffered_state_target_items: usize,
+        disk_space_guard: DiskSpaceGuard,
+        state_store_options: StateStoreOptions,
         hack_for_tests: bool,
     ) -> Self {
         let state_merkle_db = Arc::new(state_merkle_db);
@@ -321,7 +325,11 @@ impl AptosDB {
             state_merkle_pruner,
             epoch_snapshot_pruner,
             state_kv_pruner,
-            buffered_state_target_items,
+            pruner_config
+                .epoch_snapshot_pruner_config
+                .num_snapshots_to_retain,
+            disk_space_guard,
+            state_store_options,
             hack_for_tests,
         ));
 
@@ -355,8 +363,8 @@ impl AptosDB {
         pruner_config: PrunerConfig,
         rocksdb_configs: RocksdbConfigs,
         enable_indexer: bool,
-        buffered_state_target_items: usize,
         max_num_nodes_per_lru_cache_shard: usize,
+        state_store_options: StateStoreOptions,
     ) -> Result<Self> {
         ensure!(
             pruner_config.eq(&NO_OP_STORAGE_PRUNER_CONFIG) || !readonly,
@@ -375,10 +383,18 @@ impl AptosDB {
             state_merkle_db,
             state_kv_db,
             pruner_config,
-            buffered_state_target_items,
+            DiskSpaceGuard::new(
+                db_root_path.as_ref(),
+                state_store_options.min_free_disk_space_bytes,
+            ),
+            state_store_options,
             readonly,
         );
 
+        if state_store_options.pin_framework_resources {
+            myself.state_store.pin_framework_cache_at_latest_version()?;
+        }
+
         if !readonly && enable_indexer {
             myself.open_indexer(db_root_path, rocksdb_configs.index_db_config)?;
         }
@@ -482,8 +498,9 @@ impl AptosDB {
     fn new_without_pruner<P: AsRef<Path> + Clone>(
         db_root_path: P,
         readonly: bool,
-        buffered_state_target_items: usize,
+        snapshot_trigger_policy: SnapshotTriggerPolicy,
         max_num_nodes_per_lru_cache_shard: usize,
+        max_num_keys_in_state_stats_cache: usize,
         enable_indexer: bool,
     ) -> Self {
         Self::open(
@@ -492,8 +509,12 @@ impl AptosDB {
             NO_OP_STORAGE_PRUNER_CONFIG, /* pruner */
             RocksdbConfigs::default(),
             enable_indexer,
-            buffered_state_target_items,
             max_num_nodes_per_lru_cache_shard,
+            StateStoreOptions {
+                snapshot_trigger_policy,
+                max_num_keys_in_state_stats_cache,
+                ..Default::default()
+            },
         )
         .expect("Unable to open AptosDB")
     }
@@ -504,8 +525,9 @@ impl AptosDB {
         Self::new_without_pruner(
             db_root_path,
             false,
-            BUFFERED_STATE_TARGET_ITEMS,
+            SnapshotTriggerPolicy::default(),
             DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            DEFAULT_MAX_NUM_KEYS_IN_STATE_STATS_CACHE,
             false,
         )
     }
@@ -513,7 +535,14 @@ impl AptosDB {
     /// This opens db in non-readonly mode, without the pruner and cache.
     #[cfg(any(test, feature = "fuzzing"))]
     pub fn new_for_test_no_cache<P: AsRef<Path> + Clone>(db_root_path: P) -> Self {
-        Self::new_without_pruner(db_root_path, false, BUFFERED_STATE_TARGET_ITEMS, 0, false)
+        Self::new_without_pruner(
+            db_root_path,
+            false,
+            SnapshotTriggerPolicy::default(),
+            0,
+            DEFAULT_MAX_NUM_KEYS_IN_STATE_STATS_CACHE,
+            false,
+        )
     }
 
     /// This opens db in non-readonly mode, without the pruner, and with the indexer
@@ -522,8 +551,9 @@ impl AptosDB {
         Self::new_without_pruner(
             db_root_path,
             false,
-            BUFFERED_STATE_TARGET_ITEMS,
+            SnapshotTriggerPolicy::default(),
             DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            DEFAULT_MAX_NUM_KEYS_IN_STATE_STATS_CACHE,
             true,
         )
     }
@@ -537,8 +567,25 @@ impl AptosDB {
         Self::new_without_pruner(
             db_root_path,
             false,
-            buffered_state_target_items,
+            SnapshotTriggerPolicy::ByItems(buffered_state_target_items),
+            DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            DEFAULT_MAX_NUM_KEYS_IN_STATE_STATS_CACHE,
+            false,
+        )
+    }
+
+    /// This opens db in non-readonly mode, without the pruner.
+    #[cfg(any(test, feature = "fuzzing"))]
+    pub fn new_for_test_with_max_num_keys_in_state_stats_cache<P: AsRef<Path> + Clone>(
+        db_root_path: P,
+        max_num_keys_in_state_stats_cache: usize,
+    ) -> Self {
+        Self::new_without_pruner(
+            db_root_path,
+            false,
+            SnapshotTriggerPolicy::default(),
             DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            max_num_keys_in_state_stats_cache,
             false,
         )
     }
@@ -549,8 +596,9 @@ impl AptosDB {
         Self::new_without_pruner(
             db_root_path,
             true,
-            BUFFERED_STATE_TARGET_ITEMS,
+            SnapshotTriggerPolicy::default(),
             DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            DEFAULT_MAX_NUM_KEYS_IN_STATE_STATS_CACHE,
             false,
         )
     }
@@ -561,6 +609,12 @@ impl AptosDB {
         self.state_store.buffered_state()
     }
 
+    /// Verifies the state replayed on start up against the ledger, see
+    /// `StateStore::verify_buffered_state_consistency`.
+    pub fn verify_buffered_state_consistency(&self) -> Result<()> {
+        self.state_store.verify_buffered_state_consistency()
+    }
+
     /// This force the db to update rocksdb properties immediately.
     pub fn update_rocksdb_properties(&self) -> Result<()> {
         update_rocksdb_properties(&self.ledger_db, &self.state_merkle_db)
@@ -1379,6 +1433,26 @@ impl DbReader for AptosDB {
         })
     }
 
+    fn get_state_value_by_version_with_lower_bound(
+        &self,
+        state_store_key: &StateKey,
+        version: Version,
+        lower_bound_version: Option<Version>,
+    ) -> Result<Option<StateValue>> {
+        gauged_api("get_state_value_by_version_with_lower_bound", || {
+            self.error_if_state_kv_pruned("StateValue", version)?;
+
+            Ok(self
+                .state_store
+                .get_state_value_with_version_by_version_with_lower_bound(
+                    state_store_key,
+                    version,
+                    lower_bound_version,
+                )?
+                .map(|(_, value)| value))
+        })
+    }
+
     /// Returns the proof of the given state key and version.
     fn get_state_proof_by_version_ext(
         &self,
@@ -1532,11 +1606,7 @@ impl DbReader for AptosDB {
 
     fn get_latest_state_checkpoint_version(&self) -> Result<Option<Version>> {
         gauged_api("get_latest_state_checkpoint_version", || {
-            Ok(self
-                .state_store
-                .buffered_state()
-                .lock()
-                .current_checkpoint_version())
+            Ok(self.state_store.get_latest_checkpoint_version())
         })
     }
 
@@ -1669,7 +1739,7 @@ impl DbWriter for AptosDB {
         ledger_info_with_sigs: Option<&LedgerInfoWithSignatures>,
         sync_commit: bool,
         latest_in_memory_state: StateDelta,
-    ) -> Result<()> {
+    ) -> Result<AsyncCommitChannelStatus> {
         gauged_api("save_transactions", || {
             // Executing and committing from more than one threads not allowed -- consensus and
             // state sync must hand over to each other after all pending execution and committing
@@ -1718,7 +1788,7 @@ impl DbWriter for AptosDB {
                latest_in_memory_state.current_version.expect("Must exist")
             );
 
-            {
+            let async_commit_channel_status = {
                 let mut buffered_state = self.state_store.buffered_state().lock();
                 ensure!(
                     base_state_version == buffered_state.current_state().base_version,
@@ -1786,6 +1856,14 @@ impl DbWriter for AptosDB {
                     )?;
                     self.ledger_db.write_schemas(ledger_batch)?;
                 }
+                if self.state_store.pin_framework_resources() {
+                    let state_updates_vec = txns_to_commit
+                        .iter()
+                        .map(|txn_to_commit| txn_to_commit.state_updates())
+                        .collect::<Vec<_>>();
+                    self.state_store
+                        .update_pinned_values(&state_updates_vec, first_version);
+                }
 
                 let mut end_with_reconfig = false;
                 let updates_until_latest_checkpoint_since_current = {
@@ -1816,17 +1894,15 @@ impl DbWriter for AptosDB {
                     }
                 };
 
-                {
-                    let _timer = OTHER_TIMERS_SECONDS
-                        .with_label_values(&["buffered_state___update"])
-                        .start_timer();
-                    buffered_state.update(
-                        updates_until_latest_checkpoint_since_current,
-                        latest_in_memory_state,
-                        end_with_reconfig || sync_commit,
-                    )?;
-                }
-            }
+                let _timer = OTHER_TIMERS_SECONDS
+                    .with_label_values(&["buffered_state___update"])
+                    .start_timer();
+                buffered_state.update(
+                    updates_until_latest_checkpoint_since_current,
+                    latest_in_memory_state,
+                    end_with_reconfig || sync_commit,
+                )?
+            };
 
             // If commit succeeds and there are at least one transaction written to the storage, we
             // will inform the pruner thread to work.
@@ -1840,8 +1916,7 @@ impl DbWriter for AptosDB {
                 self.ledger_pruner
                     .maybe_set_pruner_target_db_version(last_version);
                 self.state_store
-                    .state_kv_pruner
-                    .maybe_set_pruner_target_db_version(last_version);
+                    .maybe_set_state_kv_pruner_target(last_version);
             }
 
             // Note: this must happen after txns have been saved to db because types can be newly
@@ -1862,7 +1937,7 @@ impl DbWriter for AptosDB {
                 NEXT_BLOCK_EPOCH.set(x.ledger_info().next_block_epoch() as i64);
             }
 
-            Ok(())
+            Ok(async_commit_channel_status)
         })
     }
 
@@ -3,8 +3,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use aptos_metrics_core::{
-    exponential_buckets, register_histogram_vec, register_int_counter, register_int_gauge,
-    register_int_gauge_vec, HistogramVec, IntCounter, IntGauge, IntGaugeVec,
+    exponential_buckets, register_histogram_vec, register_int_counter, register_int_counter_vec,
+    register_int_gauge, register_int_gauge_vec, HistogramVec, IntCounter, IntCounterVec, IntGauge,
+    IntGaugeVec,
 };
 use once_cell::sync::Lazy;
 
@@ -64,6 +65,40 @@ pub static TOTAL_STATE_BYTES: Lazy<IntGauge> = Lazy::new(|| {
     .unwrap()
 });
 
+pub static STATE_KV_SHARD_ROWS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_storage_state_kv_shard_rows",
+        "Rows written to each state K/V shard, i.e., values and stale value indices.",
+        &["shard_id"]
+    )
+    .unwrap()
+});
+
+pub static STATE_KV_SHARD_BYTES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_storage_state_kv_shard_bytes",
+        "Bytes of the keys and values written to each state K/V shard.",
+        &["shard_id"]
+    )
+    .unwrap()
+});
+
+pub static STATE_KV_FREE_BYTES: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_storage_state_kv_free_bytes",
+        "Free bytes on the volume holding the state K/V DB."
+    )
+    .unwrap()
+});
+
+pub static STATE_MERKLE_UNAVAILABLE_ERRORS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "aptos_storage_state_merkle_unavailable_errors",
+        "Reads with proofs that failed as the state merkle DB is unavailable."
+    )
+    .unwrap()
+});
+
 pub static PRUNER_WINDOW: Lazy<IntGaugeVec> = Lazy::new(|| {
     register_int_gauge_vec!(
         // metric name
@@ -129,6 +164,20 @@ pub static OTHER_TIMERS_SECONDS: Lazy<HistogramVec> = Lazy::new(|| {
     .unwrap()
 });
 
+pub static STARTUP_REPLAY_SECONDS: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        // metric name
+        "aptos_storage_startup_replay_seconds",
+        // metric description
+        "Time spent in each stage of replaying the write sets after the latest state snapshot \
+         on startup.",
+        // metric labels (dimensions)
+        &["stage"],
+        exponential_buckets(/*start=*/ 1e-3, /*factor=*/ 2.0, /*count=*/ 20).unwrap(),
+    )
+    .unwrap()
+});
+
 pub static NODE_CACHE_SECONDS: Lazy<HistogramVec> = Lazy::new(|| {
     register_histogram_vec!(
         // metric name
@@ -172,6 +221,31 @@ pub(crate) static LATEST_CHECKPOINT_VERSION: Lazy<IntGauge> = Lazy::new(|| {
     .unwrap()
 });
 
+pub(crate) static SNAPSHOT_TRIGGERS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_storage_snapshot_triggers",
+        "Snapshots sent to the async commit thread, by what triggered them.",
+        &["trigger"]
+    )
+    .unwrap()
+});
+
+pub(crate) static ASYNC_COMMIT_CHANNEL_OCCUPANCY: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_storage_async_commit_channel_occupancy",
+        "Snapshots sent or being sent to the async commit thread and not picked up by it yet."
+    )
+    .unwrap()
+});
+
+pub(crate) static ASYNC_COMMIT_CHANNEL_FULL: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "aptos_storage_async_commit_channel_full",
+        "Snapshots held back because the async commit channel stayed full until the timeout."
+    )
+    .unwrap()
+});
+
 // Backup progress gauges:
 
 pub(crate) static BACKUP_EPOCH_ENDING_EPOCH: Lazy<IntGauge> = Lazy::new(|| {
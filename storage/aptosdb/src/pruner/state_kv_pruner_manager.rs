@@ -129,6 +129,31 @@ impl StateKvPrunerManager {
             latest_version: Arc::new(Mutex::new(min_readable_version)),
         }
     }
+
+    /// Sets pruner target version like `maybe_set_pruner_target_db_version`, but never beyond
+    /// `max_target_version` (unless the target is already beyond it), so what's readable at
+    /// `max_target_version` is kept regardless of the prune window.
+    pub fn maybe_set_capped_pruner_target_db_version(
+        &self,
+        latest_version: Version,
+        max_target_version: Version,
+    ) {
+        *self.latest_version.lock() = latest_version;
+
+        if self.pruner_enabled
+            && latest_version
+                >= *self.last_version_sent_to_pruner.as_ref().lock()
+                    + self.pruning_batch_size as u64
+        {
+            self.pruner_worker.as_ref().set_target_db_version(
+                latest_version
+                    .saturating_sub(self.prune_window)
+                    .min(max_target_version)
+                    .max(self.pruner.target_version()),
+            );
+            *self.last_version_sent_to_pruner.as_ref().lock() = latest_version;
+        }
+    }
 }
 
 impl Drop for StateKvPrunerManager {
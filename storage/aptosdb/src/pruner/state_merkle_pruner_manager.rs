@@ -143,6 +143,26 @@ where
         }
     }
 
+    /// Sets pruner target version like `maybe_set_pruner_target_db_version`, but never beyond
+    /// `max_target_version`, so what's readable at `max_target_version` is kept regardless of the
+    /// prune window.
+    pub fn maybe_set_capped_pruner_target_db_version(
+        &self,
+        latest_version: Version,
+        max_target_version: Version,
+    ) {
+        *self.latest_version.lock() = latest_version;
+
+        if self.pruner_enabled {
+            self.pruner_worker.as_ref().set_target_db_version(
+                latest_version
+                    .saturating_sub(self.prune_window)
+                    .min(max_target_version),
+            );
+            *self.last_version_sent_to_pruner.as_ref().lock() = latest_version;
+        }
+    }
+
     #[cfg(test)]
     pub fn testonly_update_min_version(&self, version: Version) {
         self.pruner.testonly_update_min_version(version);
@@ -180,6 +180,25 @@ impl StateKvDb {
         &self.state_kv_db_shards[shard_id as usize]
     }
 
+    /// Manually compacts all column families of the given shard, or of the metadata db and all
+    /// shards if `shard_id` is `None`.
+    pub(crate) fn compact(&self, shard_id: Option<u8>) -> Result<()> {
+        let mut dbs: Vec<&Arc<DB>> = match shard_id {
+            Some(shard_id) => vec![&self.state_kv_db_shards[shard_id as usize]],
+            None => std::iter::once(&self.state_kv_metadata_db)
+                .chain(self.state_kv_db_shards.iter())
+                .collect(),
+        };
+        // Without sharding, the shards are the metadata db itself.
+        dbs.dedup_by(|a, b| Arc::ptr_eq(a, b));
+        for db in dbs {
+            for cf_name in state_kv_db_column_families() {
+                db.compact_cf(cf_name)?;
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn commit_single_shard(
         &self,
         version: Version,
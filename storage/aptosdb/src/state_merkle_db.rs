@@ -14,9 +14,12 @@ use crate::{
     versioned_node_cache::VersionedNodeCache,
     ShardedStateMerkleSchemaBatch, NUM_STATE_SHARDS, OTHER_TIMERS_SECONDS,
 };
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use aptos_config::config::{RocksdbConfig, RocksdbConfigs};
-use aptos_crypto::{hash::CryptoHash, HashValue};
+use aptos_crypto::{
+    hash::{CryptoHash, SPARSE_MERKLE_PLACEHOLDER_HASH},
+    HashValue,
+};
 use aptos_jellyfish_merkle::{
     node_type::{NodeKey, NodeType},
     JellyfishMerkleTree, TreeReader, TreeUpdateBatch, TreeWriter,
@@ -25,7 +28,7 @@ use aptos_logger::prelude::*;
 use aptos_rocksdb_options::gen_rocksdb_options;
 use aptos_schemadb::{SchemaBatch, DB};
 use aptos_types::{
-    nibble::{nibble_path::NibblePath, ROOT_NIBBLE_HEIGHT},
+    nibble::{nibble_path::NibblePath, Nibble, ROOT_NIBBLE_HEIGHT},
     proof::{SparseMerkleProofExt, SparseMerkleRangeProof},
     state_store::state_key::StateKey,
     transaction::Version,
@@ -34,7 +37,7 @@ use arr_macro::arr;
 use once_cell::sync::Lazy;
 use rayon::prelude::*;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     path::{Path, PathBuf},
     sync::Arc,
     time::Instant,
@@ -180,6 +183,25 @@ impl StateMerkleDb {
         &self.state_merkle_db_shards[shard_id as usize]
     }
 
+    /// Manually compacts all column families of the given shard, or of the metadata db and all
+    /// shards if `shard_id` is `None`.
+    pub(crate) fn compact(&self, shard_id: Option<u8>) -> Result<()> {
+        let mut dbs: Vec<&Arc<DB>> = match shard_id {
+            Some(shard_id) => vec![&self.state_merkle_db_shards[shard_id as usize]],
+            None => std::iter::once(&self.state_merkle_metadata_db)
+                .chain(self.state_merkle_db_shards.iter())
+                .collect(),
+        };
+        // Without sharding, the shards are the metadata db itself.
+        dbs.dedup_by(|a, b| Arc::ptr_eq(a, b));
+        for db in dbs {
+            for cf_name in state_merkle_db_column_families() {
+                db.compact_cf(cf_name)?;
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn commit_top_levels(&self, version: Version, batch: SchemaBatch) -> Result<()> {
         batch.put::<DbMetadataSchema>(
             &DbMetadataKey::StateMerkleCommitProgress,
@@ -213,6 +235,20 @@ impl StateMerkleDb {
         JellyfishMerkleTree::new(self).get_with_proof_ext(state_key.hash(), version)
     }
 
+    pub fn batch_get_with_proof_ext(
+        &self,
+        state_keys: &[StateKey],
+        version: Version,
+    ) -> Result<
+        Vec<(
+            Option<(HashValue, (StateKey, Version))>,
+            SparseMerkleProofExt,
+        )>,
+    > {
+        let keys: Vec<_> = state_keys.iter().map(|key| key.hash()).collect();
+        JellyfishMerkleTree::new(self).batch_get_with_proof_ext(&keys, version)
+    }
+
     pub fn get_range_proof(
         &self,
         rightmost_key: HashValue,
@@ -225,10 +261,164 @@ impl StateMerkleDb {
         JellyfishMerkleTree::new(self).get_root_hash(version)
     }
 
+    pub fn get_root_hash_option(&self, version: Version) -> Result<Option<HashValue>> {
+        JellyfishMerkleTree::new(self).get_root_hash_option(version)
+    }
+
     pub fn get_leaf_count(&self, version: Version) -> Result<usize> {
         JellyfishMerkleTree::new(self).get_leaf_count(version)
     }
 
+    pub fn get_leaf_index(&self, key: HashValue, version: Version) -> Result<Option<usize>> {
+        JellyfishMerkleTree::new(self).get_leaf_index(key, version)
+    }
+
+    /// Returns the root hash of the subtree under `nibble_prefix` at `version`, or the placeholder
+    /// hash if there's nothing under it.
+    pub fn get_subtree_hash(
+        &self,
+        nibble_prefix: &NibblePath,
+        version: Version,
+    ) -> Result<HashValue> {
+        ensure!(
+            nibble_prefix.num_nibbles() <= ROOT_NIBBLE_HEIGHT,
+            "Nibble prefix is too long: {:?}",
+            nibble_prefix,
+        );
+        let mut node_key = NodeKey::new_empty_path(version);
+        let mut node = self.get_node_with_tag(&node_key, "get_subtree_hash")?;
+        for (depth, nibble) in nibble_prefix.nibbles().enumerate() {
+            match node {
+                Node::Internal(internal_node) => match internal_node.child(nibble) {
+                    Some(child) => {
+                        node_key = node_key.gen_child_node_key(child.version, nibble);
+                        node = self.get_node_with_tag(&node_key, "get_subtree_hash")?;
+                    },
+                    None => return Ok(*SPARSE_MERKLE_PLACEHOLDER_HASH),
+                },
+                // A leaf above the prefix depth is the only leaf of the subtree it sits at, so the
+                // subtree under the prefix is either the leaf itself or empty.
+                Node::Leaf(leaf_node) => {
+                    let leaf_path = NibblePath::new_even(leaf_node.account_key().to_vec());
+                    let under_prefix = nibble_prefix
+                        .nibbles()
+                        .zip(leaf_path.nibbles())
+                        .skip(depth)
+                        .all(|(a, b)| a == b);
+                    return Ok(if under_prefix {
+                        leaf_node.hash()
+                    } else {
+                        *SPARSE_MERKLE_PLACEHOLDER_HASH
+                    });
+                },
+                Node::Null => return Ok(*SPARSE_MERKLE_PLACEHOLDER_HASH),
+            }
+        }
+        Ok(node.hash())
+    }
+
+    /// Walks the trees at `version_a` and `version_b` top-down, each time descending into the
+    /// first child that differs, and returns the nibble path of the smallest subtree that differs,
+    /// or `None` if the root hashes match.
+    pub fn first_differing_subtree(
+        &self,
+        version_a: Version,
+        version_b: Version,
+    ) -> Result<Option<NibblePath>> {
+        let mut node_key_a = NodeKey::new_empty_path(version_a);
+        let mut node_key_b = NodeKey::new_empty_path(version_b);
+        loop {
+            let node_a = self.get_node_with_tag(&node_key_a, "first_differing_subtree")?;
+            let node_b = self.get_node_with_tag(&node_key_b, "first_differing_subtree")?;
+            if node_a.hash() == node_b.hash() {
+                return Ok(None);
+            }
+            let (internal_a, internal_b) = match (node_a, node_b) {
+                (Node::Internal(internal_a), Node::Internal(internal_b)) => {
+                    (internal_a, internal_b)
+                },
+                // A leaf or an empty tree can't be split any further.
+                _ => return Ok(Some(node_key_a.nibble_path().clone())),
+            };
+            let (nibble, child_a, child_b) = (0..16u8)
+                .map(Nibble::from)
+                .map(|nibble| (nibble, internal_a.child(nibble), internal_b.child(nibble)))
+                .find(|(_, child_a, child_b)| {
+                    child_a.map(|child| child.hash) != child_b.map(|child| child.hash)
+                })
+                .expect("Internal nodes with different hashes must have a differing child.");
+            match (child_a, child_b) {
+                (Some(child_a), Some(child_b)) if !child_a.is_leaf() && !child_b.is_leaf() => {
+                    node_key_a = node_key_a.gen_child_node_key(child_a.version, nibble);
+                    node_key_b = node_key_b.gen_child_node_key(child_b.version, nibble);
+                },
+                _ => {
+                    let mut nibble_path = node_key_a.nibble_path().clone();
+                    nibble_path.push(nibble);
+                    return Ok(Some(nibble_path));
+                },
+            }
+        }
+    }
+
+    /// Finds the leaf with the given hash in the tree at `version`, if any. The position of a
+    /// leaf can't be derived from its hash, so this walks all the internal nodes of the tree,
+    /// which takes time linear in the size of the state. Only meant for debugging, never for a
+    /// request path.
+    pub(crate) fn get_leaf_by_hash(
+        &self,
+        leaf_hash: HashValue,
+        version: Version,
+    ) -> Result<Option<LeafNode>> {
+        let mut node_keys = vec![NodeKey::new_empty_path(version)];
+        while let Some(node_key) = node_keys.pop() {
+            match self.get_node_with_tag(&node_key, "get_leaf_by_hash")? {
+                Node::Internal(internal_node) => {
+                    for (nibble, child) in internal_node.children_sorted() {
+                        let child_node_key = node_key.gen_child_node_key(child.version, *nibble);
+                        if !child.is_leaf() {
+                            node_keys.push(child_node_key);
+                        } else if child.hash == leaf_hash {
+                            // Nothing else needs to be visited, read the matching leaf next.
+                            node_keys = vec![child_node_key];
+                            break;
+                        }
+                    }
+                },
+                Node::Leaf(leaf_node) => {
+                    if leaf_node.hash() == leaf_hash {
+                        return Ok(Some(leaf_node));
+                    }
+                },
+                Node::Null => {},
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the nodes of the tree at `version` whose nibble paths are at most `max_depth`
+    /// nibbles long, parents before children and siblings in nibble order.
+    pub fn get_nodes_up_to_depth(
+        &self,
+        version: Version,
+        max_depth: usize,
+    ) -> Result<Vec<(NodeKey, Node)>> {
+        let mut nodes = vec![];
+        let mut node_keys = VecDeque::from([NodeKey::new_empty_path(version)]);
+        while let Some(node_key) = node_keys.pop_front() {
+            let node = self.get_node_with_tag(&node_key, "get_nodes_up_to_depth")?;
+            if let Node::Internal(internal_node) = &node {
+                if node_key.nibble_path().num_nibbles() < max_depth {
+                    for (nibble, child) in internal_node.children_sorted() {
+                        node_keys.push_back(node_key.gen_child_node_key(child.version, *nibble));
+                    }
+                }
+            }
+            nodes.push((node_key, node));
+        }
+        Ok(nodes)
+    }
+
     pub fn batch_put_value_set(
         &self,
         value_set: Vec<(HashValue, Option<&(HashValue, StateKey)>)>,
@@ -4,25 +4,27 @@
 //! This file defines state store buffered state that has been committed.
 
 use crate::{
-    metrics::LATEST_CHECKPOINT_VERSION,
+    metrics::{
+        ASYNC_COMMIT_CHANNEL_FULL, ASYNC_COMMIT_CHANNEL_OCCUPANCY, LATEST_CHECKPOINT_VERSION,
+        SNAPSHOT_TRIGGERS,
+    },
     state_store::{state_snapshot_committer::StateSnapshotCommitter, StateDb},
 };
 use anyhow::{ensure, Result};
-use aptos_logger::info;
-use aptos_storage_interface::state_delta::StateDelta;
+use aptos_config::config::{AsyncCommitChannelFullPolicy, SnapshotTriggerPolicy};
+use aptos_logger::{info, warn};
+use aptos_storage_interface::{state_delta::StateDelta, AsyncCommitChannelStatus};
 use aptos_types::{
     state_store::{state_key::StateKey, state_value::StateValue},
     transaction::Version,
 };
+use crossbeam_channel::SendTimeoutError;
 use std::{
     collections::HashMap,
     mem::swap,
-    sync::{
-        mpsc,
-        mpsc::{Sender, SyncSender},
-        Arc,
-    },
+    sync::{mpsc, mpsc::Sender, Arc},
     thread::JoinHandle,
+    time::{Duration, Instant},
 };
 
 pub(crate) const ASYNC_COMMIT_CHANNEL_BUFFER_SIZE: u64 = 1;
@@ -40,8 +42,11 @@ pub struct BufferedState {
     state_until_checkpoint: Option<Box<StateDelta>>,
     // state after the latest checkpoint.
     state_after_checkpoint: StateDelta,
-    state_commit_sender: SyncSender<CommitMessage<Arc<StateDelta>>>,
-    target_items: usize,
+    state_commit_sender: crossbeam_channel::Sender<CommitMessage<Arc<StateDelta>>>,
+    snapshot_trigger_policy: SnapshotTriggerPolicy,
+    async_commit_channel_full_policy: AsyncCommitChannelFullPolicy,
+    // When the last snapshot was sent to the async commit thread, or the buffered state created.
+    last_snapshot_time: Instant,
     join_handle: Option<JoinHandle<()>>,
 }
 
@@ -55,10 +60,11 @@ impl BufferedState {
     pub(crate) fn new(
         state_db: &Arc<StateDb>,
         state_after_checkpoint: StateDelta,
-        target_items: usize,
+        snapshot_trigger_policy: SnapshotTriggerPolicy,
+        async_commit_channel_full_policy: AsyncCommitChannelFullPolicy,
     ) -> Self {
         let (state_commit_sender, state_commit_receiver) =
-            mpsc::sync_channel(ASYNC_COMMIT_CHANNEL_BUFFER_SIZE as usize);
+            crossbeam_channel::bounded(ASYNC_COMMIT_CHANNEL_BUFFER_SIZE as usize);
         let arc_state_db = Arc::clone(state_db);
         let join_handle = std::thread::Builder::new()
             .name("state-committer".to_string())
@@ -71,7 +77,9 @@ impl BufferedState {
             state_until_checkpoint: None,
             state_after_checkpoint,
             state_commit_sender,
-            target_items,
+            snapshot_trigger_policy,
+            async_commit_channel_full_policy,
+            last_snapshot_time: Instant::now(),
             // The join handle of the async state commit thread for graceful drop.
             join_handle: Some(join_handle),
         };
@@ -87,43 +95,117 @@ impl BufferedState {
         self.state_after_checkpoint.base_version
     }
 
-    fn maybe_commit(&mut self, sync_commit: bool) {
+    /// Returns what triggers a snapshot of `state_until_checkpoint`, if anything does.
+    fn snapshot_trigger(&self, state_until_checkpoint: &StateDelta) -> Option<&'static str> {
+        let (target_items, target_interval) = match self.snapshot_trigger_policy {
+            SnapshotTriggerPolicy::ByItems(items) => (Some(items), None),
+            SnapshotTriggerPolicy::ByTime { interval_ms } => {
+                (None, Some(Duration::from_millis(interval_ms)))
+            },
+            SnapshotTriggerPolicy::Either { items, interval_ms } => {
+                (Some(items), Some(Duration::from_millis(interval_ms)))
+            },
+        };
+        let num_items = state_until_checkpoint.updates_since_base.len();
+        let elapsed = self.last_snapshot_time.elapsed();
+        let num_versions = Self::num_versions(state_until_checkpoint);
+        if target_items.map_or(false, |items| num_items >= items) {
+            Some("items")
+        } else if target_interval.map_or(false, |interval| elapsed >= interval) {
+            Some("time")
+        } else if num_versions >= TARGET_SNAPSHOT_INTERVAL_IN_VERSION {
+            Some("versions")
+        } else {
+            None
+        }
+    }
+
+    fn num_versions(state_delta: &StateDelta) -> u64 {
+        state_delta.current_version.map_or(0, |v| v + 1)
+            - state_delta.base_version.map_or(0, |v| v + 1)
+    }
+
+    /// Sends `to_commit` to the async commit thread, waiting for room in the channel for at most
+    /// `timeout` if set. Returns whether it was sent.
+    fn send_to_commit(&self, to_commit: &Arc<StateDelta>, timeout: Option<Duration>) -> bool {
+        // Counted before sending, since the async commit thread may pick it up right away.
+        ASYNC_COMMIT_CHANNEL_OCCUPANCY.inc();
+        let sent = match timeout {
+            None => {
+                self.state_commit_sender
+                    .send(CommitMessage::Data(Arc::clone(to_commit)))
+                    .unwrap();
+                true
+            },
+            Some(timeout) => match self
+                .state_commit_sender
+                .send_timeout(CommitMessage::Data(Arc::clone(to_commit)), timeout)
+            {
+                Ok(()) => true,
+                Err(SendTimeoutError::Timeout(_)) => false,
+                Err(SendTimeoutError::Disconnected(_)) => {
+                    panic!("State committer thread exited unexpectedly.")
+                },
+            },
+        };
+        if !sent {
+            ASYNC_COMMIT_CHANNEL_OCCUPANCY.dec();
+        }
+        sent
+    }
+
+    fn maybe_commit(&mut self, sync_commit: bool) -> AsyncCommitChannelStatus {
         if sync_commit {
             let (commit_sync_sender, commit_sync_receiver) = mpsc::channel();
             if let Some(to_commit) = self.state_until_checkpoint.take().map(Arc::from) {
-                self.state_commit_sender
-                    .send(CommitMessage::Data(to_commit))
-                    .unwrap();
+                self.send_to_commit(&to_commit, None);
+                self.last_snapshot_time = Instant::now();
             }
             self.state_commit_sender
                 .send(CommitMessage::Sync(commit_sync_sender))
                 .unwrap();
             commit_sync_receiver.recv().unwrap();
-        } else if self.state_until_checkpoint.is_some() {
-            let take_out_to_commit = {
-                let state_until_checkpoint =
-                    self.state_until_checkpoint.as_ref().expect("Must exist");
-                state_until_checkpoint.updates_since_base.len() >= self.target_items
-                    || state_until_checkpoint.current_version.map_or(0, |v| v + 1)
-                        - state_until_checkpoint.base_version.map_or(0, |v| v + 1)
-                        >= TARGET_SNAPSHOT_INTERVAL_IN_VERSION
-            };
-            if take_out_to_commit {
+        } else if let Some(state_until_checkpoint) = &self.state_until_checkpoint {
+            if let Some(trigger) = self.snapshot_trigger(state_until_checkpoint) {
+                // A snapshot spanning the regular interval is always waited for, so that no more
+                // than `MAX_WRITE_SETS_AFTER_SNAPSHOT` write sets are replayed on start up.
+                let can_hold_back = Self::num_versions(state_until_checkpoint)
+                    < TARGET_SNAPSHOT_INTERVAL_IN_VERSION;
+                let timeout = match self.async_commit_channel_full_policy {
+                    AsyncCommitChannelFullPolicy::BlockWithTimeout(timeout) if can_hold_back => {
+                        Some(timeout)
+                    },
+                    _ => None,
+                };
                 let to_commit: Arc<StateDelta> = self
                     .state_until_checkpoint
                     .take()
                     .map(Arc::from)
                     .expect("Must exist");
+                if !self.send_to_commit(&to_commit, timeout) {
+                    warn!(
+                        base_version = to_commit.base_version,
+                        version = to_commit.current_version,
+                        "Async commit channel is full, holding back StateDelta."
+                    );
+                    ASYNC_COMMIT_CHANNEL_FULL.inc();
+                    // Later updates are merged into the held back snapshot.
+                    self.state_until_checkpoint = Some(Box::new(
+                        Arc::try_unwrap(to_commit).expect("StateDelta must not be shared."),
+                    ));
+                    return AsyncCommitChannelStatus::Full;
+                }
                 info!(
                     base_version = to_commit.base_version,
                     version = to_commit.current_version,
+                    trigger = trigger,
                     "Sent StateDelta to async commit thread."
                 );
-                self.state_commit_sender
-                    .send(CommitMessage::Data(to_commit))
-                    .unwrap();
+                self.last_snapshot_time = Instant::now();
+                SNAPSHOT_TRIGGERS.with_label_values(&[trigger]).inc();
             }
         }
+        AsyncCommitChannelStatus::Available
     }
 
     pub(crate) fn sync_commit(&mut self) {
@@ -138,6 +220,8 @@ impl BufferedState {
         );
     }
 
+    /// Applies the updates and sends a snapshot to the async commit thread if one is due. Reports
+    /// whether the async commit channel was full, in which case the snapshot is held back.
     pub fn update(
         &mut self,
         updates_until_next_checkpoint_since_current_option: Option<
@@ -145,7 +229,7 @@ impl BufferedState {
         >,
         mut new_state_after_checkpoint: StateDelta,
         sync_commit: bool,
-    ) -> Result<()> {
+    ) -> Result<AsyncCommitChannelStatus> {
         ensure!(
             new_state_after_checkpoint.base_version >= self.state_after_checkpoint.base_version
         );
@@ -172,9 +256,9 @@ impl BufferedState {
             );
             self.state_after_checkpoint = new_state_after_checkpoint;
         }
-        self.maybe_commit(sync_commit);
+        let status = self.maybe_commit(sync_commit);
         self.report_latest_committed_version();
-        Ok(())
+        Ok(status)
     }
 }
 
@@ -189,3 +273,156 @@ impl Drop for BufferedState {
             .expect("snapshot commit thread should join peacefully.");
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aptos_crypto::HashValue;
+    use aptos_types::state_store::state_storage_usage::StateStorageUsage;
+
+    /// Creates a buffered state whose async commit thread only starts receiving once the returned
+    /// gate is opened, by sending to or dropping it, so that the channel can be kept full.
+    fn new_buffered_state_with_gated_committer(
+        async_commit_channel_full_policy: AsyncCommitChannelFullPolicy,
+    ) -> (BufferedState, Sender<()>) {
+        let (state_commit_sender, state_commit_receiver) =
+            crossbeam_channel::bounded(ASYNC_COMMIT_CHANNEL_BUFFER_SIZE as usize);
+        let (gate_sender, gate_receiver) = mpsc::channel();
+        let join_handle = std::thread::spawn(move || {
+            let _ = gate_receiver.recv();
+            while let Ok(msg) = state_commit_receiver.recv() {
+                match msg {
+                    CommitMessage::Data(_) => ASYNC_COMMIT_CHANNEL_OCCUPANCY.dec(),
+                    CommitMessage::Sync(finish_sender) => finish_sender.send(()).unwrap(),
+                    CommitMessage::Exit => break,
+                }
+            }
+        });
+        let buffered_state = BufferedState {
+            state_until_checkpoint: None,
+            state_after_checkpoint: StateDelta::new_empty(),
+            state_commit_sender,
+            // Every checkpoint makes a snapshot due.
+            snapshot_trigger_policy: SnapshotTriggerPolicy::ByItems(1),
+            async_commit_channel_full_policy,
+            last_snapshot_time: Instant::now(),
+            join_handle: Some(join_handle),
+        };
+        (buffered_state, gate_sender)
+    }
+
+    /// Applies an update that moves the latest checkpoint to `version`.
+    fn update_to_checkpoint(
+        buffered_state: &mut BufferedState,
+        version: Version,
+    ) -> AsyncCommitChannelStatus {
+        let updates = HashMap::from([(
+            StateKey::raw(version.to_be_bytes().to_vec()),
+            Some(StateValue::from(vec![0; 10])),
+        )]);
+        let new_state_after_checkpoint = StateDelta::new_at_checkpoint(
+            HashValue::random(),
+            StateStorageUsage::zero(),
+            Some(version),
+        );
+        buffered_state
+            .update(
+                Some(updates),
+                new_state_after_checkpoint,
+                false, /* sync_commit */
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_block_until_channel_has_room() {
+        let (mut buffered_state, gate) =
+            new_buffered_state_with_gated_committer(AsyncCommitChannelFullPolicy::Block);
+        assert_eq!(
+            update_to_checkpoint(&mut buffered_state, 0),
+            AsyncCommitChannelStatus::Available
+        );
+
+        // The channel is full until the gate is opened.
+        let delay = Duration::from_millis(100);
+        let opener = std::thread::spawn(move || {
+            std::thread::sleep(delay);
+            gate.send(()).unwrap();
+        });
+        let start = Instant::now();
+        assert_eq!(
+            update_to_checkpoint(&mut buffered_state, 1),
+            AsyncCommitChannelStatus::Available
+        );
+        assert!(start.elapsed() >= delay);
+        assert!(buffered_state.state_until_checkpoint.is_none());
+        opener.join().unwrap();
+    }
+
+    #[test]
+    fn test_block_with_timeout_when_channel_full() {
+        let timeout = Duration::from_millis(100);
+        let (mut buffered_state, gate) = new_buffered_state_with_gated_committer(
+            AsyncCommitChannelFullPolicy::BlockWithTimeout(timeout),
+        );
+        assert_eq!(
+            update_to_checkpoint(&mut buffered_state, 0),
+            AsyncCommitChannelStatus::Available
+        );
+
+        // The channel stays full, so the snapshot is held back after the timeout.
+        let start = Instant::now();
+        assert_eq!(
+            update_to_checkpoint(&mut buffered_state, 1),
+            AsyncCommitChannelStatus::Full
+        );
+        assert!(start.elapsed() >= timeout);
+        // The next update is merged into the held back snapshot.
+        assert_eq!(
+            update_to_checkpoint(&mut buffered_state, 2),
+            AsyncCommitChannelStatus::Full
+        );
+        let held_back = buffered_state.state_until_checkpoint.as_ref().unwrap();
+        assert_eq!(held_back.base_version, Some(0));
+        assert_eq!(held_back.current_version, Some(2));
+        assert_eq!(held_back.updates_since_base.len(), 2);
+
+        // Once the channel has room, the held back snapshot goes out with the next update.
+        gate.send(()).unwrap();
+        assert_eq!(
+            update_to_checkpoint(&mut buffered_state, 3),
+            AsyncCommitChannelStatus::Available
+        );
+        assert!(buffered_state.state_until_checkpoint.is_none());
+    }
+
+    #[test]
+    fn test_snapshot_triggered_by_time() {
+        let (mut buffered_state, gate) =
+            new_buffered_state_with_gated_committer(AsyncCommitChannelFullPolicy::Block);
+        drop(gate);
+        let interval = Duration::from_secs(60);
+        buffered_state.snapshot_trigger_policy = SnapshotTriggerPolicy::ByTime {
+            interval_ms: interval.as_millis() as u64,
+        };
+
+        // No snapshot is due before the interval has passed, so the checkpoints are merged.
+        update_to_checkpoint(&mut buffered_state, 0);
+        update_to_checkpoint(&mut buffered_state, 1);
+        let pending = buffered_state.state_until_checkpoint.as_ref().unwrap();
+        assert_eq!(pending.base_version, None);
+        assert_eq!(pending.current_version, Some(1));
+
+        // Once it has, the next checkpoint sends a snapshot and restarts the interval.
+        buffered_state.last_snapshot_time = Instant::now().checked_sub(interval).unwrap();
+        let num_time_triggers = SNAPSHOT_TRIGGERS.with_label_values(&["time"]).get();
+        update_to_checkpoint(&mut buffered_state, 2);
+        assert!(buffered_state.state_until_checkpoint.is_none());
+        assert!(buffered_state.last_snapshot_time.elapsed() < interval);
+        assert!(SNAPSHOT_TRIGGERS.with_label_values(&["time"]).get() > num_time_triggers);
+
+        // The interval is counted from the last snapshot.
+        update_to_checkpoint(&mut buffered_state, 3);
+        assert!(buffered_state.state_until_checkpoint.is_some());
+    }
+}
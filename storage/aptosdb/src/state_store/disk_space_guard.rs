@@ -0,0 +1,87 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{errors::AptosDbError, metrics::STATE_KV_FREE_BYTES};
+use anyhow::Result;
+use aptos_infallible::Mutex;
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+use sysinfo::{DiskExt, RefreshKind, System, SystemExt};
+
+/// How long a reading of the free space is reused, since refreshing it re-enumerates the mounts
+/// and the guard is checked on every write.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Refuses writes once the free space on the volume holding the state K/V DB drops below a
+/// threshold, since running out of disk in the middle of a commit can corrupt RocksDB.
+pub(crate) struct DiskSpaceGuard {
+    path: PathBuf,
+    min_free_bytes: u64,
+    state: Mutex<DiskSpaceState>,
+}
+
+struct DiskSpaceState {
+    system: System,
+    refreshed_at: Option<Instant>,
+    available: Option<u64>,
+}
+
+impl DiskSpaceGuard {
+    /// A `min_free_bytes` of 0 disables the guard.
+    pub fn new(path: &Path, min_free_bytes: u64) -> Self {
+        Self {
+            path: path.canonicalize().unwrap_or_else(|_| path.to_path_buf()),
+            min_free_bytes,
+            state: Mutex::new(DiskSpaceState {
+                system: System::new_with_specifics(RefreshKind::new()),
+                refreshed_at: None,
+                available: None,
+            }),
+        }
+    }
+
+    /// The free space is read at most once per `REFRESH_INTERVAL`, and only reported while the
+    /// guard is enabled.
+    pub fn check(&self) -> Result<()> {
+        if self.min_free_bytes == 0 {
+            return Ok(());
+        }
+        // Don't block writes if we can't tell which volume the DB lives on.
+        if let Some(available) = self.available_space() {
+            if available < self.min_free_bytes {
+                return Err(
+                    AptosDbError::InsufficientDiskSpace(available, self.min_free_bytes).into(),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn available_space(&self) -> Option<u64> {
+        let mut state = self.state.lock();
+        match state.refreshed_at {
+            Some(refreshed_at) if refreshed_at.elapsed() < REFRESH_INTERVAL => {
+                return state.available;
+            },
+            Some(_) => state.system.refresh_disks(),
+            // The disks are only listed once the guard is used, so a disabled one costs nothing.
+            None => state.system.refresh_disks_list(),
+        }
+        // The volume is the one with the longest mount point containing the path.
+        let available = state
+            .system
+            .disks()
+            .iter()
+            .filter(|disk| self.path.starts_with(disk.mount_point()))
+            .max_by_key(|disk| disk.mount_point().as_os_str().len())
+            .map(|disk| disk.available_space());
+        if let Some(available) = available {
+            STATE_KV_FREE_BYTES.set(available as i64);
+        }
+        state.refreshed_at = Some(Instant::now());
+        state.available = available;
+        available
+    }
+}
@@ -7,13 +7,28 @@
 use crate::{
     db_metadata::{DbMetadataKey, DbMetadataSchema, DbMetadataValue},
     epoch_by_version::EpochByVersionSchema,
-    metrics::{STATE_ITEMS, TOTAL_STATE_BYTES},
-    schema::state_value::StateValueSchema,
+    errors::StateValueChunkError,
+    event_store::EventStore,
+    ledger_info::LedgerInfoSchema,
+    metrics::{
+        STARTUP_REPLAY_SECONDS, STATE_ITEMS, STATE_KV_SHARD_BYTES, STATE_KV_SHARD_ROWS,
+        STATE_MERKLE_UNAVAILABLE_ERRORS, TOTAL_STATE_BYTES,
+    },
+    pruner::{db_pruner::DBPruner, pruner_manager::PrunerManager},
+    schema::{state_value::StateValueSchema, write_set::WriteSetSchema},
     stale_state_value_index::StaleStateValueIndexSchema,
     state_kv_db::StateKvDb,
-    state_merkle_db::StateMerkleDb,
+    state_merkle_db::{Node, StateMerkleDb},
     state_restore::{StateSnapshotProgress, StateSnapshotRestore, StateValueWriter},
-    state_store::buffered_state::BufferedState,
+    state_store::{
+        buffered_state::BufferedState,
+        disk_space_guard::DiskSpaceGuard,
+        pinned_state_reader::{PinnedStateReader, PinnedVersions},
+        pinned_value_cache::PinnedValueCache,
+        restore_limiter::{LimitedSnapshotReceiver, RestoreLimiter},
+        state_value_chunk_cursor::StateValueChunkCursor,
+    },
+    transaction_info::TransactionInfoSchema,
     utils::{
         iterators::PrefixedStateValueIterator,
         truncation_helper::{truncate_ledger_db, truncate_state_kv_db},
@@ -21,46 +36,71 @@ use crate::{
     version_data::VersionDataSchema,
     AptosDbError, LedgerStore, ShardedStateKvSchemaBatch, StaleNodeIndexCrossEpochSchema,
     StaleNodeIndexSchema, StateKvPrunerManager, StateMerklePrunerManager, TransactionStore,
-    OTHER_TIMERS_SECONDS,
+    NUM_STATE_SHARDS, OTHER_TIMERS_SECONDS,
+};
+use anyhow::{bail, ensure, format_err, Result};
+use aptos_config::config::{
+    AsyncCommitChannelFullPolicy, SnapshotTriggerPolicy, StorageConfig, UsageMismatchPolicy,
 };
-use anyhow::{ensure, format_err, Result};
 use aptos_crypto::{
-    hash::{CryptoHash, SPARSE_MERKLE_PLACEHOLDER_HASH},
+    hash::{CryptoHash, DefaultHasher, SPARSE_MERKLE_PLACEHOLDER_HASH},
     HashValue,
 };
-use aptos_executor_types::in_memory_state_calculator::InMemoryStateCalculator;
+use aptos_executor_types::{in_memory_state_calculator::InMemoryStateCalculator, ProofReader};
 use aptos_infallible::Mutex;
-use aptos_jellyfish_merkle::iterator::JellyfishMerkleIterator;
-use aptos_logger::info;
-use aptos_schemadb::{ReadOptions, SchemaBatch, DB};
+use aptos_jellyfish_merkle::{
+    iterator::JellyfishMerkleIterator, restore::JellyfishMerkleRestore, NodeBatch, TreeWriter,
+};
+use aptos_logger::{error, info, warn};
+use aptos_schemadb::{schema::KeyCodec, ReadOptions, SchemaBatch, DB};
+use aptos_scratchpad::SparseMerkleTree;
 use aptos_state_view::StateViewId;
 use aptos_storage_interface::{
-    cached_state_view::CachedStateView, state_delta::StateDelta,
-    sync_proof_fetcher::SyncProofFetcher, DbReader, StateSnapshotReceiver,
+    cached_state_view::CachedStateView, jmt_update_refs, jmt_updates_with_hasher,
+    state_delta::StateDelta, sync_proof_fetcher::SyncProofFetcher, DbReader, LeafHasher,
+    StateSnapshotReceiver,
 };
 use aptos_types::{
-    proof::{definition::LeafCount, SparseMerkleProofExt, SparseMerkleRangeProof},
+    access_path::AccessPath,
+    account_address::AccountAddress,
+    ledger_info::LedgerInfoWithSignatures,
+    nibble::nibble_path::NibblePath,
+    proof::{
+        definition::LeafCount, SparseMerkleInternalNode, SparseMerkleLeafNode, SparseMerkleProof,
+        SparseMerkleProofExt, SparseMerkleRangeProof,
+    },
     state_store::{
-        state_key::StateKey,
+        state_key::{StateKey, StateKeyInner, StateKeyTag},
         state_key_prefix::StateKeyPrefix,
         state_storage_usage::StateStorageUsage,
         state_value::{StaleStateValueIndex, StateValue, StateValueChunkWithProof},
     },
     transaction::Version,
+    write_set::TransactionWrite,
 };
 use claims::{assert_ge, assert_le};
 use dashmap::DashMap;
+use futures::{channel::mpsc, executor::block_on, SinkExt, Stream};
+use move_core_types::language_storage::{StructTag, CORE_CODE_ADDRESS};
 use once_cell::sync::Lazy;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, HashSet},
-    ops::Deref,
+    cmp::{Ordering, Reverse},
+    collections::{BTreeMap, BinaryHeap, HashMap, HashSet},
+    ops::{Deref, Range},
+    path::Path,
     sync::Arc,
 };
 
 pub(crate) mod buffered_state;
+pub(crate) mod disk_space_guard;
+pub(crate) mod pinned_state_reader;
+pub(crate) mod pinned_value_cache;
+pub(crate) mod restore_limiter;
 mod state_merkle_batch_committer;
 mod state_snapshot_committer;
+pub(crate) mod state_value_chunk_cursor;
 
 #[cfg(test)]
 mod state_store_test;
@@ -74,6 +114,12 @@ const MAX_WRITE_SETS_AFTER_SNAPSHOT: LeafCount = buffered_state::TARGET_SNAPSHOT
 
 const MAX_COMMIT_PROGRESS_DIFFERENCE: u64 = 100000;
 
+/// Max number of values `StateStore::stream_state_values` reads ahead of its consumer.
+const STATE_VALUE_STREAM_READ_AHEAD: usize = 1000;
+
+/// Name of the manifest file written by `StateStore::export_snapshot_to_dir`.
+pub const MANIFEST_FILE_NAME: &str = "manifest";
+
 static IO_POOL: Lazy<rayon::ThreadPool> = Lazy::new(|| {
     rayon::ThreadPoolBuilder::new()
         .num_threads(32)
@@ -82,6 +128,177 @@ static IO_POOL: Lazy<rayon::ThreadPool> = Lazy::new(|| {
         .unwrap()
 });
 
+/// Which state a read is served from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ReadConsistency {
+    /// The state at the overall commit progress. Data beyond it may be truncated on restart, so
+    /// it's never returned.
+    Committed,
+    /// The latest state known to the buffered state, served from its in-memory updates where
+    /// possible.
+    BufferedTip,
+}
+
+/// Evidence anchoring a state proof to the validator set of the epoch the version belongs to.
+#[derive(Clone, Debug)]
+pub struct EpochEndingProof {
+    /// The epoch the version belongs to.
+    pub epoch: u64,
+    /// The ledger info ending the previous epoch, whose next epoch state is the validator set
+    /// of `epoch`. `None` for the genesis epoch.
+    pub previous_epoch_ending_ledger_info: Option<LedgerInfoWithSignatures>,
+}
+
+/// Describes a state snapshot exported by `StateStore::export_snapshot_to_dir`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ExportManifest {
+    pub version: Version,
+    pub root_hash: HashValue,
+    pub num_values: u64,
+    /// The chunk files, in the order of the hashed state keys they hold.
+    pub chunks: Vec<ExportedChunk>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ExportedChunk {
+    pub first_index: u64,
+    pub last_index: u64,
+    /// Name of the file in the export directory holding the BCS encoded
+    /// `StateValueChunkWithProof`.
+    pub file_name: String,
+}
+
+/// The top of a state tree, as returned by `StateStore::export_jmt_structure`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct JmtStructureDump {
+    pub version: Version,
+    pub max_depth: usize,
+    /// Parents come before their children, siblings in nibble order.
+    pub nodes: Vec<JmtNodeSummary>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct JmtNodeSummary {
+    pub nibble_path: NibblePath,
+    pub node_type: JmtNodeKind,
+    /// Number of direct children, 0 for leaves and empty trees.
+    pub num_children: usize,
+    pub hash: HashValue,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum JmtNodeKind {
+    Internal,
+    Leaf,
+    /// The root of an empty tree.
+    Null,
+}
+
+/// What a client trusting the root hashes of the state tree at two versions needs to transition
+/// from the former to the latter, as returned by `StateStore::get_incremental_state_proof`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IncrementalStateProof {
+    pub from_version: Version,
+    pub to_version: Version,
+    /// Each changed key with its value at `to_version`, `None` if deleted, and the proof of its
+    /// value at `from_version`, which holds the sibling hashes along its path.
+    pub updates: Vec<(StateKey, Option<StateValue>, SparseMerkleProofExt)>,
+}
+
+impl IncrementalStateProof {
+    /// Computes the root hash after applying the updates to the tree with `from_root_hash`, and
+    /// checks that it's `to_root_hash`, i.e., that the updates cover all the changes.
+    pub fn verify(&self, from_root_hash: HashValue, to_root_hash: HashValue) -> Result<()> {
+        let proof_reader = ProofReader::new(
+            self.updates
+                .iter()
+                .map(|(key, _, proof)| (key.hash(), proof.clone()))
+                .collect(),
+        );
+        let root_hash =
+            SparseMerkleTree::<StateValue>::new(from_root_hash, StateStorageUsage::new_untracked())
+                .freeze()
+                .batch_update(
+                    self.updates
+                        .iter()
+                        .map(|(key, value, _)| (key.hash(), value.as_ref()))
+                        .collect(),
+                    StateStorageUsage::new_untracked(),
+                    &proof_reader,
+                )?
+                .root_hash();
+        ensure!(
+            root_hash == to_root_hash,
+            "Root hash {} computed from the updates between versions {} and {} doesn't match \
+            expected {}, some changed keys are missing.",
+            root_hash,
+            self.from_version,
+            self.to_version,
+            to_root_hash,
+        );
+        Ok(())
+    }
+}
+
+/// The change in state storage usage between two versions, as returned by
+/// `StateStore::get_usage_delta`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StateStorageUsageDelta {
+    pub items_delta: i64,
+    pub bytes_delta: i64,
+}
+
+/// Called with the state storage usage when it crosses the threshold of a usage alert, see
+/// `StateStore::set_usage_alert`.
+pub type UsageAlertCallback = Arc<dyn Fn(StateStorageUsage) + Send + Sync>;
+
+struct UsageAlert {
+    bytes_threshold: u64,
+    callback: UsageAlertCallback,
+}
+
+/// Options of the state store, mirroring the corresponding fields of `StorageConfig`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StateStoreOptions {
+    pub snapshot_trigger_policy: SnapshotTriggerPolicy,
+    pub max_num_keys_in_state_stats_cache: usize,
+    pub skip_commit_progress_sync: bool,
+    pub min_free_disk_space_bytes: u64,
+    pub max_concurrent_restores: usize,
+    pub enable_shard_metrics: bool,
+    pub usage_mismatch_policy: UsageMismatchPolicy,
+    pub max_state_values_per_restore_batch: usize,
+    pub degrade_on_state_merkle_errors: bool,
+    pub error_on_pruned_state_value_reads: bool,
+    pub async_commit_channel_full_policy: AsyncCommitChannelFullPolicy,
+    pub pin_framework_resources: bool,
+}
+
+impl From<&StorageConfig> for StateStoreOptions {
+    fn from(config: &StorageConfig) -> Self {
+        Self {
+            snapshot_trigger_policy: config.snapshot_trigger_policy(),
+            max_num_keys_in_state_stats_cache: config.max_num_keys_in_state_stats_cache,
+            skip_commit_progress_sync: config.skip_commit_progress_sync,
+            min_free_disk_space_bytes: config.min_free_disk_space_bytes,
+            max_concurrent_restores: config.max_concurrent_restores,
+            enable_shard_metrics: config.enable_state_kv_shard_metrics,
+            usage_mismatch_policy: config.usage_mismatch_policy,
+            max_state_values_per_restore_batch: config.max_state_values_per_restore_batch,
+            degrade_on_state_merkle_errors: config.degrade_on_state_merkle_errors,
+            error_on_pruned_state_value_reads: config.error_on_pruned_state_value_reads,
+            async_commit_channel_full_policy: config.async_commit_channel_full_policy,
+            pin_framework_resources: config.pin_framework_resources,
+        }
+    }
+}
+
+impl Default for StateStoreOptions {
+    fn default() -> Self {
+        Self::from(&StorageConfig::default())
+    }
+}
+
 pub(crate) struct StateDb {
     pub ledger_db: Arc<DB>,
     pub state_merkle_db: Arc<StateMerkleDb>,
@@ -89,6 +306,11 @@ pub(crate) struct StateDb {
     pub state_merkle_pruner: StateMerklePrunerManager<StaleNodeIndexSchema>,
     pub epoch_snapshot_pruner: StateMerklePrunerManager<StaleNodeIndexCrossEpochSchema>,
     pub state_kv_pruner: StateKvPrunerManager,
+    // Min number of the latest epoch snapshots the epoch snapshot pruner retains, 0 if only its
+    // window applies.
+    pub num_epoch_snapshots_to_retain: usize,
+    // The versions pinned by `PinnedStateReader`s, which the state pruners keep readable.
+    pub pinned_versions: PinnedVersions,
 }
 
 pub(crate) struct StateStore {
@@ -97,7 +319,19 @@ pub(crate) struct StateStore {
     // is the latest state sparse merkle tree that is replayed from that snapshot until the latest
     // write set stored in ledger_db.
     buffered_state: Mutex<BufferedState>,
-    buffered_state_target_items: usize,
+    snapshot_trigger_policy: SnapshotTriggerPolicy,
+    async_commit_channel_full_policy: AsyncCommitChannelFullPolicy,
+    max_num_keys_in_state_stats_cache: usize,
+    disk_space_guard: DiskSpaceGuard,
+    restore_limiter: RestoreLimiter,
+    pinned_value_cache: PinnedValueCache,
+    pin_framework_resources: bool,
+    enable_shard_metrics: bool,
+    usage_mismatch_policy: UsageMismatchPolicy,
+    max_state_values_per_restore_batch: usize,
+    degrade_on_state_merkle_errors: bool,
+    error_on_pruned_state_value_reads: bool,
+    usage_alert: Mutex<Option<UsageAlert>>,
 }
 
 impl Deref for StateStore {
@@ -179,19 +413,76 @@ impl DbReader for StateDb {
 }
 
 impl StateDb {
+    /// Returns the version of the latest transaction info in the ledger db, or `None` if there is
+    /// none, without locking the buffered state. Transaction infos are written before the ledger
+    /// info that commits them, so this can be ahead of the latest ledger info.
+    pub fn get_latest_txn_info_version(&self) -> Result<Option<Version>> {
+        let mut iter = self
+            .ledger_db
+            .iter::<TransactionInfoSchema>(ReadOptions::default())?;
+        iter.seek_to_last();
+        Ok(iter.next().transpose()?.map(|(version, _)| version))
+    }
+
     /// Get the latest state value and the its corresponding version when its of the given key up
     /// to the given version.
     pub fn get_state_value_with_version_by_version(
         &self,
         state_key: &StateKey,
         version: Version,
+    ) -> Result<Option<(Version, StateValue)>> {
+        self.get_state_value_with_version_in_shard(state_key.get_shard_id(), state_key, version)
+    }
+
+    /// Same as `get_state_value_with_version_by_version`, with a hint that the key was last
+    /// written at or after `lower_bound_version`, e.g., because the VM knows it was written
+    /// recently, so that the iterator is bounded to the versions of the key since then. The hint
+    /// is advisory: if the key has no version in `[lower_bound_version, version]`, older versions
+    /// are read as without the hint, so the result is the same either way.
+    pub fn get_state_value_with_version_by_version_with_lower_bound(
+        &self,
+        state_key: &StateKey,
+        version: Version,
+        lower_bound_version: Option<Version>,
+    ) -> Result<Option<(Version, StateValue)>> {
+        // At 0 there are no older versions to exclude, and above `version` there is nothing to
+        // read within the bound.
+        let lower_bound_version =
+            lower_bound_version.filter(|lower_bound| (1..=version).contains(lower_bound));
+        if let Some(lower_bound_version) = lower_bound_version {
+            let mut read_opts = ReadOptions::default();
+            // We want `None` if the state_key changes in iteration.
+            read_opts.set_prefix_same_as_start(true);
+            // Versions are encoded in descending order, so the older versions of the key come
+            // after the lower bound, and the iteration stops before them.
+            read_opts.set_iterate_upper_bound(KeyCodec::<StateValueSchema>::encode_key(&(
+                state_key.clone(),
+                lower_bound_version - 1,
+            ))?);
+            let mut iter = self
+                .state_kv_db
+                .db_shard(state_key.get_shard_id())
+                .iter::<StateValueSchema>(read_opts)?;
+            iter.seek(&(state_key.clone(), version))?;
+            if let Some(((_, version), value_opt)) = iter.next().transpose()? {
+                return Ok(value_opt.map(|value| (version, value)));
+            }
+        }
+        self.get_state_value_with_version_by_version(state_key, version)
+    }
+
+    fn get_state_value_with_version_in_shard(
+        &self,
+        shard_id: u8,
+        state_key: &StateKey,
+        version: Version,
     ) -> Result<Option<(Version, StateValue)>> {
         let mut read_opts = ReadOptions::default();
         // We want `None` if the state_key changes in iteration.
         read_opts.set_prefix_same_as_start(true);
         let mut iter = self
             .state_kv_db
-            .db_shard(state_key.get_shard_id())
+            .db_shard(shard_id)
             .iter::<StateValueSchema>(read_opts)?;
         iter.seek(&(state_key.clone(), version))?;
         Ok(iter
@@ -200,6 +491,117 @@ impl StateDb {
             .and_then(|((_, version), value_opt)| value_opt.map(|value| (version, value))))
     }
 
+    /// Returns the values of `keys` at `version`, in the same order as `keys`, with `None` where a
+    /// key doesn't exist or has been deleted. The keys are grouped by shard, and the shards are
+    /// read in parallel with one iterator each, which is cheaper than a lookup per key.
+    pub fn get_state_values_by_version(
+        &self,
+        keys: &[StateKey],
+        version: Version,
+    ) -> Result<Vec<Option<StateValue>>> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["get_state_values_by_version"])
+            .start_timer();
+        let mut key_indices_by_shard = vec![vec![]; NUM_STATE_SHARDS];
+        for (index, key) in keys.iter().enumerate() {
+            key_indices_by_shard[key.get_shard_id() as usize].push(index);
+        }
+        let shard_values = IO_POOL.install(|| {
+            key_indices_by_shard
+                .par_iter()
+                .enumerate()
+                .filter(|(_, key_indices)| !key_indices.is_empty())
+                .map(|(shard_id, key_indices)| {
+                    self.get_shard_state_values_by_version(
+                        shard_id as u8,
+                        keys,
+                        key_indices,
+                        version,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+        let mut values = vec![None; keys.len()];
+        for (index, value) in shard_values.into_iter().flatten() {
+            values[index] = value;
+        }
+        Ok(values)
+    }
+
+    /// Returns the values at `version` of the keys at `key_indices` in `keys`, all in shard
+    /// `shard_id`, along with their indices.
+    fn get_shard_state_values_by_version(
+        &self,
+        shard_id: u8,
+        keys: &[StateKey],
+        key_indices: &[usize],
+        version: Version,
+    ) -> Result<Vec<(usize, Option<StateValue>)>> {
+        let mut read_opts = ReadOptions::default();
+        // We want `None` if the state_key changes in iteration.
+        read_opts.set_prefix_same_as_start(true);
+        let mut iter = self
+            .state_kv_db
+            .db_shard(shard_id)
+            .iter::<StateValueSchema>(read_opts)?;
+        // In the order of the db, the iterator only seeks forward, and a repeated key is next to
+        // its first occurrence, so it's read only once.
+        let mut sorted_keys = key_indices
+            .iter()
+            .map(|index| Ok((keys[*index].encode()?, *index)))
+            .collect::<Result<Vec<_>>>()?;
+        sorted_keys.sort_unstable();
+
+        let mut values: Vec<(usize, Option<StateValue>)> = Vec::with_capacity(sorted_keys.len());
+        let mut prev_encoded_key = None;
+        for (encoded_key, index) in sorted_keys {
+            let value = if prev_encoded_key.as_ref() == Some(&encoded_key) {
+                values.last().expect("Must exist.").1.clone()
+            } else {
+                iter.seek(&(keys[index].clone(), version))?;
+                iter.next()
+                    .transpose()?
+                    .and_then(|(_, value_opt)| value_opt)
+            };
+            values.push((index, value));
+            prev_encoded_key = Some(encoded_key);
+        }
+        Ok(values)
+    }
+
+    /// Returns the values of `keys` at `version` with their proofs, in the same order as `keys`.
+    /// The proofs are looked up in parallel, once per distinct key.
+    pub fn get_state_values_with_proofs_by_version(
+        &self,
+        keys: &[StateKey],
+        version: Version,
+    ) -> Result<Vec<(Option<StateValue>, SparseMerkleProofExt)>> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["get_state_values_with_proofs_by_version"])
+            .start_timer();
+        let mut unique_keys = vec![];
+        let mut unique_key_indices = HashMap::new();
+        let positions = keys
+            .iter()
+            .map(|key| {
+                *unique_key_indices.entry(key).or_insert_with(|| {
+                    unique_keys.push(key);
+                    unique_keys.len() - 1
+                })
+            })
+            .collect::<Vec<_>>();
+        let values_with_proofs = IO_POOL.install(|| {
+            unique_keys
+                .par_iter()
+                .map(|key| self.get_state_value_with_proof_by_version_ext(key, version))
+                .collect::<Result<Vec<_>>>()
+        })?;
+        Ok(positions
+            .into_iter()
+            .map(|position| values_with_proofs[position].clone())
+            .collect())
+    }
+
     /// Get the latest ended epoch strictly before required version, i.e. if the passed in version
     /// ends an epoch, return one epoch early than that.
     pub fn get_previous_epoch_ending(&self, version: Version) -> Result<Option<(u64, Version)>> {
@@ -215,6 +617,76 @@ impl StateDb {
         iter.seek_for_prev(&prev_version)?;
         iter.next().transpose()
     }
+
+    /// Sets the target of the epoch snapshot pruner for `latest_version`, retaining at least the
+    /// latest `num_epoch_snapshots_to_retain` epoch snapshots and the pinned versions.
+    pub fn maybe_set_epoch_snapshot_pruner_target(&self, latest_version: Version) -> Result<()> {
+        let max_target_version = if self.num_epoch_snapshots_to_retain == 0 {
+            None
+        } else {
+            let mut iter = self
+                .ledger_db
+                .rev_iter::<EpochByVersionSchema>(ReadOptions::default())?;
+            iter.seek_for_prev(&latest_version)?;
+            // With fewer epoch snapshots than to retain, nothing is pruned.
+            Some(match iter.nth(self.num_epoch_snapshots_to_retain - 1) {
+                Some(res) => res?.0,
+                None => 0,
+            })
+        };
+
+        let pruner = &self.epoch_snapshot_pruner;
+        self.pinned_versions.with_min_pinned(|min_pinned| {
+            match max_target_version.into_iter().chain(min_pinned).min() {
+                Some(max_target) => {
+                    pruner.maybe_set_capped_pruner_target_db_version(latest_version, max_target)
+                },
+                None => pruner.maybe_set_pruner_target_db_version(latest_version),
+            }
+        });
+        Ok(())
+    }
+
+    /// Sets the target of the state merkle pruner for `latest_version`, retaining the pinned
+    /// versions.
+    pub fn maybe_set_state_merkle_pruner_target(&self, latest_version: Version) {
+        let pruner = &self.state_merkle_pruner;
+        self.pinned_versions
+            .with_min_pinned(|min_pinned| match min_pinned {
+                Some(min_pinned) => {
+                    pruner.maybe_set_capped_pruner_target_db_version(latest_version, min_pinned)
+                },
+                None => pruner.maybe_set_pruner_target_db_version(latest_version),
+            });
+    }
+
+    /// Sets the target of the state K/V pruner for `latest_version`, retaining the pinned
+    /// versions.
+    pub fn maybe_set_state_kv_pruner_target(&self, latest_version: Version) {
+        let pruner = &self.state_kv_pruner;
+        self.pinned_versions
+            .with_min_pinned(|min_pinned| match min_pinned {
+                Some(min_pinned) => {
+                    pruner.maybe_set_capped_pruner_target_db_version(latest_version, min_pinned)
+                },
+                None => pruner.maybe_set_pruner_target_db_version(latest_version),
+            });
+    }
+
+    /// Pins `version`, so the state pruners keep the state values readable at it until it's
+    /// unpinned. Fails if they are already pruned, or about to be.
+    fn pin_version(&self, version: Version) -> Result<()> {
+        self.pinned_versions.pin(version, || {
+            let min_readable_version = self.state_kv_pruner.pruner().target_version();
+            ensure!(
+                version >= min_readable_version,
+                "{} is pruned, min readable version is {}.",
+                version,
+                min_readable_version,
+            );
+            Ok(())
+        })
+    }
 }
 
 impl DbReader for StateStore {
@@ -234,6 +706,16 @@ impl DbReader for StateStore {
         state_key: &StateKey,
         version: Version,
     ) -> Result<Option<StateValue>> {
+        // Otherwise, a value pruned away reads as if the key didn't exist.
+        if self.error_on_pruned_state_value_reads {
+            let min_readable_version = self.state_kv_pruner.get_min_readable_version();
+            if version < min_readable_version {
+                return Err(AptosDbError::VersionPruned(version, min_readable_version).into());
+            }
+        }
+        if let Some(value) = self.pinned_value_cache.get(state_key, version) {
+            return Ok(value);
+        }
         self.deref().get_state_value_by_version(state_key, version)
     }
 
@@ -245,6 +727,7 @@ impl DbReader for StateStore {
     ) -> Result<SparseMerkleProofExt> {
         self.deref()
             .get_state_proof_by_version_ext(state_key, version)
+            .map_err(|err| self.state_merkle_error(err))
     }
 
     /// Get the state value with proof extension given the state key and version
@@ -255,6 +738,7 @@ impl DbReader for StateStore {
     ) -> Result<(Option<StateValue>, SparseMerkleProofExt)> {
         self.deref()
             .get_state_value_with_proof_by_version_ext(state_key, version)
+            .map_err(|err| self.state_merkle_error(err))
     }
 }
 
@@ -285,14 +769,37 @@ impl StateStore {
         state_merkle_pruner: StateMerklePrunerManager<StaleNodeIndexSchema>,
         epoch_snapshot_pruner: StateMerklePrunerManager<StaleNodeIndexCrossEpochSchema>,
         state_kv_pruner: StateKvPrunerManager,
-        buffered_state_target_items: usize,
+        num_epoch_snapshots_to_retain: usize,
+        disk_space_guard: DiskSpaceGuard,
+        options: StateStoreOptions,
         hack_for_tests: bool,
     ) -> Self {
-        Self::sync_commit_progress(
-            Arc::clone(&ledger_db),
-            Arc::clone(&state_kv_db),
-            /*crash_if_difference_is_too_large=*/ true,
-        );
+        let StateStoreOptions {
+            snapshot_trigger_policy,
+            max_num_keys_in_state_stats_cache,
+            skip_commit_progress_sync,
+            min_free_disk_space_bytes: _,
+            max_concurrent_restores,
+            enable_shard_metrics,
+            usage_mismatch_policy,
+            max_state_values_per_restore_batch,
+            degrade_on_state_merkle_errors,
+            error_on_pruned_state_value_reads,
+            async_commit_channel_full_policy,
+            pin_framework_resources,
+        } = options;
+        if skip_commit_progress_sync {
+            warn!(
+                "Skipping commit progress sync on start up. The sub DBs might be ahead of the \
+                overall commit progress and must be repaired before the DB is used."
+            );
+        } else {
+            Self::sync_commit_progress(
+                Arc::clone(&ledger_db),
+                Arc::clone(&state_kv_db),
+                /*crash_if_difference_is_too_large=*/ true,
+            );
+        }
         let state_db = Arc::new(StateDb {
             ledger_db,
             state_merkle_db,
@@ -300,11 +807,14 @@ impl StateStore {
             state_merkle_pruner,
             epoch_snapshot_pruner,
             state_kv_pruner,
+            num_epoch_snapshots_to_retain,
+            pinned_versions: PinnedVersions::default(),
         });
         let buffered_state = Mutex::new(
             Self::create_buffered_state_from_latest_snapshot(
                 &state_db,
-                buffered_state_target_items,
+                snapshot_trigger_policy,
+                async_commit_channel_full_policy,
                 hack_for_tests,
                 /*check_max_versions_after_snapshot=*/ true,
             )
@@ -313,8 +823,31 @@ impl StateStore {
         Self {
             state_db,
             buffered_state,
-            buffered_state_target_items,
+            snapshot_trigger_policy,
+            async_commit_channel_full_policy,
+            max_num_keys_in_state_stats_cache,
+            disk_space_guard,
+            restore_limiter: RestoreLimiter::new(max_concurrent_restores),
+            pinned_value_cache: PinnedValueCache::default(),
+            pin_framework_resources,
+            enable_shard_metrics,
+            usage_mismatch_policy,
+            max_state_values_per_restore_batch,
+            degrade_on_state_merkle_errors,
+            error_on_pruned_state_value_reads,
+            usage_alert: Mutex::new(None),
+        }
+    }
+
+    /// With `degrade_on_state_merkle_errors`, reports an IO or RocksDB error reading a proof as
+    /// `AptosDbError::MerkleUnavailable`, so that callers can tell reads without proofs are still
+    /// served. Other errors, e.g. a missing node or a pruned version, are returned as is.
+    fn state_merkle_error(&self, error: anyhow::Error) -> anyhow::Error {
+        if !self.degrade_on_state_merkle_errors || !is_io_error(&error) {
+            return error;
         }
+        STATE_MERKLE_UNAVAILABLE_ERRORS.inc();
+        AptosDbError::MerkleUnavailable(format!("{:#}", error)).into()
     }
 
     // We commit the overall commit progress at the last, and use it as the source of truth of the
@@ -414,9 +947,14 @@ impl StateStore {
             state_merkle_pruner,
             epoch_snapshot_pruner,
             state_kv_pruner,
+            num_epoch_snapshots_to_retain: 0,
+            pinned_versions: PinnedVersions::default(),
         });
         let buffered_state = Self::create_buffered_state_from_latest_snapshot(
-            &state_db, 0, /*hack_for_tests=*/ false,
+            &state_db,
+            SnapshotTriggerPolicy::ByItems(0),
+            AsyncCommitChannelFullPolicy::Block,
+            /*hack_for_tests=*/ false,
             /*check_max_versions_after_snapshot=*/ false,
         )?;
         Ok(buffered_state.current_state().base_version)
@@ -424,15 +962,14 @@ impl StateStore {
 
     fn create_buffered_state_from_latest_snapshot(
         state_db: &Arc<StateDb>,
-        buffered_state_target_items: usize,
+        snapshot_trigger_policy: SnapshotTriggerPolicy,
+        async_commit_channel_full_policy: AsyncCommitChannelFullPolicy,
         hack_for_tests: bool,
         check_max_versions_after_snapshot: bool,
     ) -> Result<BufferedState> {
-        let ledger_store = LedgerStore::new(Arc::clone(&state_db.ledger_db));
-        let num_transactions = ledger_store
-            .get_latest_transaction_info_option()?
-            .map(|(version, _)| version + 1)
-            .unwrap_or(0);
+        let num_transactions = state_db
+            .get_latest_txn_info_version()?
+            .map_or(0, |version| version + 1);
 
         let latest_snapshot_version = state_db
             .state_merkle_db
@@ -454,7 +991,8 @@ impl StateStore {
                 usage,
                 latest_snapshot_version,
             ),
-            buffered_state_target_items,
+            snapshot_trigger_policy,
+            async_commit_channel_full_policy,
         );
 
         // In some backup-restore tests we hope to open the db without consistency check.
@@ -493,8 +1031,8 @@ impl StateStore {
             )?;
             let write_sets = TransactionStore::new(Arc::clone(&state_db.ledger_db))
                 .get_write_sets(snapshot_next_version, num_transactions)?;
-            let txn_info_iter =
-                ledger_store.get_transaction_info_iter(snapshot_next_version, write_sets.len())?;
+            let txn_info_iter = LedgerStore::new(Arc::clone(&state_db.ledger_db))
+                .get_transaction_info_iter(snapshot_next_version, write_sets.len())?;
             let last_checkpoint_index = txn_info_iter
                 .into_iter()
                 .collect::<Result<Vec<_>>>()?
@@ -503,15 +1041,33 @@ impl StateStore {
                 .filter(|(_idx, txn_info)| txn_info.is_state_checkpoint())
                 .last()
                 .map(|(idx, _)| idx);
-            latest_snapshot_state_view.prime_cache_by_write_set(&write_sets)?;
-            let calculator = InMemoryStateCalculator::new(
-                buffered_state.current_state(),
-                latest_snapshot_state_view.into_state_cache(),
-            );
-            let (updates_until_last_checkpoint, state_after_last_checkpoint) = calculator
-                .calculate_for_write_sets_after_snapshot(last_checkpoint_index, &write_sets)?;
+            // Priming the cache only reads the values of the keys the write sets touch from the
+            // latest snapshot, so it's done in parallel.
+            {
+                let _timer = STARTUP_REPLAY_SECONDS
+                    .with_label_values(&["prime_cache"])
+                    .start_timer();
+                latest_snapshot_state_view.prime_cache_by_write_set(&write_sets)?;
+            }
+            // Applying the write sets must stay serial and in version order: a later write to a
+            // key overrides an earlier one, and the state is checkpointed right after the write
+            // set at `last_checkpoint_index`.
+            let (updates_until_last_checkpoint, state_after_last_checkpoint) = {
+                let _timer = STARTUP_REPLAY_SECONDS
+                    .with_label_values(&["calculate"])
+                    .start_timer();
+                let calculator = InMemoryStateCalculator::new(
+                    buffered_state.current_state(),
+                    latest_snapshot_state_view.into_state_cache(),
+                );
+                calculator
+                    .calculate_for_write_sets_after_snapshot(last_checkpoint_index, &write_sets)?
+            };
 
             // synchronously commit the snapshot at the last checkpoint here if not committed to disk yet.
+            let _timer = STARTUP_REPLAY_SECONDS
+                .with_label_values(&["commit"])
+                .start_timer();
             buffered_state.update(
                 updates_until_last_checkpoint,
                 state_after_last_checkpoint,
@@ -530,9 +1086,11 @@ impl StateStore {
     }
 
     pub fn reset(&self) {
+        self.pinned_value_cache.clear();
         *self.buffered_state.lock() = Self::create_buffered_state_from_latest_snapshot(
             &self.state_db,
-            self.buffered_state_target_items,
+            self.snapshot_trigger_policy,
+            self.async_commit_channel_full_policy,
             false,
             true,
         )
@@ -543,123 +1101,1467 @@ impl StateStore {
         &self.buffered_state
     }
 
-    /// Returns the key, value pairs for a particular state key prefix at at desired version. This
-    /// API can be used to get all resources of an account by passing the account address as the
-    /// key prefix.
-    pub fn get_prefixed_state_value_iterator(
-        &self,
-        key_prefix: &StateKeyPrefix,
-        first_key_opt: Option<&StateKey>,
-        desired_version: Version,
-    ) -> Result<PrefixedStateValueIterator> {
-        // TODO(grao): Support sharding here.
-        PrefixedStateValueIterator::new(
-            self.state_kv_db.metadata_db(),
-            key_prefix.clone(),
-            first_key_opt.cloned(),
-            desired_version,
-        )
+    /// Returns the version of the latest transaction info in the ledger db, or `None` if there is
+    /// none. Unlike `DbReader::get_latest_version`, which goes through the latest ledger info,
+    /// this includes transactions not yet covered by a ledger info.
+    pub fn get_latest_txn_info_version(&self) -> Result<Option<Version>> {
+        self.state_db.get_latest_txn_info_version()
     }
 
-    /// Gets the proof that proves a range of accounts.
-    pub fn get_value_range_proof(
-        &self,
-        rightmost_key: HashValue,
-        version: Version,
-    ) -> Result<SparseMerkleRangeProof> {
-        self.state_merkle_db.get_range_proof(rightmost_key, version)
+    /// Returns the version of the latest checkpoint in the buffered state, i.e., the base version
+    /// of the state after it, or `None` if there is none yet. Its snapshot may still be being
+    /// committed to the state merkle db. The buffered state is only locked to copy the version.
+    pub fn get_latest_checkpoint_version(&self) -> Option<Version> {
+        self.buffered_state.lock().current_checkpoint_version()
     }
 
-    /// Put the `value_state_sets` into its own CF.
-    pub fn put_value_sets(
-        &self,
-        value_state_sets: Vec<&HashMap<StateKey, Option<StateValue>>>,
-        first_version: Version,
-        expected_usage: StateStorageUsage,
-        ledger_batch: &SchemaBatch,
-        sharded_state_kv_batches: &ShardedStateKvSchemaBatch,
-    ) -> Result<()> {
+    /// Verifies that the root hash of the latest checkpoint in the buffered state, i.e. the state
+    /// replayed to on start up, matches the state checkpoint hash recorded in the ledger at that
+    /// version. Fails if the ledger doesn't have that version yet, e.g. during a state restore.
+    pub fn verify_buffered_state_consistency(&self) -> Result<()> {
+        let (checkpoint_version, checkpoint_root_hash) = {
+            let buffered_state = self.buffered_state.lock();
+            let current_state = buffered_state.current_state();
+            (current_state.base_version, current_state.base.root_hash())
+        };
+        let checkpoint_version = match checkpoint_version {
+            Some(version) => version,
+            // Nothing is committed yet.
+            None => return Ok(()),
+        };
+        let expected_root_hash = LedgerStore::new(Arc::clone(&self.ledger_db))
+            .get_transaction_info(checkpoint_version)?
+            .state_checkpoint_hash()
+            .ok_or_else(|| {
+                format_err!("Version {} is not a state checkpoint.", checkpoint_version)
+            })?;
+        ensure!(
+            checkpoint_root_hash == expected_root_hash,
+            "Buffered state root hash {} at checkpoint version {} doesn't match the state checkpoint hash {} in the ledger.",
+            checkpoint_root_hash,
+            checkpoint_version,
+            expected_root_hash,
+        );
+        Ok(())
+    }
+
+    /// Returns the number of versions accumulated since the latest snapshot persisted in the
+    /// state merkle db.
+    pub fn versions_since_last_snapshot(&self) -> Result<u64> {
+        let latest_version = match self.buffered_state.lock().current_state().current_version {
+            Some(version) => version,
+            None => return Ok(0),
+        };
+        let snapshot_next_version = self
+            .state_merkle_db
+            .get_state_snapshot_version_before(latest_version + 1)?
+            .map_or(0, |v| v + 1);
+        Ok(latest_version + 1 - snapshot_next_version)
+    }
+
+    /// Manually compacts the state kv db, either one shard or all of them, e.g. to reclaim space
+    /// and flatten the LSM levels after a bulk restore via `write_kv_batch`, before the node starts
+    /// serving reads.
+    pub fn compact_state_kv(&self, shard_id: Option<u8>) -> Result<()> {
+        Self::ensure_valid_shard_id(shard_id)?;
         let _timer = OTHER_TIMERS_SECONDS
-            .with_label_values(&["put_value_sets"])
+            .with_label_values(&["compact_state_kv"])
             .start_timer();
+        info!(shard_id = shard_id, "Compacting state kv db.");
+        self.state_kv_db.compact(shard_id)
+    }
 
-        self.put_stats_and_indices(
-            &value_state_sets,
-            first_version,
-            expected_usage,
-            ledger_batch,
-            sharded_state_kv_batches,
-        )?;
-
+    /// Manually compacts the state merkle db, either one shard or all of them. The companion of
+    /// `compact_state_kv`.
+    pub fn compact_state_merkle(&self, shard_id: Option<u8>) -> Result<()> {
+        Self::ensure_valid_shard_id(shard_id)?;
         let _timer = OTHER_TIMERS_SECONDS
-            .with_label_values(&["add_state_kv_batch"])
+            .with_label_values(&["compact_state_merkle"])
             .start_timer();
+        info!(shard_id = shard_id, "Compacting state merkle db.");
+        self.state_merkle_db.compact(shard_id)
+    }
 
-        value_state_sets
-            .par_iter()
-            .enumerate()
-            .flat_map_iter(|(i, kvs)| {
-                let version = first_version + i as Version;
-                kvs.iter().map(move |(k, v)| {
-                    sharded_state_kv_batches[k.get_shard_id() as usize]
-                        .put::<StateValueSchema>(&(k.clone(), version), v)
-                })
+    fn ensure_valid_shard_id(shard_id: Option<u8>) -> Result<()> {
+        if let Some(shard_id) = shard_id {
+            ensure!(
+                (shard_id as usize) < NUM_STATE_SHARDS,
+                "Invalid shard id {}, there are only {} shards.",
+                shard_id,
+                NUM_STATE_SHARDS,
+            );
+        }
+        Ok(())
+    }
+
+    /// Warms up the read caches for `keys` ahead of executing a block on top of `version`. The
+    /// tree nodes on the proof paths of the keys in the latest snapshot at or before `version` are
+    /// loaded into the state merkle node cache, and the values are read through so the underlying
+    /// block cache is warm as well. It's a no-op if the node cache is disabled.
+    pub fn prime_read_cache(&self, keys: &[StateKey], version: Version) -> Result<()> {
+        if !self.state_merkle_db.cache_enabled() {
+            return Ok(());
+        }
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["prime_read_cache"])
+            .start_timer();
+        let snapshot_version = match self
+            .state_merkle_db
+            .get_state_snapshot_version_before(version + 1)?
+        {
+            Some(snapshot_version) => snapshot_version,
+            None => return Ok(()),
+        };
+        IO_POOL.install(|| {
+            keys.par_iter().try_for_each(|key| {
+                self.state_merkle_db
+                    .get_with_proof_ext(key, snapshot_version)?;
+                self.get_state_value_with_version_by_version(key, version)?;
+                Ok(())
             })
-            .collect()
+        })
     }
 
-    pub fn get_usage(&self, version: Option<Version>) -> Result<StateStorageUsage> {
+    /// Returns the depth of each of `keys` in the tree at `version`, i.e. the number of siblings
+    /// in its proof, in the same order as `keys`. For a nonexistent key it's the depth at which
+    /// the search for it ends. A skewed distribution of depths means the tree is unbalanced.
+    pub fn get_proof_depths(&self, keys: &[StateKey], version: Version) -> Result<Vec<usize>> {
         let _timer = OTHER_TIMERS_SECONDS
-            .with_label_values(&["get_usage"])
+            .with_label_values(&["get_proof_depths"])
             .start_timer();
-        self.state_db.get_state_storage_usage(version)
+        IO_POOL.install(|| {
+            keys.par_iter()
+                .map(|key| {
+                    let (_, proof) = self.state_merkle_db.get_with_proof_ext(key, version)?;
+                    Ok(proof.siblings().len())
+                })
+                .collect()
+        })
     }
 
-    /// Put storage usage stats and State key and value indices into the batch.
-    /// The state KV indices will be generated as follows:
-    /// 1. A deletion at current version is always coupled with stale index for the tombstone with
-    /// `stale_since_version` equal to the version, to ensure tombstone is cleared from db after
-    /// pruner processes the current version.
-    /// 2. An update at current version will first try to find the corresponding old value, if it
-    /// exists, a stale index of that old value will be added. Otherwise, it's a no-op. Because
-    /// non-existence means either the key never shows up or it got deleted. Neither case needs
-    /// extra stale index as 1 cover the latter case.
-    pub fn put_stats_and_indices(
+    /// Returns the value of `state_key` effective at each of `versions`, in the same order as
+    /// `versions`, with `None` where the key doesn't exist or has been deleted. The values are
+    /// read with a single iterator over the key's entries instead of one seek per version.
+    pub fn get_value_history(
         &self,
-        value_state_sets: &[&HashMap<StateKey, Option<StateValue>>],
-        first_version: Version,
-        expected_usage: StateStorageUsage,
-        batch: &SchemaBatch,
-        sharded_state_kv_batches: &ShardedStateKvSchemaBatch,
-    ) -> Result<()> {
+        state_key: &StateKey,
+        versions: &[Version],
+    ) -> Result<Vec<Option<StateValue>>> {
         let _timer = OTHER_TIMERS_SECONDS
-            .with_label_values(&["put_stats_and_indices"])
+            .with_label_values(&["get_value_history"])
             .start_timer();
+        // Entries of a key are ordered from the newest version to the oldest, so the requested
+        // versions are visited in the same order.
+        let mut sorted_versions: Vec<_> = versions.iter().copied().enumerate().collect();
+        sorted_versions.sort_unstable_by_key(|(_, version)| Reverse(*version));
 
-        let base_version = first_version.checked_sub(1);
-        let mut usage = self.get_usage(base_version)?;
-        let base_version_usage = usage;
-        let cache = Arc::new(DashMap::<StateKey, (Version, Option<StateValue>)>::new());
+        let mut read_opts = ReadOptions::default();
+        read_opts.set_prefix_same_as_start(true);
+        let mut iter = self
+            .state_kv_db
+            .db_shard(state_key.get_shard_id())
+            .iter::<StateValueSchema>(read_opts)?;
+        // The entry with the largest version not above the version being visited, `None` once
+        // there is no such entry.
+        let mut current = None;
+        if let Some((_, max_version)) = sorted_versions.first() {
+            iter.seek(&(state_key.clone(), *max_version))?;
+            current = iter.next().transpose()?;
+        }
 
-        if let Some(base_version) = base_version {
-            let _timer = OTHER_TIMERS_SECONDS
-                .with_label_values(&["put_stats_and_indices__total_get"])
-                .start_timer();
-            let key_set = value_state_sets
-                .iter()
-                .flat_map(|value_state_set| value_state_set.iter())
-                .map(|(key, _)| key)
-                .collect::<HashSet<_>>();
-            IO_POOL.scope(|s| {
-                for key in key_set {
-                    let cache = cache.clone();
-                    s.spawn(move |_| {
-                        let _timer = OTHER_TIMERS_SECONDS
-                            .with_label_values(&["put_stats_and_indices__get_state_value"])
-                            .start_timer();
-                        let version_and_value = self
-                            .state_db
-                            .get_state_value_with_version_by_version(key, base_version)
+        let mut values = vec![None; versions.len()];
+        for (idx, version) in sorted_versions {
+            if matches!(&current, Some(((_, entry_version), _)) if *entry_version > version) {
+                // Requested versions tend to be close together, so try the next entry before
+                // falling back to a seek.
+                current = iter.next().transpose()?;
+                if matches!(&current, Some(((_, entry_version), _)) if *entry_version > version) {
+                    iter.seek(&(state_key.clone(), version))?;
+                    current = iter.next().transpose()?;
+                }
+            }
+            values[idx] = current.as_ref().and_then(|(_, value)| value.clone());
+        }
+        Ok(values)
+    }
+
+    /// Returns the value of `state_key` effective at `version`, and the value it had before it
+    /// last changed, with the version that value was written at. The previous value is `None` if
+    /// the key didn't exist before. Both are read with a single seek.
+    pub fn get_value_and_previous(
+        &self,
+        state_key: &StateKey,
+        version: Version,
+    ) -> Result<(Option<StateValue>, Option<(Version, StateValue)>)> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["get_value_and_previous"])
+            .start_timer();
+        let mut read_opts = ReadOptions::default();
+        read_opts.set_prefix_same_as_start(true);
+        let mut iter = self
+            .state_kv_db
+            .db_shard(state_key.get_shard_id())
+            .iter::<StateValueSchema>(read_opts)?;
+        iter.seek(&(state_key.clone(), version))?;
+        let current = match iter.next().transpose()? {
+            Some((_, value_opt)) => value_opt,
+            None => return Ok((None, None)),
+        };
+        // Skip the entries rewriting the same value, they are not a change.
+        for res in iter {
+            let ((_, prev_version), prev_value_opt) = res?;
+            if prev_value_opt != current {
+                return Ok((current, prev_value_opt.map(|value| (prev_version, value))));
+            }
+        }
+        Ok((current, None))
+    }
+
+    /// Returns the latest committed value of `state_key` if it was last written at or after
+    /// `min_version`, or `AptosDbError::StaleStateValue` otherwise, so that a caller knowing a
+    /// write landed at `min_version` never reads an older value. A value deleted at or after
+    /// `min_version` reads as `None`.
+    pub fn get_state_value_if_fresh(
+        &self,
+        state_key: &StateKey,
+        min_version: Version,
+    ) -> Result<Option<StateValue>> {
+        let last_write = match self
+            .ledger_db
+            .get::<DbMetadataSchema>(&DbMetadataKey::OverallCommitProgress)?
+        {
+            Some(progress) => {
+                let mut read_opts = ReadOptions::default();
+                read_opts.set_prefix_same_as_start(true);
+                let mut iter = self
+                    .state_kv_db
+                    .db_shard(state_key.get_shard_id())
+                    .iter::<StateValueSchema>(read_opts)?;
+                iter.seek(&(state_key.clone(), progress.expect_version()))?;
+                iter.next().transpose()?
+            },
+            None => None,
+        };
+        match last_write {
+            Some(((_, version), value_opt)) if version >= min_version => Ok(value_opt),
+            last_write => Err(AptosDbError::StaleStateValue(
+                last_write.map(|((_, version), _)| version),
+                min_version,
+            )
+            .into()),
+        }
+    }
+
+    /// Returns the latest value of `state_key` in the state chosen by `consistency`.
+    pub fn get_state_value_consistent(
+        &self,
+        state_key: &StateKey,
+        consistency: ReadConsistency,
+    ) -> Result<Option<StateValue>> {
+        match consistency {
+            ReadConsistency::Committed => {
+                match self
+                    .ledger_db
+                    .get::<DbMetadataSchema>(&DbMetadataKey::OverallCommitProgress)?
+                {
+                    Some(progress) => {
+                        self.get_state_value_by_version(state_key, progress.expect_version())
+                    },
+                    None => Ok(None),
+                }
+            },
+            ReadConsistency::BufferedTip => {
+                let (updated_value_opt, current_version) = {
+                    let buffered_state = self.buffered_state.lock();
+                    let current_state = buffered_state.current_state();
+                    (
+                        current_state.updates_since_base.get(state_key).cloned(),
+                        current_state.current_version,
+                    )
+                };
+                match (updated_value_opt, current_version) {
+                    (Some(value_opt), _) => Ok(value_opt),
+                    (None, Some(version)) => self.get_state_value_by_version(state_key, version),
+                    (None, None) => Ok(None),
+                }
+            },
+        }
+    }
+
+    /// Returns the value of `state_key` as of the wall-clock time `unix_micros`, i.e. at the latest
+    /// committed version whose block started at or before it, together with that version.
+    pub fn get_state_value_by_timestamp(
+        &self,
+        state_key: &StateKey,
+        unix_micros: u64,
+    ) -> Result<(Version, Option<StateValue>)> {
+        let ledger_version = self
+            .ledger_db
+            .get::<DbMetadataSchema>(&DbMetadataKey::OverallCommitProgress)?
+            .ok_or_else(|| AptosDbError::NotFound("OverallCommitProgress".to_string()))?
+            .expect_version();
+        let version = EventStore::new(Arc::clone(&self.ledger_db))
+            .get_last_version_at_or_before_timestamp(unix_micros, ledger_version)?
+            .ok_or_else(|| {
+                AptosDbError::NotFound(format!("Block at or before timestamp {}", unix_micros))
+            })?;
+        Ok((
+            version,
+            self.get_state_value_by_version(state_key, version)?,
+        ))
+    }
+
+    /// Returns the values of `keys` at the latest state snapshot strictly before `version`,
+    /// together with the snapshot version, e.g. to diff a key set between the tip and a snapshot.
+    pub fn get_values_at_previous_snapshot(
+        &self,
+        keys: &[StateKey],
+        version: Version,
+    ) -> Result<(Version, Vec<Option<StateValue>>)> {
+        let (snapshot_version, _) = self.get_state_snapshot_before(version)?.ok_or_else(|| {
+            AptosDbError::NotFound(format!("State snapshot before version {}", version))
+        })?;
+        let values = IO_POOL.install(|| {
+            keys.par_iter()
+                .map(|key| self.get_state_value_by_version(key, snapshot_version))
+                .collect::<Result<Vec<_>>>()
+        })?;
+        Ok((snapshot_version, values))
+    }
+
+    /// Returns the value of `state_key` at `version` even if `version` has been pruned from the
+    /// state DBs, as long as its write sets survive in the ledger. Starting from the latest
+    /// snapshot at or before `version`, e.g. an epoch ending one that is kept longer, the write
+    /// sets after it are scanned backwards for the last write to the key. This reads up to one
+    /// write set per version in between, which can be a whole epoch, so it's expensive. It fails
+    /// if any of those write sets, or the value of the key at the snapshot, has been pruned.
+    pub fn reconstruct_value_at_pruned_version(
+        &self,
+        state_key: &StateKey,
+        version: Version,
+    ) -> Result<Option<StateValue>> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["reconstruct_value_at_pruned_version"])
+            .start_timer();
+        let snapshot_version = self
+            .state_merkle_db
+            .get_state_snapshot_version_before(version + 1)?
+            .ok_or_else(|| {
+                AptosDbError::NotFound(format!("State snapshot at or before version {}", version))
+            })?;
+        let transaction_store = TransactionStore::new(Arc::clone(&self.ledger_db));
+        for write_version in (snapshot_version + 1..=version).rev() {
+            let write_set = transaction_store.get_write_set(write_version)?;
+            if let Some(write_op) = write_set.get(state_key) {
+                return Ok(write_op.as_state_value());
+            }
+        }
+
+        // Not written since the snapshot, so it's the value the snapshot has a leaf for.
+        let (leaf, _) = self
+            .state_merkle_db
+            .get_with_proof_ext(state_key, snapshot_version)?;
+        let value_version = match leaf {
+            Some((_, (_, value_version))) => value_version,
+            None => return Ok(None),
+        };
+        let value = self
+            .state_kv_db
+            .db_shard(state_key.get_shard_id())
+            .get::<StateValueSchema>(&(state_key.clone(), value_version))?
+            .flatten()
+            .ok_or_else(|| {
+                AptosDbError::NotFound(format!(
+                    "Value of {:?} at version {}",
+                    state_key, value_version
+                ))
+            })?;
+        Ok(Some(value))
+    }
+
+    /// Returns the key, value pairs for a particular state key prefix at at desired version. This
+    /// API can be used to get all resources of an account by passing the account address as the
+    /// key prefix. With sharding, the pairs of all shards are merged in the order of their keys in
+    /// the db, so `first_key_opt` can be used to page through them.
+    pub fn get_prefixed_state_value_iterator(
+        &self,
+        key_prefix: &StateKeyPrefix,
+        first_key_opt: Option<&StateKey>,
+        desired_version: Version,
+    ) -> Result<PrefixedStateValueIterator> {
+        let mut shards: Vec<&DB> = (0..NUM_STATE_SHARDS as u8)
+            .map(|shard_id| self.state_kv_db.db_shard(shard_id))
+            .collect();
+        // Without sharding, the shards are the same db, i.e., the metadata db.
+        shards.dedup_by(|a, b| std::ptr::eq(*a, *b));
+        PrefixedStateValueIterator::new(
+            shards,
+            key_prefix.clone(),
+            first_key_opt.cloned(),
+            desired_version,
+        )
+    }
+
+    /// Returns a hash over the ordered key, value pairs under `key_prefix` at `version`. It only
+    /// changes if some value under the prefix changed, so clients can compare it across versions
+    /// to tell whether e.g. an account's resources need to be fetched again.
+    pub fn get_prefix_content_hash(
+        &self,
+        key_prefix: &StateKeyPrefix,
+        version: Version,
+    ) -> Result<HashValue> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["get_prefix_content_hash"])
+            .start_timer();
+        let mut hasher = DefaultHasher::new(b"PrefixContentHash");
+        for res in self.get_prefixed_state_value_iterator(key_prefix, None, version)? {
+            let (key, value) = res?;
+            hasher.update(key.hash().as_ref());
+            hasher.update(value.hash().as_ref());
+        }
+        Ok(hasher.finish())
+    }
+
+    /// Returns all the key, value pairs of the account at `version`. An account without any
+    /// resources gets an empty result.
+    pub fn get_account_state(
+        &self,
+        address: AccountAddress,
+        version: Version,
+    ) -> Result<Vec<(StateKey, StateValue)>> {
+        self.get_prefixed_state_value_iterator(&StateKeyPrefix::from(address), None, version)?
+            .collect()
+    }
+
+    /// Returns the number of keys of the account at `version`, which is cheaper than counting the
+    /// result of `get_account_state` since the values are not collected. An account without any
+    /// resources has a count of 0.
+    pub fn count_account_resources(
+        &self,
+        address: AccountAddress,
+        version: Version,
+    ) -> Result<usize> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["count_account_resources"])
+            .start_timer();
+        self.get_prefixed_state_value_iterator(&StateKeyPrefix::from(address), None, version)?
+            .try_fold(0, |count, res| res.map(|_| count + 1))
+    }
+
+    /// Returns the members of the resource group `group_tag` under `address` at `version`, keyed
+    /// by their tags, or `None` if the group doesn't exist. Fails with
+    /// `AptosDbError::InvalidResourceGroup` if the value can't be decoded as a resource group.
+    pub fn get_resource_group(
+        &self,
+        address: AccountAddress,
+        group_tag: StructTag,
+        version: Version,
+    ) -> Result<Option<BTreeMap<StructTag, Vec<u8>>>> {
+        let state_key = StateKey::access_path(AccessPath::resource_group_access_path(
+            address,
+            group_tag.clone(),
+        ));
+        self.get_state_value_by_version(&state_key, version)?
+            .map(|value| {
+                bcs::from_bytes(value.bytes()).map_err(|err| {
+                    AptosDbError::InvalidResourceGroup(
+                        format!("{} under {}", group_tag, address),
+                        err.to_string(),
+                    )
+                    .into()
+                })
+            })
+            .transpose()
+    }
+
+    /// Loads the values of all keys under the framework address at `version`, which must be the
+    /// latest committed version, and pins them in memory to serve reads at `version` and later.
+    /// The pinned values are updated as new versions modifying them are committed, see
+    /// `update_pinned_values`, which the commits only do with `pin_framework_resources`.
+    pub fn pin_framework_cache(&self, version: Version) -> Result<()> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["pin_framework_cache"])
+            .start_timer();
+        let committed_version = self.get_overall_commit_progress()?;
+        ensure!(
+            committed_version == Some(version),
+            "Can only pin the framework cache at the latest committed version {:?}, got {}.",
+            committed_version,
+            version,
+        );
+        let framework_prefix = StateKeyPrefix::from(CORE_CODE_ADDRESS);
+        let values = self
+            .get_prefixed_state_value_iterator(&framework_prefix, None, version)?
+            .collect::<Result<HashMap<_, _>>>()?;
+        self.pinned_value_cache.pin(version, values)
+    }
+
+    /// Same as `pin_framework_cache` at the latest committed version. Nothing is pinned if
+    /// nothing is committed yet.
+    pub fn pin_framework_cache_at_latest_version(&self) -> Result<()> {
+        match self.get_overall_commit_progress()? {
+            Some(version) => self.pin_framework_cache(version),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the framework resources are pinned on open, in which case the commits have to be
+    /// applied to them with `update_pinned_values`.
+    pub fn pin_framework_resources(&self) -> bool {
+        self.pin_framework_resources
+    }
+
+    /// Applies the writes to framework keys in `value_state_sets`, which start at `first_version`,
+    /// to the values pinned by `pin_framework_cache`. Must only be called once the value sets are
+    /// committed, so that uncommitted values are never served.
+    pub fn update_pinned_values(
+        &self,
+        value_state_sets: &[&HashMap<StateKey, Option<StateValue>>],
+        first_version: Version,
+    ) {
+        self.pinned_value_cache
+            .update(value_state_sets, first_version);
+    }
+
+    fn get_overall_commit_progress(&self) -> Result<Option<Version>> {
+        Ok(self
+            .ledger_db
+            .get::<DbMetadataSchema>(&DbMetadataKey::OverallCommitProgress)?
+            .map(|progress| progress.expect_version()))
+    }
+
+    /// Returns the `n` accounts whose resources take the most bytes at `version`, largest first.
+    /// Values are visited in key order, i.e. grouped by account, so only the running top `n` are
+    /// kept in memory. Table items don't belong to an account and are not counted.
+    pub fn top_accounts_by_storage(
+        &self,
+        version: Version,
+        n: usize,
+    ) -> Result<Vec<(AccountAddress, u64)>> {
+        // Min-heap of the largest accounts seen so far.
+        let mut top_accounts = BinaryHeap::with_capacity(n + 1);
+        let mut push_account = |address: AccountAddress, bytes: u64| {
+            top_accounts.push(Reverse((bytes, address)));
+            if top_accounts.len() > n {
+                top_accounts.pop();
+            }
+        };
+        let mut current_account: Option<(AccountAddress, u64)> = None;
+        let all_access_paths = StateKeyPrefix::new(StateKeyTag::AccessPath, vec![]);
+        for res in self.get_prefixed_state_value_iterator(&all_access_paths, None, version)? {
+            let (key, value) = res?;
+            let address = match key.inner() {
+                StateKeyInner::AccessPath(access_path) => access_path.address,
+                _ => continue,
+            };
+            let bytes = value.size() as u64;
+            if let Some((current_address, total_bytes)) = &mut current_account {
+                if *current_address == address {
+                    *total_bytes += bytes;
+                    continue;
+                }
+                push_account(*current_address, *total_bytes);
+            }
+            current_account = Some((address, bytes));
+        }
+        if let Some((address, total_bytes)) = current_account {
+            push_account(address, total_bytes);
+        }
+        Ok(top_accounts
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((bytes, address))| (address, bytes))
+            .collect())
+    }
+
+    /// Returns the value of `state_key` at `version` with its proof against the state root,
+    /// together with the epoch-ending ledger info that establishes the validator set of the
+    /// epoch the version belongs to.
+    pub fn get_state_value_with_epoch_anchored_proof(
+        &self,
+        state_key: &StateKey,
+        version: Version,
+    ) -> Result<(Option<StateValue>, SparseMerkleProofExt, EpochEndingProof)> {
+        let (value, proof) = self.get_state_value_with_proof_by_version_ext(state_key, version)?;
+        let epoch_ending_proof = match self.get_previous_epoch_ending(version)? {
+            Some((previous_epoch, _)) => {
+                let li = self
+                    .ledger_db
+                    .get::<LedgerInfoSchema>(&previous_epoch)?
+                    .ok_or_else(|| {
+                        AptosDbError::NotFound(format!("LedgerInfo for epoch {}.", previous_epoch))
+                    })?;
+                EpochEndingProof {
+                    epoch: previous_epoch + 1,
+                    previous_epoch_ending_ledger_info: Some(li),
+                }
+            },
+            None => EpochEndingProof {
+                epoch: 0,
+                previous_epoch_ending_ledger_info: None,
+            },
+        };
+        Ok((value, proof, epoch_ending_proof))
+    }
+
+    /// Returns the distinct keys written by the versions in `[first_version, last_version]`,
+    /// e.g. the versions of a block. All the keys are held in memory, which can be a lot for very
+    /// large blocks, in which case `get_block_changed_keys_iter` should be used instead.
+    pub fn get_block_changed_keys(
+        &self,
+        first_version: Version,
+        last_version: Version,
+    ) -> Result<HashSet<StateKey>> {
+        self.get_block_changed_keys_iter(first_version, last_version)?
+            .collect()
+    }
+
+    /// Yields the keys written by the versions in `[first_version, last_version]` as they are
+    /// found. A key written by multiple versions in the range can be yielded more than once. The
+    /// keys are read from the write sets of the versions in the ledger db, so only the range is
+    /// read, and versions whose write sets are missing, e.g. pruned or not committed yet, yield
+    /// no keys.
+    pub fn get_block_changed_keys_iter(
+        &self,
+        first_version: Version,
+        last_version: Version,
+    ) -> Result<impl Iterator<Item = Result<StateKey>> + '_> {
+        ensure!(
+            first_version <= last_version,
+            "Invalid version range [{}, {}].",
+            first_version,
+            last_version,
+        );
+        let mut iter = self
+            .ledger_db
+            .iter::<WriteSetSchema>(ReadOptions::default())?;
+        iter.seek(&first_version)?;
+        Ok(iter
+            .take_while(move |res| {
+                res.as_ref()
+                    .map_or(true, |(version, _)| *version <= last_version)
+            })
+            .flat_map(|res| match res {
+                Ok((_, write_set)) => write_set
+                    .into_iter()
+                    .map(|(state_key, _)| Ok(state_key))
+                    .collect::<Vec<_>>(),
+                Err(err) => vec![Err(err)],
+            }))
+    }
+
+    /// Returns the stale values the pruner deletes once it reaches `up_to_version`, with the key,
+    /// version and size in the db of each, e.g. to prune the largest ones first. Unlike iterating
+    /// the stale indices alone, this reads every stale value, so it's meant for analysis and
+    /// one-off prioritization rather than the pruner itself.
+    pub fn iter_stale_values_with_sizes(
+        &self,
+        up_to_version: Version,
+    ) -> Result<impl Iterator<Item = Result<(StateKey, Version, usize)>> + '_> {
+        let mut shards: Vec<&DB> = (0..NUM_STATE_SHARDS as u8)
+            .map(|shard_id| self.state_kv_db.db_shard(shard_id))
+            .collect();
+        // Without sharding, the shards are the same db.
+        shards.dedup_by(|a, b| std::ptr::eq(*a, *b));
+
+        let shard_iters = shards
+            .into_iter()
+            .map(|shard| {
+                let mut iter = shard.iter::<StaleStateValueIndexSchema>(ReadOptions::default())?;
+                iter.seek_to_first();
+                Ok(iter
+                    .take_while(move |res| {
+                        res.as_ref().map_or(true, |(index, _)| {
+                            index.stale_since_version <= up_to_version
+                        })
+                    })
+                    .map(move |res| {
+                        let (index, _) = res?;
+                        let db_key = (index.state_key, index.version);
+                        let value = shard.get::<StateValueSchema>(&db_key)?.ok_or_else(|| {
+                            AptosDbError::NotFound(format!(
+                                "Stale value of {:?} at version {}",
+                                db_key.0, db_key.1
+                            ))
+                        })?;
+                        let (state_key, version) = db_key;
+                        Ok((state_key, version, bcs::serialized_size(&value)?))
+                    }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(shard_iters.into_iter().flatten())
+    }
+
+    /// Returns the number of stale value indices with `stale_since_version <= up_to_version`, i.e.
+    /// the stale values the pruner deletes once it reaches `up_to_version`.
+    pub fn count_pending_stale_state_values(&self, up_to_version: Version) -> Result<u64> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["count_pending_stale_state_values"])
+            .start_timer();
+        Ok(
+            Self::count_pending_stale_state_values_by_shard(&self.state_kv_db, up_to_version)?
+                .into_iter()
+                .map(|(_, count)| count)
+                .sum(),
+        )
+    }
+
+    /// Same as `count_pending_stale_state_values`, but returns the count of each shard of
+    /// `state_kv_db` along with its id. Without sharding, there's a single count, for shard 0.
+    pub fn count_pending_stale_state_values_by_shard(
+        state_kv_db: &StateKvDb,
+        up_to_version: Version,
+    ) -> Result<Vec<(u8, u64)>> {
+        let mut shards: Vec<(u8, &DB)> = (0..NUM_STATE_SHARDS as u8)
+            .map(|shard_id| (shard_id, state_kv_db.db_shard(shard_id)))
+            .collect();
+        // Without sharding, the shards are the same db.
+        shards.dedup_by(|(_, a), (_, b)| std::ptr::eq(*a, *b));
+
+        IO_POOL.install(|| {
+            shards
+                .par_iter()
+                .map(|(shard_id, shard)| {
+                    let mut iter =
+                        shard.iter::<StaleStateValueIndexSchema>(ReadOptions::default())?;
+                    iter.seek_to_first();
+                    // The indices are ordered by `stale_since_version`, so the iteration stops at
+                    // the first one past `up_to_version`.
+                    let mut count: u64 = 0;
+                    for res in iter {
+                        let (index, _) = res?;
+                        if index.stale_since_version > up_to_version {
+                            break;
+                        }
+                        count += 1;
+                    }
+                    Ok((*shard_id, count))
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+    }
+
+    /// Returns the values written at or before `version` that are neither the value of their key
+    /// in the JMT at `version` nor covered by a stale index, i.e., values the pruner will never
+    /// delete. `version` must be a state snapshot version. This scans the whole state K/V db and
+    /// holds all stale indices in memory, so it's only meant for debugging.
+    pub fn find_orphaned_state_values(&self, version: Version) -> Result<Vec<(StateKey, Version)>> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["find_orphaned_state_values"])
+            .start_timer();
+        let mut shards: Vec<&DB> = (0..NUM_STATE_SHARDS as u8)
+            .map(|shard_id| self.state_kv_db.db_shard(shard_id))
+            .collect();
+        // Without sharding, the shards are the same db.
+        shards.dedup_by(|a, b| std::ptr::eq(*a, *b));
+
+        let mut orphaned_values = vec![];
+        for shard in shards {
+            let mut stale_index_iter =
+                shard.iter::<StaleStateValueIndexSchema>(ReadOptions::default())?;
+            stale_index_iter.seek_to_first();
+            let stale_values = stale_index_iter
+                .map(|res| res.map(|(index, _)| (index.state_key, index.version)))
+                .collect::<Result<HashSet<_>>>()?;
+
+            let mut value_iter = shard.iter::<StateValueSchema>(ReadOptions::default())?;
+            value_iter.seek_to_first();
+            // The key seen last and the version of its leaf in the JMT, if any.
+            let mut last_leaf: Option<(StateKey, Option<Version>)> = None;
+            for res in value_iter {
+                let ((state_key, value_version), _) = res?;
+                if value_version > version
+                    || stale_values.contains(&(state_key.clone(), value_version))
+                {
+                    continue;
+                }
+                if last_leaf
+                    .as_ref()
+                    .map_or(true, |(key, _)| *key != state_key)
+                {
+                    let (leaf, _) = self
+                        .state_merkle_db
+                        .get_with_proof_ext(&state_key, version)?;
+                    let leaf_version = leaf.map(|(_, (_, leaf_version))| leaf_version);
+                    last_leaf = Some((state_key.clone(), leaf_version));
+                }
+                let leaf_version = last_leaf
+                    .as_ref()
+                    .and_then(|(_, leaf_version)| *leaf_version);
+                if leaf_version != Some(value_version) {
+                    orphaned_values.push((state_key, value_version));
+                }
+            }
+        }
+        Ok(orphaned_values)
+    }
+
+    /// Checks that the value of every leaf at `version` is in the state K/V shard its key is routed
+    /// to, returning `(key, expected_shard, found_shard)` for the values found in another shard,
+    /// where reads can't find them. Fails if a value is in no shard at all.
+    pub fn verify_shard_routing(&self, version: Version) -> Result<Vec<(StateKey, u8, u8)>> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["verify_shard_routing"])
+            .start_timer();
+        let mut misrouted = vec![];
+        let iter = JellyfishMerkleIterator::new(
+            Arc::clone(&self.state_merkle_db),
+            version,
+            HashValue::zero(),
+        )?;
+        for res in iter {
+            let (_, (state_key, value_version)) = res?;
+            let db_key = (state_key.clone(), value_version);
+            let expected_shard = state_key.get_shard_id();
+            if self
+                .state_kv_db
+                .db_shard(expected_shard)
+                .get::<StateValueSchema>(&db_key)?
+                .is_some()
+            {
+                continue;
+            }
+            let mut found_shard = None;
+            for shard_id in 0..NUM_STATE_SHARDS as u8 {
+                if shard_id != expected_shard
+                    && self
+                        .state_kv_db
+                        .db_shard(shard_id)
+                        .get::<StateValueSchema>(&db_key)?
+                        .is_some()
+                {
+                    found_shard = Some(shard_id);
+                    break;
+                }
+            }
+            let found_shard = found_shard.ok_or_else(|| {
+                AptosDbError::NotFound(format!(
+                    "Value of {:?} at version {} in any shard",
+                    state_key, value_version
+                ))
+            })?;
+            misrouted.push((state_key, expected_shard, found_shard));
+        }
+        Ok(misrouted)
+    }
+
+    /// Returns the value of `state_key` at `version` along with the id of the state K/V shard it
+    /// was read from, to confirm how reads are routed, e.g. while debugging sharding.
+    pub fn get_state_value_with_shard(
+        &self,
+        state_key: &StateKey,
+        version: Version,
+    ) -> Result<(u8, Option<StateValue>)> {
+        let shard_id = state_key.get_shard_id();
+        let value = self
+            .get_state_value_with_version_in_shard(shard_id, state_key, version)?
+            .map(|(_, value)| value);
+        Ok((shard_id, value))
+    }
+
+    /// Returns the proofs that none of `keys` exist at `version`, in the same order as `keys`.
+    /// Fails if any of them exists. The part of the tree shared by the keys' paths is read once.
+    pub fn get_batch_absence_proof(
+        &self,
+        keys: &[StateKey],
+        version: Version,
+    ) -> Result<Vec<SparseMerkleProofExt>> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["get_batch_absence_proof"])
+            .start_timer();
+        self.state_merkle_db
+            .batch_get_with_proof_ext(keys, version)
+            .map_err(|err| self.state_merkle_error(err))?
+            .into_iter()
+            .zip(keys)
+            .map(|((leaf, proof), key)| {
+                ensure!(
+                    leaf.is_none(),
+                    "{:?} exists at version {}, no absence proof.",
+                    key,
+                    version
+                );
+                Ok(proof)
+            })
+            .collect()
+    }
+
+    /// Returns the values of `changed_keys` at `to_version` with the sibling hashes a client
+    /// trusting the root hash at `from_version` needs to compute the one at `to_version`, so that
+    /// it doesn't need the full state on each sync step. Fails unless `changed_keys` covers all
+    /// the keys changed in between.
+    pub fn get_incremental_state_proof(
+        &self,
+        from_version: Version,
+        to_version: Version,
+        changed_keys: &[StateKey],
+    ) -> Result<IncrementalStateProof> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["get_incremental_state_proof"])
+            .start_timer();
+        ensure!(
+            from_version <= to_version,
+            "from_version {} is after to_version {}.",
+            from_version,
+            to_version
+        );
+        let updates = changed_keys
+            .iter()
+            .map(|key| {
+                let (_, proof) = self
+                    .state_merkle_db
+                    .get_with_proof_ext(key, from_version)
+                    .map_err(|err| self.state_merkle_error(err))?;
+                let value = self.get_state_value_by_version(key, to_version)?;
+                Ok((key.clone(), value, proof))
+            })
+            .collect::<Result<_>>()?;
+        let proof = IncrementalStateProof {
+            from_version,
+            to_version,
+            updates,
+        };
+        proof.verify(
+            self.state_merkle_db.get_root_hash(from_version)?,
+            self.state_merkle_db.get_root_hash(to_version)?,
+        )?;
+        Ok(proof)
+    }
+
+    /// Gets the proof that proves a range of accounts.
+    pub fn get_value_range_proof(
+        &self,
+        rightmost_key: HashValue,
+        version: Version,
+    ) -> Result<SparseMerkleRangeProof> {
+        self.state_merkle_db
+            .get_range_proof(rightmost_key, version)
+            .map_err(|err| self.state_merkle_error(err))
+    }
+
+    /// Returns the range proof for the leaves `[first_index, last_index]` of the tree at
+    /// `version`, for clients that address the state by leaf index.
+    pub fn get_value_range_proof_by_index(
+        &self,
+        version: Version,
+        first_index: usize,
+        last_index: usize,
+    ) -> Result<SparseMerkleRangeProof> {
+        let leaf_count = self.get_value_count(version)?;
+        ensure!(
+            first_index <= last_index && last_index < leaf_count,
+            "Invalid leaf index range [{}, {}], leaf count at version {} is {}.",
+            first_index,
+            last_index,
+            version,
+            leaf_count,
+        );
+        // A range proof only depends on the rightmost leaf of the range, the leaves to the left of
+        // it are covered by the chunk itself.
+        let mut iter = JellyfishMerkleIterator::new_by_index(
+            Arc::clone(&self.state_merkle_db),
+            version,
+            last_index,
+        )?;
+        let (rightmost_key, _) = iter.next().ok_or_else(|| {
+            AptosDbError::NotFound(format!("Leaf {} at version {}", last_index, version))
+        })??;
+        self.get_value_range_proof(rightmost_key, version)
+    }
+
+    /// Put the `value_state_sets` into its own CF.
+    pub fn put_value_sets(
+        &self,
+        value_state_sets: Vec<&HashMap<StateKey, Option<StateValue>>>,
+        first_version: Version,
+        expected_usage: StateStorageUsage,
+        ledger_batch: &SchemaBatch,
+        sharded_state_kv_batches: &ShardedStateKvSchemaBatch,
+    ) -> Result<()> {
+        let block_ends = [(value_state_sets.len(), expected_usage)];
+        self.put_block_value_sets(
+            value_state_sets,
+            first_version,
+            &block_ends,
+            true, /* verify_usage */
+            ledger_batch,
+            sharded_state_kv_batches,
+        )
+    }
+
+    /// Same as `put_value_sets`, but for callers that already trust `usage`, the usage at the end
+    /// of `value_state_sets`, e.g., a bulk restore recomputing the usage anyway. The old values
+    /// of the keys are not read from the DB at the base version and `usage` is not checked
+    /// against the calculated one, but put as is, with the `STATE_ITEMS` and `TOTAL_STATE_BYTES`
+    /// metrics set from it.
+    ///
+    /// Without the old values in the DB, the stale indices and the usage at the versions before
+    /// the last one are calculated from `value_state_sets` alone, on top of the usage at the base
+    /// version. They are only correct if none of the keys has a value at the base version, e.g.,
+    /// on top of an empty state; otherwise the values at the base version are never marked stale.
+    pub fn put_value_sets_unchecked(
+        &self,
+        value_state_sets: Vec<&HashMap<StateKey, Option<StateValue>>>,
+        first_version: Version,
+        usage: StateStorageUsage,
+        ledger_batch: &SchemaBatch,
+        sharded_state_kv_batches: &ShardedStateKvSchemaBatch,
+    ) -> Result<()> {
+        let block_ends = [(value_state_sets.len(), usage)];
+        self.put_block_value_sets(
+            value_state_sets,
+            first_version,
+            &block_ends,
+            false, /* verify_usage */
+            ledger_batch,
+            sharded_state_kv_batches,
+        )
+    }
+
+    /// Same as `put_value_sets`, but for several consecutive blocks, e.g., a run of blocks
+    /// applied during catch-up, so that they are all put into the same batches to be committed
+    /// at once. Each block is given by its first version, its value sets and the usage expected
+    /// at its end, which is checked for each block.
+    pub fn put_value_sets_multi(
+        &self,
+        blocks: &[(
+            Version,
+            Vec<&HashMap<StateKey, Option<StateValue>>>,
+            StateStorageUsage,
+        )],
+        ledger_batch: &SchemaBatch,
+        sharded_state_kv_batches: &ShardedStateKvSchemaBatch,
+    ) -> Result<()> {
+        let first_version = match blocks.first() {
+            Some((first_version, _, _)) => *first_version,
+            None => return Ok(()),
+        };
+        let mut value_state_sets = Vec::new();
+        let mut block_ends = Vec::with_capacity(blocks.len());
+        for (version, block_value_state_sets, expected_usage) in blocks {
+            let expected_version = first_version + value_state_sets.len() as Version;
+            ensure!(
+                *version == expected_version,
+                "Block at version {} doesn't follow the previous one, expected version {}.",
+                version,
+                expected_version,
+            );
+            value_state_sets.extend(block_value_state_sets.iter().copied());
+            block_ends.push((value_state_sets.len(), *expected_usage));
+        }
+        self.put_block_value_sets(
+            value_state_sets,
+            first_version,
+            &block_ends,
+            true, /* verify_usage */
+            ledger_batch,
+            sharded_state_kv_batches,
+        )
+    }
+
+    /// Puts the value sets of consecutive blocks, which end at the indices in `block_ends`, with
+    /// the usage expected at the end of each block. The expected usages are checked if
+    /// `verify_usage`, otherwise they are trusted as in `put_value_sets_unchecked`.
+    fn put_block_value_sets(
+        &self,
+        value_state_sets: Vec<&HashMap<StateKey, Option<StateValue>>>,
+        first_version: Version,
+        block_ends: &[(usize, StateStorageUsage)],
+        verify_usage: bool,
+        ledger_batch: &SchemaBatch,
+        sharded_state_kv_batches: &ShardedStateKvSchemaBatch,
+    ) -> Result<()> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["put_value_sets"])
+            .start_timer();
+        self.disk_space_guard.check()?;
+
+        if verify_usage {
+            self.put_stats_and_indices(
+                &value_state_sets,
+                first_version,
+                block_ends,
+                ledger_batch,
+                sharded_state_kv_batches,
+            )?;
+        } else {
+            self.put_stats_and_indices_unchecked(
+                &value_state_sets,
+                first_version,
+                block_ends,
+                ledger_batch,
+                sharded_state_kv_batches,
+            )?;
+        }
+
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["add_state_kv_batch"])
+            .start_timer();
+
+        if self.enable_shard_metrics {
+            let mut rows = [0; NUM_STATE_SHARDS];
+            let mut bytes = [0; NUM_STATE_SHARDS];
+            for (key, value) in value_state_sets.iter().flat_map(|kvs| kvs.iter()) {
+                let shard_id = self.shard_id_for_key(key) as usize;
+                rows[shard_id] += 1;
+                bytes[shard_id] += key.size() + value.as_ref().map_or(0, |value| value.size());
+            }
+            for (shard_id, (rows, bytes)) in rows.iter().zip(bytes).enumerate() {
+                let shard_label = shard_id.to_string();
+                STATE_KV_SHARD_ROWS
+                    .with_label_values(&[&shard_label])
+                    .inc_by(*rows);
+                STATE_KV_SHARD_BYTES
+                    .with_label_values(&[&shard_label])
+                    .inc_by(bytes as u64);
+            }
+        }
+
+        value_state_sets
+            .par_iter()
+            .enumerate()
+            .flat_map_iter(|(i, kvs)| {
+                let version = first_version + i as Version;
+                kvs.iter().map(move |(k, v)| {
+                    sharded_state_kv_batches[self.shard_id_for_key(k) as usize]
+                        .put::<StateValueSchema>(&(k.clone(), version), v)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the id of the state K/V shard the store routes `key` to. It forwards to
+    /// `StateKey::get_shard_id`, the first nibble of the key hash, which is always below
+    /// `NUM_STATE_SHARDS`.
+    pub fn shard_id_for_key(&self, key: &StateKey) -> u8 {
+        key.get_shard_id()
+    }
+
+    pub fn get_usage(&self, version: Option<Version>) -> Result<StateStorageUsage> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["get_usage"])
+            .start_timer();
+        self.state_db.get_state_storage_usage(version)
+    }
+
+    /// Returns the change in usage from version `from`, `None` meaning before genesis where the
+    /// usage is zero, to version `to`.
+    pub fn get_usage_delta(
+        &self,
+        from: Option<Version>,
+        to: Version,
+    ) -> Result<StateStorageUsageDelta> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["get_usage_delta"])
+            .start_timer();
+        let from_usage = self.state_db.get_state_storage_usage(from)?;
+        let to_usage = self.state_db.get_state_storage_usage(Some(to))?;
+        Ok(StateStorageUsageDelta {
+            items_delta: to_usage.items() as i64 - from_usage.items() as i64,
+            bytes_delta: to_usage.bytes() as i64 - from_usage.bytes() as i64,
+        })
+    }
+
+    /// Returns the usage at the latest version in the buffered state, taken under the same lock
+    /// as the version so the two are consistent. Falls back to the latest `VersionDataSchema` row
+    /// if the buffered state doesn't track usage.
+    pub fn get_latest_usage(&self) -> Result<StateStorageUsage> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["get_latest_usage"])
+            .start_timer();
+        let usage = self.buffered_state.lock().current_state().current.usage();
+        if !usage.is_untracked() {
+            return Ok(usage);
+        }
+
+        let mut iter = self
+            .ledger_db
+            .iter::<VersionDataSchema>(ReadOptions::default())?;
+        iter.seek_to_last();
+        Ok(iter
+            .next()
+            .transpose()?
+            .map_or(StateStorageUsage::zero(), |(_, version_data)| {
+                version_data.get_state_storage_usage()
+            }))
+    }
+
+    /// Put storage usage stats and State key and value indices into the batch.
+    /// The state KV indices will be generated as follows:
+    /// 1. A deletion at current version is always coupled with stale index for the tombstone with
+    /// `stale_since_version` equal to the version, to ensure tombstone is cleared from db after
+    /// pruner processes the current version.
+    /// 2. An update at current version will first try to find the corresponding old value, if it
+    /// exists, a stale index of that old value will be added. Otherwise, it's a no-op. Because
+    /// non-existence means either the key never shows up or it got deleted. Neither case needs
+    /// extra stale index as 1 cover the latter case.
+    ///
+    /// If the write sets touch more than `max_num_keys_in_state_stats_cache` distinct keys, they
+    /// are processed in sub-batches of consecutive versions to bound the old value cache.
+    ///
+    /// The write sets may span several blocks, each ending right before an index in `block_ends`,
+    /// along with the usage expected at its end, which is checked unless untracked.
+    pub fn put_stats_and_indices(
+        &self,
+        value_state_sets: &[&HashMap<StateKey, Option<StateValue>>],
+        first_version: Version,
+        block_ends: &[(usize, StateStorageUsage)],
+        batch: &SchemaBatch,
+        sharded_state_kv_batches: &ShardedStateKvSchemaBatch,
+    ) -> Result<()> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["put_stats_and_indices"])
+            .start_timer();
+
+        let mut base_version = first_version.checked_sub(1);
+        let mut base_version_usage = self.get_usage(base_version)?;
+        let first_base_version_usage = base_version_usage;
+        // The usage at each version.
+        let mut usages = Vec::with_capacity(value_state_sets.len());
+
+        for range in self.split_by_stats_cache_capacity(value_state_sets) {
+            let usage = usages.last().copied().unwrap_or(base_version_usage);
+            usages.extend(self.put_stats_and_indices_for_range(
+                value_state_sets,
+                range,
+                first_version,
+                base_version,
+                usage,
+                sharded_state_kv_batches,
+            )?);
+        }
+        self.put_usages(first_version, &usages, batch)?;
+
+        for (block_end, expected_usage) in block_ends {
+            let usage = block_end
+                .checked_sub(1)
+                .map_or(base_version_usage, |idx| usages[idx]);
+            let next_version = first_version + *block_end as Version;
+            self.check_usage(
+                next_version,
+                *expected_usage,
+                usage,
+                base_version,
+                base_version_usage,
+            )?;
+            base_version = next_version.checked_sub(1);
+            base_version_usage = usage;
+        }
+
+        self.check_usage_alert(
+            first_base_version_usage,
+            usages.last().copied().unwrap_or(first_base_version_usage),
+        );
+        Ok(())
+    }
+
+    /// Same as `put_stats_and_indices`, but the usages in `block_ends` are trusted and put as is
+    /// instead of checked, and no old values are read from the DB at the base version, see
+    /// `put_value_sets_unchecked`. The usage alert is not checked.
+    fn put_stats_and_indices_unchecked(
+        &self,
+        value_state_sets: &[&HashMap<StateKey, Option<StateValue>>],
+        first_version: Version,
+        block_ends: &[(usize, StateStorageUsage)],
+        batch: &SchemaBatch,
+        sharded_state_kv_batches: &ShardedStateKvSchemaBatch,
+    ) -> Result<()> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["put_stats_and_indices_unchecked"])
+            .start_timer();
+
+        let base_version_usage = self.get_usage(first_version.checked_sub(1))?;
+        let mut usages = Vec::with_capacity(value_state_sets.len());
+        for range in self.split_by_stats_cache_capacity(value_state_sets) {
+            let usage = usages.last().copied().unwrap_or(base_version_usage);
+            usages.extend(self.put_stats_and_indices_for_range(
+                value_state_sets,
+                range,
+                first_version,
+                None, /* base_version, the old values in the DB are not read */
+                usage,
+                sharded_state_kv_batches,
+            )?);
+        }
+        for (block_end, usage) in block_ends {
+            if let Some(idx) = block_end.checked_sub(1) {
+                usages[idx] = *usage;
+            }
+        }
+        self.put_usages(first_version, &usages, batch)
+    }
+
+    /// Puts the usage at each version from `first_version` on, and sets the `STATE_ITEMS` and
+    /// `TOTAL_STATE_BYTES` metrics to the last one.
+    fn put_usages(
+        &self,
+        first_version: Version,
+        usages: &[StateStorageUsage],
+        batch: &SchemaBatch,
+    ) -> Result<()> {
+        for (idx, usage) in usages.iter().enumerate() {
+            batch.put::<VersionDataSchema>(&(first_version + idx as Version), &(*usage).into())?;
+        }
+        if let Some(usage) = usages.last() {
+            STATE_ITEMS.set(usage.items() as i64);
+            TOTAL_STATE_BYTES.set(usage.bytes() as i64);
+        }
+        Ok(())
+    }
+
+    /// Sets an alert on the state storage usage, replacing any previous one. `callback` is called
+    /// with the usage after write sets are put whenever they take the usage from below
+    /// `bytes_threshold` to at least `bytes_threshold`. It isn't called again while the usage
+    /// stays over the threshold, only if it drops below and crosses it again.
+    pub fn set_usage_alert(&self, bytes_threshold: u64, callback: UsageAlertCallback) {
+        *self.usage_alert.lock() = Some(UsageAlert {
+            bytes_threshold,
+            callback,
+        });
+    }
+
+    /// Clears the usage alert set by `set_usage_alert`, if any.
+    pub fn clear_usage_alert(&self) {
+        *self.usage_alert.lock() = None;
+    }
+
+    /// Calls the usage alert callback if the usage crossed the threshold upward, going from
+    /// `prev_usage` to `usage`.
+    fn check_usage_alert(&self, prev_usage: StateStorageUsage, usage: StateStorageUsage) {
+        if prev_usage.is_untracked() || usage.is_untracked() {
+            return;
+        }
+        // The callback is called without holding the lock, so it can set or clear the alert.
+        let callback = match &*self.usage_alert.lock() {
+            Some(alert)
+                if (prev_usage.bytes() as u64) < alert.bytes_threshold
+                    && usage.bytes() as u64 >= alert.bytes_threshold =>
+            {
+                Arc::clone(&alert.callback)
+            },
+            _ => return,
+        };
+        callback(usage);
+    }
+
+    /// Checks the usage calculated at the end of a block, right before `next_version`, against the
+    /// expected one, handling a mismatch according to the usage mismatch policy.
+    fn check_usage(
+        &self,
+        next_version: Version,
+        expected_usage: StateStorageUsage,
+        usage: StateStorageUsage,
+        base_version: Option<Version>,
+        base_version_usage: StateStorageUsage,
+    ) -> Result<()> {
+        if !expected_usage.is_untracked() && expected_usage != usage {
+            let version = next_version - 1;
+            match self.usage_mismatch_policy {
+                UsageMismatchPolicy::Abort => bail!(
+                    "Calculated state db usage at version {} not expected. expected: {:?}, calculated: {:?}, base version: {:?}, base version usage: {:?}",
+                    version,
+                    expected_usage,
+                    usage,
+                    base_version,
+                    base_version_usage,
+                ),
+                // The calculated usage has been put into the batch already.
+                UsageMismatchPolicy::LogAndUseComputed => error!(
+                    version = version,
+                    expected_usage = ?expected_usage,
+                    calculated_usage = ?usage,
+                    base_version = ?base_version,
+                    base_version_usage = ?base_version_usage,
+                    "Calculated state db usage not expected, using the calculated one."
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Splits the write sets into ranges of consecutive versions, each touching no more than
+    /// `max_num_keys_in_state_stats_cache` distinct keys, unless a single write set alone
+    /// exceeds it.
+    fn split_by_stats_cache_capacity(
+        &self,
+        value_state_sets: &[&HashMap<StateKey, Option<StateValue>>],
+    ) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        let mut range_start = 0;
+        let mut keys_in_range = HashSet::new();
+        for (idx, kvs) in value_state_sets.iter().enumerate() {
+            let num_new_keys = kvs
+                .keys()
+                .filter(|key| !keys_in_range.contains(key))
+                .count();
+            if idx > range_start
+                && keys_in_range.len() + num_new_keys > self.max_num_keys_in_state_stats_cache
+            {
+                ranges.push(range_start..idx);
+                range_start = idx;
+                keys_in_range.clear();
+            }
+            keys_in_range.extend(kvs.keys());
+        }
+        if range_start < value_state_sets.len() {
+            ranges.push(range_start..value_state_sets.len());
+        }
+        ranges
+    }
+
+    /// Puts the indices for `value_state_sets[range]` and returns the usage at each version of
+    /// the range, calculated on top of `usage`, which is the usage right before the range. Old
+    /// values written before `first_version` are read from the DB at `base_version`, if any.
+    fn put_stats_and_indices_for_range(
+        &self,
+        value_state_sets: &[&HashMap<StateKey, Option<StateValue>>],
+        range: Range<usize>,
+        first_version: Version,
+        base_version: Option<Version>,
+        mut usage: StateStorageUsage,
+        sharded_state_kv_batches: &ShardedStateKvSchemaBatch,
+    ) -> Result<Vec<StateStorageUsage>> {
+        let cache = Arc::new(DashMap::<StateKey, (Version, Option<StateValue>)>::new());
+        // Write sets before the range are not in the DB yet, so old values written by them are
+        // looked up in memory.
+        let preceding_sets = &value_state_sets[..range.start];
+
+        if base_version.is_some() || !preceding_sets.is_empty() {
+            let _timer = OTHER_TIMERS_SECONDS
+                .with_label_values(&["put_stats_and_indices__total_get"])
+                .start_timer();
+            let key_set = value_state_sets[range.clone()]
+                .iter()
+                .flat_map(|value_state_set| value_state_set.iter())
+                .map(|(key, _)| key)
+                .collect::<HashSet<_>>();
+            IO_POOL.scope(|s| {
+                for key in key_set {
+                    let cache = cache.clone();
+                    s.spawn(move |_| {
+                        if let Some((idx, value)) = preceding_sets
+                            .iter()
+                            .enumerate()
+                            .rev()
+                            .find_map(|(idx, kvs)| kvs.get(key).map(|value| (idx, value)))
+                        {
+                            cache.insert(
+                                key.clone(),
+                                (first_version + idx as Version, value.clone()),
+                            );
+                            return;
+                        }
+                        let base_version = match base_version {
+                            Some(base_version) => base_version,
+                            None => return,
+                        };
+                        let _timer = OTHER_TIMERS_SECONDS
+                            .with_label_values(&["put_stats_and_indices__get_state_value"])
+                            .start_timer();
+                        let version_and_value = self
+                            .state_db
+                            .get_state_value_with_version_by_version(key, base_version)
                             .expect("Must succeed.");
                         if let Some((version, value)) = version_and_value {
                             cache.insert(key.clone(), (version, Some(value)));
@@ -674,24 +2576,24 @@ impl StateStore {
         let _timer = OTHER_TIMERS_SECONDS
             .with_label_values(&["put_stats_and_indices__calculate_total_size"])
             .start_timer();
+        // Stale indices are collected per shard in order, and put into the shard batches in
+        // parallel once the usage is calculated.
+        let mut stale_indices: [Vec<StaleStateValueIndex>; NUM_STATE_SHARDS] = Default::default();
+        let mut usages = Vec::with_capacity(range.len());
         // calculate total state size in bytes
-        for (idx, kvs) in value_state_sets.iter().enumerate() {
-            let version = first_version + idx as Version;
+        for (idx, kvs) in value_state_sets[range.clone()].iter().enumerate() {
+            let version = first_version + (range.start + idx) as Version;
 
             for (key, value) in kvs.iter() {
                 if let Some(value) = value {
                     usage.add_item(key.size() + value.size());
                 } else {
                     // stale index of the tombstone at current version.
-                    sharded_state_kv_batches[key.get_shard_id() as usize]
-                        .put::<StaleStateValueIndexSchema>(
-                        &StaleStateValueIndex {
-                            stale_since_version: version,
-                            version,
-                            state_key: key.clone(),
-                        },
-                        &(),
-                    )?;
+                    stale_indices[self.shard_id_for_key(key) as usize].push(StaleStateValueIndex {
+                        stale_since_version: version,
+                        version,
+                        state_key: key.clone(),
+                    });
                 }
 
                 let old_version_and_value_opt = if let Some((old_version, old_value_opt)) =
@@ -705,36 +2607,36 @@ impl StateStore {
                 if let Some((old_version, old_value)) = old_version_and_value_opt {
                     usage.remove_item(key.size() + old_value.size());
                     // stale index of the old value at its version.
-                    sharded_state_kv_batches[key.get_shard_id() as usize]
-                        .put::<StaleStateValueIndexSchema>(
-                        &StaleStateValueIndex {
-                            stale_since_version: version,
-                            version: old_version,
-                            state_key: key.clone(),
-                        },
-                        &(),
-                    )?;
+                    stale_indices[self.shard_id_for_key(key) as usize].push(StaleStateValueIndex {
+                        stale_since_version: version,
+                        version: old_version,
+                        state_key: key.clone(),
+                    });
                 }
             }
 
-            STATE_ITEMS.set(usage.items() as i64);
-            TOTAL_STATE_BYTES.set(usage.bytes() as i64);
-            batch.put::<VersionDataSchema>(&version, &usage.into())?;
+            usages.push(usage);
         }
 
-        if !expected_usage.is_untracked() {
-            ensure!(
-                expected_usage == usage,
-                "Calculated state db usage at version {} not expected. expected: {:?}, calculated: {:?}, base version: {:?}, base version usage: {:?}",
-                first_version + value_state_sets.len() as u64 - 1,
-                expected_usage,
-                usage,
-                base_version,
-                base_version_usage,
-            );
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["put_stats_and_indices__put_stale_indices"])
+            .start_timer();
+        (0..NUM_STATE_SHARDS)
+            .into_par_iter()
+            .try_for_each(|shard_id| {
+                stale_indices[shard_id].iter().try_for_each(|index| {
+                    sharded_state_kv_batches[shard_id].put::<StaleStateValueIndexSchema>(index, &())
+                })
+            })?;
+        if self.enable_shard_metrics {
+            for (shard_id, indices) in stale_indices.iter().enumerate() {
+                STATE_KV_SHARD_ROWS
+                    .with_label_values(&[&shard_id.to_string()])
+                    .inc_by(indices.len() as u64);
+            }
         }
 
-        Ok(())
+        Ok(usages)
     }
 
     /// Merklize the results generated by `value_state_sets` to `batch` and return the result root
@@ -759,14 +2661,255 @@ impl StateStore {
         Ok(hash)
     }
 
+    /// Same as `merklize_value_set`, but hashes the leaves of `value_set` with `leaf_hasher`
+    /// instead of the default crypto hashes. The node hashes of the in-memory tree are computed
+    /// with the default hashes, so they are never reused here.
+    pub fn merklize_value_set_with_hasher(
+        &self,
+        value_set: &HashMap<StateKey, Option<StateValue>>,
+        leaf_hasher: &dyn LeafHasher,
+        version: Version,
+        base_version: Option<Version>,
+    ) -> Result<HashValue> {
+        let jmt_updates = jmt_updates_with_hasher(value_set, leaf_hasher);
+        self.merklize_value_set(jmt_update_refs(&jmt_updates), None, version, base_version)
+    }
+
     pub fn get_root_hash(&self, version: Version) -> Result<HashValue> {
         self.state_merkle_db.get_root_hash(version)
     }
 
+    /// Returns the root hashes of the snapshots at the given versions, in the same order. A
+    /// version that is not a state snapshot yields `None`. The root node reads are issued in
+    /// parallel.
+    pub fn get_snapshot_root_hashes(&self, versions: &[Version]) -> Result<Vec<Option<HashValue>>> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["get_snapshot_root_hashes"])
+            .start_timer();
+        IO_POOL.install(|| {
+            versions
+                .par_iter()
+                .map(|version| self.state_merkle_db.get_root_hash_option(*version))
+                .collect()
+        })
+    }
+
+    /// Returns the latest version in `search_range` at which the state root hash is `root_hash`,
+    /// or `None` if there is none. Only the root hashes of state snapshots are persisted, so only
+    /// snapshot versions are checked, and a version that is not a snapshot is never returned even
+    /// if its state has that root hash. The caller should constrain `search_range` to where a
+    /// snapshot is expected accordingly.
+    pub fn find_version_for_root_hash(
+        &self,
+        root_hash: HashValue,
+        search_range: Range<Version>,
+    ) -> Result<Option<Version>> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["find_version_for_root_hash"])
+            .start_timer();
+        let mut next_version = search_range.end;
+        while let Some(version) = self
+            .state_merkle_db
+            .get_state_snapshot_version_before(next_version)?
+        {
+            if version < search_range.start {
+                break;
+            }
+            if self.get_root_hash(version)? == root_hash {
+                return Ok(Some(version));
+            }
+            next_version = version;
+        }
+        Ok(None)
+    }
+
+    /// Returns the root hash of the subtree under `nibble_prefix` in the state tree at `version`,
+    /// so that parts of the tree can be verified independently and combined.
+    pub fn get_subtree_hash(
+        &self,
+        nibble_prefix: NibblePath,
+        version: Version,
+    ) -> Result<HashValue> {
+        self.state_merkle_db
+            .get_subtree_hash(&nibble_prefix, version)
+    }
+
+    /// Returns the nibble path of the smallest subtree in which the state trees at `version_a`
+    /// and `version_b` differ, or `None` if they have the same root hash. Useful to localize a
+    /// root hash divergence.
+    pub fn first_differing_subtree(
+        &self,
+        version_a: Version,
+        version_b: Version,
+    ) -> Result<Option<NibblePath>> {
+        self.state_merkle_db
+            .first_differing_subtree(version_a, version_b)
+    }
+
+    /// Returns the nodes of the state tree at `version` down to `max_depth` nibbles below the
+    /// root, e.g. to inspect how the tree is balanced. A depth of 0 only returns the root.
+    pub fn export_jmt_structure(
+        &self,
+        version: Version,
+        max_depth: usize,
+    ) -> Result<JmtStructureDump> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["export_jmt_structure"])
+            .start_timer();
+        let nodes = self
+            .state_merkle_db
+            .get_nodes_up_to_depth(version, max_depth)?
+            .into_iter()
+            .map(|(node_key, node)| {
+                let (node_type, num_children) = match &node {
+                    Node::Internal(internal_node) => (
+                        JmtNodeKind::Internal,
+                        internal_node.children_sorted().count(),
+                    ),
+                    Node::Leaf(_) => (JmtNodeKind::Leaf, 0),
+                    Node::Null => (JmtNodeKind::Null, 0),
+                };
+                JmtNodeSummary {
+                    nibble_path: node_key.nibble_path().clone(),
+                    node_type,
+                    num_children,
+                    hash: node.hash(),
+                }
+            })
+            .collect();
+        Ok(JmtStructureDump {
+            version,
+            max_depth,
+            nodes,
+        })
+    }
+
+    /// Returns the key and value of the leaf with the given hash in the tree at `version`, or
+    /// `None` if the tree has no such leaf.
+    ///
+    /// This is O(state size): it reads every internal node of the tree at `version`, see
+    /// `StateMerkleDb::get_leaf_by_hash`. Only meant for debugging, never for a request path.
+    pub fn get_value_by_leaf_hash(
+        &self,
+        leaf_hash: HashValue,
+        version: Version,
+    ) -> Result<Option<(StateKey, StateValue)>> {
+        self.state_merkle_db
+            .get_leaf_by_hash(leaf_hash, version)?
+            .map(|leaf_node| {
+                let (key, version) = leaf_node.value_index();
+                Ok((key.clone(), self.expect_value_by_version(key, *version)?))
+            })
+            .transpose()
+    }
+
+    /// Returns whether the leaf of `state_key` in the state tree at `version` has the hash of
+    /// `value`, or `false` if the key doesn't exist at `version`. Useful to validate a value
+    /// obtained out of band, e.g., from a peer's cache, against this node's tree.
+    pub fn verify_value_against_merkle(
+        &self,
+        state_key: &StateKey,
+        value: &StateValue,
+        version: Version,
+    ) -> Result<bool> {
+        let (leaf, _proof) = self
+            .state_merkle_db
+            .get_with_proof_ext(state_key, version)
+            .map_err(|err| self.state_merkle_error(err))?;
+        Ok(leaf.map_or(false, |(value_hash, _)| value_hash == value.hash()))
+    }
+
+    /// Returns the configured min number of the latest epoch snapshots retained, 0 if they are
+    /// only retained within the epoch snapshot prune window.
+    pub fn epoch_snapshot_retention(&self) -> usize {
+        self.num_epoch_snapshots_to_retain
+    }
+
+    /// Returns the version of the oldest epoch snapshot that's not pruned, i.e. how far back
+    /// proofs are available, or `None` if there's no epoch snapshot.
+    pub fn oldest_retained_epoch_snapshot(&self) -> Result<Option<Version>> {
+        let min_readable_version = self.epoch_snapshot_pruner.get_min_readable_version();
+        let mut iter = self
+            .ledger_db
+            .iter::<EpochByVersionSchema>(ReadOptions::default())?;
+        iter.seek(&min_readable_version)?;
+        match iter.next().transpose()? {
+            // The epoch may have ended after the latest snapshot was committed.
+            Some((version, _epoch)) => Ok(self
+                .state_merkle_db
+                .get_root_hash_option(version)?
+                .map(|_| version)),
+            None => Ok(None),
+        }
+    }
+
     pub fn get_value_count(&self, version: Version) -> Result<usize> {
         self.state_merkle_db.get_leaf_count(version)
     }
 
+    /// Returns the position of `state_key` among all the keys at `version` ordered by hashed key,
+    /// i.e. the index `JellyfishMerkleIterator::new_by_index` starts at to yield it, or `None` if
+    /// the key doesn't exist at `version`.
+    pub fn get_key_index(&self, state_key: &StateKey, version: Version) -> Result<Option<usize>> {
+        self.state_merkle_db
+            .get_leaf_index(state_key.hash(), version)
+    }
+
+    /// Returns `num_splits - 1` increasing hashed keys that split the leaves of the tree at
+    /// `version` into `num_splits` contiguous ranges with near-equal numbers of leaves, e.g. for
+    /// as many workers to process in parallel. Each range starts at a split point, inclusive, and
+    /// ends before the next one, with the first starting at the beginning of the keyspace and the
+    /// last ending at its end, so iterating with `get_state_key_and_value_iter` from each split
+    /// point to the next covers every leaf exactly once.
+    pub fn compute_balanced_splits(
+        &self,
+        version: Version,
+        num_splits: usize,
+    ) -> Result<Vec<HashValue>> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["compute_balanced_splits"])
+            .start_timer();
+        let leaf_count = self.get_value_count(version)?;
+        ensure!(
+            num_splits == 1 || (num_splits > 0 && num_splits <= leaf_count),
+            "Can't split {} leaves into {} ranges.",
+            leaf_count,
+            num_splits,
+        );
+        (1..num_splits)
+            .map(|i| {
+                let index = i * leaf_count / num_splits;
+                let (hashed_key, _) = JellyfishMerkleIterator::new_by_index(
+                    Arc::clone(&self.state_merkle_db),
+                    version,
+                    index,
+                )?
+                .next()
+                .ok_or_else(|| format_err!("Leaf {} not found at version {}.", index, version))??;
+                Ok(hashed_key)
+            })
+            .collect()
+    }
+
+    /// Returns a reader of the state at `version`, which must not be later than the latest
+    /// version. The state pruners keep what's readable at `version` until the reader is dropped,
+    /// so a long running scan through it sees a consistent state.
+    pub fn pinned_reader(self: &Arc<Self>, version: Version) -> Result<PinnedStateReader> {
+        let mut iter = self
+            .ledger_db
+            .iter::<VersionDataSchema>(ReadOptions::default())?;
+        iter.seek_to_last();
+        let latest_version = iter.next().transpose()?.map(|(version, _)| version);
+        ensure!(
+            latest_version.map_or(false, |latest_version| version <= latest_version),
+            "Can't pin {} past the latest version {:?}.",
+            version,
+            latest_version,
+        );
+        self.pin_version(version)?;
+        Ok(PinnedStateReader::new(Arc::clone(self), version))
+    }
+
     pub fn get_state_key_and_value_iter(
         self: &Arc<Self>,
         version: Version,
@@ -786,26 +2929,108 @@ impl StateStore {
         }))
     }
 
+    /// Same as `get_state_key_and_value_iter`, but walks the tree backward, from the largest hashed
+    /// key that is less or equal to `start_hashed_key`, in descending hashed key order.
+    pub fn get_state_key_and_value_iter_rev(
+        self: &Arc<Self>,
+        version: Version,
+        start_hashed_key: HashValue,
+    ) -> Result<impl Iterator<Item = Result<(StateKey, StateValue)>> + Send + Sync> {
+        let store = Arc::clone(self);
+        Ok(JellyfishMerkleIterator::new_rev(
+            Arc::clone(&self.state_merkle_db),
+            version,
+            start_hashed_key,
+        )?
+        .map(move |res| match res {
+            Ok((_hashed_key, (key, version))) => {
+                Ok((key.clone(), store.expect_value_by_version(&key, version)?))
+            },
+            Err(err) => Err(err),
+        }))
+    }
+
+    /// Streams the state values of the tree at `version`, from `start_hashed_key` on, in hashed
+    /// key order. The values are read on the IO pool up to `STATE_VALUE_STREAM_READ_AHEAD` ahead
+    /// of the consumer, after which reading pauses until the consumer catches up, so a slow
+    /// consumer doesn't make the values pile up in memory. Dropping the stream stops the reading.
+    pub fn stream_state_values(
+        self: &Arc<Self>,
+        version: Version,
+        start_hashed_key: HashValue,
+    ) -> impl Stream<Item = Result<(StateKey, StateValue)>> {
+        let (mut sender, receiver) = mpsc::channel(STATE_VALUE_STREAM_READ_AHEAD);
+        let store = Arc::clone(self);
+        IO_POOL.spawn(move || {
+            let iter = match store.get_state_key_and_value_iter(version, start_hashed_key) {
+                Ok(iter) => iter,
+                Err(err) => {
+                    // Fails only if the stream was dropped already.
+                    let _ = block_on(sender.send(Err(err)));
+                    return;
+                },
+            };
+            for res in iter {
+                let is_err = res.is_err();
+                // Blocks while the channel is full, i.e. the consumer is behind.
+                if block_on(sender.send(res)).is_err() || is_err {
+                    break;
+                }
+            }
+        });
+        receiver
+    }
+
     pub fn get_value_chunk_with_proof(
         self: &Arc<Self>,
         version: Version,
         first_index: usize,
         chunk_size: usize,
     ) -> Result<StateValueChunkWithProof> {
-        let result_iter = JellyfishMerkleIterator::new_by_index(
+        self.get_value_chunk_cursor(version, first_index)?
+            .next_chunk(chunk_size)
+    }
+
+    /// Returns a cursor over the value chunks of the snapshot at `version`, starting at
+    /// `first_index`. Consecutive chunks continue from where the previous one left off in the
+    /// tree, instead of seeking to their first index from the root.
+    pub fn get_value_chunk_cursor(
+        self: &Arc<Self>,
+        version: Version,
+        first_index: usize,
+    ) -> Result<StateValueChunkCursor> {
+        let iter = JellyfishMerkleIterator::new_by_index(
             Arc::clone(&self.state_merkle_db),
             version,
             first_index,
-        )?
-        .take(chunk_size);
-        let state_key_values: Vec<(StateKey, StateValue)> = result_iter
-            .into_iter()
-            .map(|res| {
-                res.and_then(|(_, (key, version))| {
-                    Ok((key.clone(), self.expect_value_by_version(&key, version)?))
+        )?;
+        Ok(StateValueChunkCursor::new(
+            Arc::clone(self),
+            version,
+            first_index,
+            iter,
+        ))
+    }
+
+    /// Resolves the values of `leaves`, the consecutive leaves starting at `first_index` in the
+    /// snapshot at `version`, into a chunk with its range proof.
+    pub(crate) fn value_chunk_with_proof(
+        &self,
+        version: Version,
+        first_index: usize,
+        leaves: Vec<(StateKey, Version)>,
+    ) -> Result<StateValueChunkWithProof> {
+        // Values are resolved in parallel, bounded by the size of the IO pool. The indexed
+        // parallel collect keeps them in leaf order.
+        let state_key_values: Vec<(StateKey, StateValue)> = IO_POOL.install(|| {
+            leaves
+                .into_par_iter()
+                .map(|(key, version)| {
+                    let value = self.expect_value_by_version(&key, version)?;
+                    Ok((key, value))
                 })
-            })
-            .collect::<Result<Vec<_>>>()?;
+                .collect::<Result<Vec<_>>>()
+        })?;
         ensure!(
             !state_key_values.is_empty(),
             AptosDbError::NotFound(format!("State chunk starting at {}", first_index)),
@@ -827,20 +3052,201 @@ impl StateStore {
         })
     }
 
+    /// Verifies that `chunk` is consistent with the snapshot with root hash `expected_root`,
+    /// without importing it: its keys and index range match its values, and its range proof
+    /// verifies against `expected_root`. Fails with a [`StateValueChunkError`] for the first
+    /// inconsistency found.
+    ///
+    /// A range proof only has the right siblings, and a restore computes the left ones from the
+    /// values it already has. So a chunk not starting at index 0 also needs
+    /// `first_value_proof`, the proof of its first value in the snapshot (e.g. from
+    /// `get_state_value_with_proof_by_version`), whose left siblings stand in for the values
+    /// before the chunk.
+    pub fn verify_value_chunk(
+        &self,
+        chunk: &StateValueChunkWithProof,
+        expected_root: HashValue,
+        first_value_proof: Option<&SparseMerkleProof>,
+    ) -> Result<()> {
+        let _timer = OTHER_TIMERS_SECONDS
+            .with_label_values(&["verify_value_chunk"])
+            .start_timer();
+        ensure!(
+            chunk.root_hash == expected_root,
+            StateValueChunkError::RootHashMismatch {
+                actual: chunk.root_hash,
+                expected: expected_root,
+            },
+        );
+        ensure!(!chunk.raw_values.is_empty(), StateValueChunkError::Empty);
+        ensure!(
+            chunk.last_index.checked_sub(chunk.first_index)
+                == Some(chunk.raw_values.len() as u64 - 1),
+            StateValueChunkError::IndexRangeMismatch {
+                first_index: chunk.first_index,
+                last_index: chunk.last_index,
+                num_values: chunk.raw_values.len(),
+            },
+        );
+
+        // (hashed key, value hash) of the leaves in the chunk.
+        let leaves: Vec<_> = chunk
+            .raw_values
+            .iter()
+            .map(|(key, value)| (key.hash(), value.hash()))
+            .collect();
+        for (i, pair) in leaves.windows(2).enumerate() {
+            ensure!(
+                pair[0].0 < pair[1].0,
+                StateValueChunkError::KeysOutOfOrder(i + 1)
+            );
+        }
+        let first_key = leaves.first().expect("checked to exist").0;
+        ensure!(
+            first_key == chunk.first_key,
+            StateValueChunkError::FirstKeyMismatch {
+                claimed: chunk.first_key,
+                actual: first_key,
+            },
+        );
+        let (last_key, last_value_hash) = *leaves.last().expect("checked to exist");
+        ensure!(
+            last_key == chunk.last_key,
+            StateValueChunkError::LastKeyMismatch {
+                claimed: chunk.last_key,
+                actual: last_key,
+            },
+        );
+        let preceding_siblings = match first_value_proof {
+            Some(proof) => {
+                let (first_key, first_value_hash) = leaves[0];
+                proof
+                    .verify_by_hash(expected_root, first_key, Some(first_value_hash))
+                    .map_err(|err| StateValueChunkError::InvalidProof(err.to_string()))?;
+                compute_preceding_siblings(first_key, proof.siblings())
+            },
+            None => {
+                ensure!(
+                    chunk.first_index == 0,
+                    StateValueChunkError::PrecedingValuesUnknown(chunk.first_index),
+                );
+                vec![]
+            },
+        };
+
+        let left_siblings = compute_left_siblings(
+            &leaves,
+            chunk.proof.right_siblings().len(),
+            &preceding_siblings,
+        );
+        chunk
+            .proof
+            .verify(
+                expected_root,
+                SparseMerkleLeafNode::new(last_key, last_value_hash),
+                left_siblings,
+            )
+            .map_err(|err| StateValueChunkError::InvalidProof(err.to_string()))?;
+        Ok(())
+    }
+
+    /// Exports all the state values of the snapshot at `version` into `dir` as numbered chunk
+    /// files of up to `chunk_size` values each. The range proof of each chunk is verified against
+    /// the root hash of the snapshot the way a restore does, before the manifest is written to
+    /// `MANIFEST_FILE_NAME`, so an export directory without a manifest is incomplete.
+    pub fn export_snapshot_to_dir(
+        self: &Arc<Self>,
+        version: Version,
+        dir: &Path,
+        chunk_size: usize,
+    ) -> Result<ExportManifest> {
+        ensure!(chunk_size > 0, "Chunk size must be positive.");
+        let root_hash = self.get_root_hash(version)?;
+        let num_values = self.get_value_count(version)?;
+        std::fs::create_dir_all(dir)?;
+
+        // Only the partial nodes of the restore are kept, so it verifies the chunks in constant
+        // memory.
+        let mut verifier = JellyfishMerkleRestore::new_overwrite(
+            Arc::new(DiscardingTreeWriter),
+            version,
+            root_hash,
+        )?;
+        let mut chunks = Vec::new();
+        let mut cursor = self.get_value_chunk_cursor(version, 0)?;
+        let mut next_index = 0;
+        let mut last_key = None;
+        while next_index < num_values {
+            let chunk = cursor.next_chunk(chunk_size)?;
+            // The restore skips the values it already has instead of failing on them.
+            ensure!(
+                last_key.map_or(true, |last_key| chunk.first_key > last_key),
+                "Chunk starting at {} is out of order.",
+                next_index,
+            );
+            verifier
+                .add_chunk_impl(
+                    chunk
+                        .raw_values
+                        .iter()
+                        .map(|(key, value)| (key, value.hash()))
+                        .collect(),
+                    chunk.proof.clone(),
+                )
+                .map_err(|err| {
+                    format_err!(
+                        "Chunk starting at {} doesn't verify against root hash {}: {}",
+                        next_index,
+                        root_hash,
+                        err
+                    )
+                })?;
+            next_index = chunk.last_index as usize + 1;
+            ensure!(
+                chunk.is_last_chunk() == (next_index == num_values),
+                "Chunk ending at {} doesn't match the {} values in the snapshot.",
+                chunk.last_index,
+                num_values,
+            );
+            last_key = Some(chunk.last_key);
+
+            let file_name = format!("{}.chunk", chunks.len());
+            std::fs::write(dir.join(&file_name), bcs::to_bytes(&chunk)?)?;
+            chunks.push(ExportedChunk {
+                first_index: chunk.first_index,
+                last_index: chunk.last_index,
+                file_name,
+            });
+        }
+
+        let manifest = ExportManifest {
+            version,
+            root_hash,
+            num_values: num_values as u64,
+            chunks,
+        };
+        std::fs::write(dir.join(MANIFEST_FILE_NAME), bcs::to_bytes(&manifest)?)?;
+        Ok(manifest)
+    }
+
     // state sync doesn't query for the progress, but keeps its record by itself.
     // TODO: change to async comment once it does like https://github.com/aptos-labs/aptos-core/blob/159b00f3d53e4327523052c1b99dd9889bf13b03/storage/backup/backup-cli/src/backup_types/state_snapshot/restore.rs#L147 or overlap at least two chunks.
+    /// Returns a receiver to restore the snapshot at `version` with. Fails if the configured max
+    /// number of restores are already running.
     pub fn get_snapshot_receiver(
         self: &Arc<Self>,
         version: Version,
         expected_root_hash: HashValue,
     ) -> Result<Box<dyn StateSnapshotReceiver<StateKey, StateValue>>> {
-        Ok(Box::new(StateSnapshotRestore::new(
+        let permit = self.restore_limiter.try_acquire()?;
+        let restore = StateSnapshotRestore::new(
             &self.state_merkle_db,
             self,
             version,
             expected_root_hash,
             false, /* async_commit */
-        )?))
+        )?;
+        Ok(Box::new(LimitedSnapshotReceiver::new(restore, permit)))
     }
 
     #[cfg(test)]
@@ -868,6 +3274,13 @@ impl StateStore {
     }
 }
 
+/// Whether the error, or any error causing it, comes from the underlying storage.
+fn is_io_error(error: &anyhow::Error) -> bool {
+    error
+        .chain()
+        .any(|cause| cause.is::<std::io::Error>() || cause.is::<rocksdb::Error>())
+}
+
 impl StateValueWriter<StateKey, StateValue> for StateStore {
     fn write_kv_batch(
         &self,
@@ -878,11 +3291,19 @@ impl StateValueWriter<StateKey, StateValue> for StateStore {
         let _timer = OTHER_TIMERS_SECONDS
             .with_label_values(&["state_value_writer_write_chunk"])
             .start_timer();
-        let batch = SchemaBatch::new();
-        node_batch
-            .par_iter()
-            .map(|(k, v)| batch.put::<StateValueSchema>(k, v))
-            .collect::<Result<Vec<_>>>()?;
+        self.disk_space_guard.check()?;
+        // The restored values replace the state the pinned values were read from.
+        self.pinned_value_cache.clear();
+        // A large chunk is committed in several batches to bound the size of a write batch. The
+        // progress is only written with the last one, so a crash in between redoes the chunk.
+        let entries: Vec<_> = node_batch.iter().collect();
+        let mut chunks = entries.chunks(self.max_state_values_per_restore_batch);
+        let last_chunk = chunks.next_back().unwrap_or_default();
+        for chunk in chunks {
+            self.state_kv_db
+                .commit_raw_batch(state_value_schema_batch(chunk)?)?;
+        }
+        let batch = state_value_schema_batch(last_chunk)?;
         batch.put::<DbMetadataSchema>(
             &DbMetadataKey::StateSnapshotRestoreProgress(version),
             &DbMetadataValue::StateSnapshotProgress(progress),
@@ -904,3 +3325,152 @@ impl StateValueWriter<StateKey, StateValue> for StateStore {
             .map(|v| v.expect_state_snapshot_progress()))
     }
 }
+
+/// Puts the state values of a restored chunk into a new batch.
+fn state_value_schema_batch(
+    entries: &[(&(StateKey, Version), &Option<StateValue>)],
+) -> Result<SchemaBatch> {
+    let batch = SchemaBatch::new();
+    entries
+        .par_iter()
+        .map(|(k, v)| batch.put::<StateValueSchema>(k, v))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(batch)
+}
+
+/// Drops the nodes of a JMT restore that's only run to verify chunks with.
+struct DiscardingTreeWriter;
+
+impl TreeWriter<StateKey> for DiscardingTreeWriter {
+    fn write_node_batch(&self, _node_batch: &NodeBatch<StateKey>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Computes the left siblings, bottom up, of the last of the sorted `leaves` of a sparse Merkle
+/// tree, given the leaves before it back to the first one, the left siblings of the first leaf
+/// by depth as returned by `compute_preceding_siblings`, and the number of right siblings of the
+/// last leaf. Mirrors how a JMT restore computes the left siblings to verify a range proof with.
+fn compute_left_siblings(
+    leaves: &[(HashValue, HashValue)],
+    num_right_siblings: usize,
+    preceding_siblings: &[HashValue],
+) -> Vec<HashValue> {
+    let (first_key, _) = leaves.first().expect("Leaves must not be empty.");
+    let (last_key, _) = leaves.last().expect("Leaves must not be empty.");
+    // Above this depth, the left siblings of the last leaf are the ones of the first leaf.
+    let common_prefix_len = first_key.common_prefix_bits_len(*last_key);
+    // The leaves before the last one that share a prefix of the visited bits with it.
+    let mut prefix_leaves = &leaves[..leaves.len() - 1];
+    let mut left_siblings = vec![];
+    let mut num_visited_right_siblings = 0;
+    for (depth, bit) in last_key.iter_bits().enumerate() {
+        let split = prefix_leaves.partition_point(|(key, _)| !key.bit(depth));
+        if bit {
+            left_siblings.push(match depth.cmp(&common_prefix_len) {
+                Ordering::Less => preceding_sibling(preceding_siblings, depth),
+                Ordering::Equal => compute_first_subtree_hash(
+                    &prefix_leaves[..split],
+                    depth + 1,
+                    preceding_siblings,
+                ),
+                Ordering::Greater => compute_subtree_hash(&prefix_leaves[..split], depth + 1),
+            });
+            prefix_leaves = &prefix_leaves[split..];
+        } else {
+            num_visited_right_siblings += 1;
+            prefix_leaves = &prefix_leaves[..split];
+        }
+    }
+
+    // Remove the placeholder siblings below the leaf, as the restore does.
+    for bit in last_key.iter_bits().rev() {
+        if bit {
+            if *left_siblings.last().expect("This sibling must exist.")
+                == *SPARSE_MERKLE_PLACEHOLDER_HASH
+            {
+                left_siblings.pop();
+            } else {
+                break;
+            }
+        } else if num_visited_right_siblings > num_right_siblings {
+            num_visited_right_siblings -= 1;
+        } else {
+            break;
+        }
+    }
+    left_siblings.reverse();
+    left_siblings
+}
+
+/// Returns the left siblings of the leaf with `key`, indexed by depth, from the `siblings` of its
+/// proof, which are ordered bottom up.
+fn compute_preceding_siblings(key: HashValue, siblings: &[HashValue]) -> Vec<HashValue> {
+    siblings
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(depth, sibling)| {
+            if key.bit(depth) {
+                *sibling
+            } else {
+                *SPARSE_MERKLE_PLACEHOLDER_HASH
+            }
+        })
+        .collect()
+}
+
+/// The left sibling at `depth` of the path of the first leaf. Depths past the end of
+/// `preceding_siblings` have no values before the first leaf.
+fn preceding_sibling(preceding_siblings: &[HashValue], depth: usize) -> HashValue {
+    preceding_siblings
+        .get(depth)
+        .copied()
+        .unwrap_or(*SPARSE_MERKLE_PLACEHOLDER_HASH)
+}
+
+/// Computes the root hash of the sparse Merkle subtree at `depth` on the path of the first of the
+/// sorted `leaves`, in which the values before the first leaf are given by `preceding_siblings`.
+fn compute_first_subtree_hash(
+    leaves: &[(HashValue, HashValue)],
+    depth: usize,
+    preceding_siblings: &[HashValue],
+) -> HashValue {
+    let (first_key, first_value_hash) = leaves[0];
+    if leaves.len() == 1
+        && (depth..HashValue::LENGTH_IN_BITS)
+            .all(|d| preceding_sibling(preceding_siblings, d) == *SPARSE_MERKLE_PLACEHOLDER_HASH)
+    {
+        return SparseMerkleLeafNode::new(first_key, first_value_hash).hash();
+    }
+    let (left, right) = if first_key.bit(depth) {
+        // The values before the first leaf are on the left, and the rest of the leaves with it.
+        (
+            preceding_sibling(preceding_siblings, depth),
+            compute_first_subtree_hash(leaves, depth + 1, preceding_siblings),
+        )
+    } else {
+        let split = leaves.partition_point(|(key, _)| !key.bit(depth));
+        (
+            compute_first_subtree_hash(&leaves[..split], depth + 1, preceding_siblings),
+            compute_subtree_hash(&leaves[split..], depth + 1),
+        )
+    };
+    SparseMerkleInternalNode::new(left, right).hash()
+}
+
+/// Computes the root hash of the sparse Merkle subtree at `depth` with the sorted `leaves`.
+fn compute_subtree_hash(leaves: &[(HashValue, HashValue)], depth: usize) -> HashValue {
+    match leaves {
+        [] => *SPARSE_MERKLE_PLACEHOLDER_HASH,
+        [(key, value_hash)] => SparseMerkleLeafNode::new(*key, *value_hash).hash(),
+        _ => {
+            let split = leaves.partition_point(|(key, _)| !key.bit(depth));
+            SparseMerkleInternalNode::new(
+                compute_subtree_hash(&leaves[..split], depth + 1),
+                compute_subtree_hash(&leaves[split..], depth + 1),
+            )
+            .hash()
+        },
+    }
+}
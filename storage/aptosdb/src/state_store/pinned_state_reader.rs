@@ -0,0 +1,97 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{state_store::StateStore, utils::iterators::PrefixedStateValueIterator};
+use anyhow::Result;
+use aptos_infallible::Mutex;
+use aptos_storage_interface::DbReader;
+use aptos_types::{
+    proof::SparseMerkleProofExt,
+    state_store::{state_key::StateKey, state_key_prefix::StateKeyPrefix, state_value::StateValue},
+    transaction::Version,
+};
+use std::{collections::BTreeMap, sync::Arc};
+
+/// The versions pinned by `PinnedStateReader`s, with the number of readers pinning each. The
+/// state pruners' targets are set under the same lock, so a version can't be pinned while what's
+/// readable at it is about to be pruned.
+#[derive(Default)]
+pub(crate) struct PinnedVersions {
+    inner: Mutex<BTreeMap<Version, usize>>,
+}
+
+impl PinnedVersions {
+    /// Pins `version` if `check` passes.
+    pub fn pin(&self, version: Version, check: impl FnOnce() -> Result<()>) -> Result<()> {
+        let mut pinned = self.inner.lock();
+        check()?;
+        *pinned.entry(version).or_insert(0) += 1;
+        Ok(())
+    }
+
+    pub fn unpin(&self, version: Version) {
+        let mut pinned = self.inner.lock();
+        let count = pinned.get_mut(&version).expect("Version must be pinned.");
+        *count -= 1;
+        if *count == 0 {
+            pinned.remove(&version);
+        }
+    }
+
+    /// Calls `f` with the lowest pinned version, if any, which stays pinned until `f` returns.
+    pub fn with_min_pinned<R>(&self, f: impl FnOnce(Option<Version>) -> R) -> R {
+        let pinned = self.inner.lock();
+        f(pinned.keys().next().copied())
+    }
+}
+
+/// Reads the state at a pinned version, as returned by `StateStore::pinned_reader`. The state
+/// pruners keep what's readable at the version until the reader is dropped.
+pub struct PinnedStateReader {
+    store: Arc<StateStore>,
+    version: Version,
+}
+
+impl PinnedStateReader {
+    pub(crate) fn new(store: Arc<StateStore>, version: Version) -> Self {
+        Self { store, version }
+    }
+
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    pub fn get_state_value(&self, state_key: &StateKey) -> Result<Option<StateValue>> {
+        self.store
+            .get_state_value_by_version(state_key, self.version)
+    }
+
+    pub fn get_state_values(&self, keys: &[StateKey]) -> Result<Vec<Option<StateValue>>> {
+        self.store.get_state_values_by_version(keys, self.version)
+    }
+
+    /// Fails if the version has no state snapshot, or its nodes had been pruned before the reader
+    /// was created.
+    pub fn get_state_value_with_proof(
+        &self,
+        state_key: &StateKey,
+    ) -> Result<(Option<StateValue>, SparseMerkleProofExt)> {
+        self.store
+            .get_state_value_with_proof_by_version_ext(state_key, self.version)
+    }
+
+    pub fn get_prefixed_state_value_iterator(
+        &self,
+        key_prefix: &StateKeyPrefix,
+        first_key_opt: Option<&StateKey>,
+    ) -> Result<PrefixedStateValueIterator> {
+        self.store
+            .get_prefixed_state_value_iterator(key_prefix, first_key_opt, self.version)
+    }
+}
+
+impl Drop for PinnedStateReader {
+    fn drop(&mut self) {
+        self.store.pinned_versions.unpin(self.version);
+    }
+}
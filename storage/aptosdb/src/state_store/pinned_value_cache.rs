@@ -0,0 +1,138 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use aptos_infallible::RwLock;
+use aptos_types::{
+    state_store::{
+        state_key::{StateKey, StateKeyInner},
+        state_value::StateValue,
+    },
+    transaction::Version,
+};
+use move_core_types::language_storage::CORE_CODE_ADDRESS;
+use std::collections::HashMap;
+
+/// Holds the values of all keys under the framework address in memory once pinned, so the most
+/// read resources are served without touching the DB and can't be evicted. The pinned values are
+/// kept up to date as new versions are committed.
+#[derive(Default)]
+pub(crate) struct PinnedValueCache {
+    inner: RwLock<PinnedValueCacheInner>,
+}
+
+#[derive(Default)]
+struct PinnedValueCacheInner {
+    // The latest version committed that touched a framework key, tracked even if nothing is
+    // pinned so values read before a concurrent commit are not pinned.
+    last_write_version: Option<Version>,
+    // The latest version whose writes have been applied.
+    last_applied_version: Option<Version>,
+    pinned: Option<PinnedValues>,
+}
+
+struct PinnedValues {
+    // The values are the ones at any version from `valid_since` to `valid_until`, inclusively.
+    // Reads at later versions go to the DB until the writes at these versions are applied.
+    valid_since: Version,
+    valid_until: Version,
+    values: HashMap<StateKey, StateValue>,
+}
+
+pub(crate) fn is_framework_key(key: &StateKey) -> bool {
+    match key.inner() {
+        StateKeyInner::AccessPath(access_path) => access_path.address == CORE_CODE_ADDRESS,
+        _ => false,
+    }
+}
+
+impl PinnedValueCache {
+    /// Pins `values`, the values of all framework keys at `version`, replacing what was pinned
+    /// before. Fails if a framework key was written after `version`, as the values are stale.
+    pub fn pin(&self, version: Version, values: HashMap<StateKey, StateValue>) -> Result<()> {
+        let mut inner = self.inner.write();
+        if let Some(last_write_version) = inner.last_write_version {
+            ensure!(
+                last_write_version <= version,
+                "Framework resources were written at version {}, after version {} to pin.",
+                last_write_version,
+                version,
+            );
+        }
+        // Versions applied after `version` didn't touch a framework key, so the values hold for
+        // them as well.
+        let valid_until = inner
+            .last_applied_version
+            .map_or(version, |applied| applied.max(version));
+        inner.pinned = Some(PinnedValues {
+            valid_since: version,
+            valid_until,
+            values,
+        });
+        Ok(())
+    }
+
+    /// Returns `Some` if the read of `key` at `version` is answered by the pinned values, with
+    /// `None` inside if the key doesn't exist, or `None` if the read has to go to the DB.
+    pub fn get(&self, key: &StateKey, version: Version) -> Option<Option<StateValue>> {
+        if !is_framework_key(key) {
+            return None;
+        }
+        let inner = self.inner.read();
+        let pinned = inner.pinned.as_ref()?;
+        (pinned.valid_since..=pinned.valid_until)
+            .contains(&version)
+            .then(|| pinned.values.get(key).cloned())
+    }
+
+    /// Applies the writes to framework keys in `value_state_sets`, which start at `first_version`
+    /// and must have been committed to the DB. The pinned values are dropped if the versions
+    /// right before `first_version` were not applied, since they missed some writes.
+    pub fn update(
+        &self,
+        value_state_sets: &[&HashMap<StateKey, Option<StateValue>>],
+        first_version: Version,
+    ) {
+        if value_state_sets.is_empty() {
+            return;
+        }
+        let last_version = first_version + value_state_sets.len() as Version - 1;
+        let framework_writes: Vec<_> = value_state_sets
+            .iter()
+            .enumerate()
+            .flat_map(|(i, kvs)| {
+                let version = first_version + i as Version;
+                kvs.iter()
+                    .filter(|(key, _)| is_framework_key(key))
+                    .map(move |(key, value)| (version, key, value))
+            })
+            .collect();
+
+        let mut inner = self.inner.write();
+        inner.last_applied_version = Some(last_version);
+        if let Some((version, _, _)) = framework_writes.last() {
+            inner.last_write_version = Some(*version);
+        }
+        if matches!(&inner.pinned, Some(pinned) if pinned.valid_until + 1 < first_version) {
+            inner.pinned = None;
+        }
+        if let Some(pinned) = &mut inner.pinned {
+            for (_, key, value) in &framework_writes {
+                match value {
+                    Some(value) => pinned.values.insert((*key).clone(), value.clone()),
+                    None => pinned.values.remove(*key),
+                };
+            }
+            if let Some((version, _, _)) = framework_writes.last() {
+                pinned.valid_since = *version;
+            }
+            pinned.valid_until = last_version;
+        }
+    }
+
+    /// Drops the pinned values and forgets the versions applied, e.g., when the DB is reset to
+    /// what's committed or a state snapshot is restored into it.
+    pub fn clear(&self) {
+        *self.inner.write() = PinnedValueCacheInner::default();
+    }
+}
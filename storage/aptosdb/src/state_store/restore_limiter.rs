@@ -0,0 +1,92 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::state_restore::StateSnapshotRestore;
+use anyhow::{ensure, Result};
+use aptos_storage_interface::StateSnapshotReceiver;
+use aptos_types::{
+    proof::SparseMerkleRangeProof,
+    state_store::{state_key::StateKey, state_value::StateValue},
+};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// Limits the number of state snapshot restores running at the same time, since concurrent
+/// restores contend on the state K/V DB and interleave their writes and progress tracking.
+pub(crate) struct RestoreLimiter {
+    max_concurrent_restores: usize,
+    num_running: Arc<AtomicUsize>,
+}
+
+impl RestoreLimiter {
+    pub fn new(max_concurrent_restores: usize) -> Self {
+        Self {
+            max_concurrent_restores,
+            num_running: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Takes a slot for a restore, failing instead of blocking if all slots are taken. The slot
+    /// is released once the returned permit is dropped.
+    pub fn try_acquire(&self) -> Result<RestorePermit> {
+        let acquired = self
+            .num_running
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |num_running| {
+                (num_running < self.max_concurrent_restores).then_some(num_running + 1)
+            })
+            .is_ok();
+        ensure!(
+            acquired,
+            "Too many concurrent state snapshot restores, at most {} allowed.",
+            self.max_concurrent_restores,
+        );
+        Ok(RestorePermit {
+            num_running: Arc::clone(&self.num_running),
+        })
+    }
+}
+
+pub(crate) struct RestorePermit {
+    num_running: Arc<AtomicUsize>,
+}
+
+impl Drop for RestorePermit {
+    fn drop(&mut self) {
+        self.num_running.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// A snapshot receiver that holds a restore slot until it's finished or dropped.
+pub(crate) struct LimitedSnapshotReceiver {
+    restore: StateSnapshotRestore<StateKey, StateValue>,
+    _permit: RestorePermit,
+}
+
+impl LimitedSnapshotReceiver {
+    pub fn new(restore: StateSnapshotRestore<StateKey, StateValue>, permit: RestorePermit) -> Self {
+        Self {
+            restore,
+            _permit: permit,
+        }
+    }
+}
+
+impl StateSnapshotReceiver<StateKey, StateValue> for LimitedSnapshotReceiver {
+    fn add_chunk(
+        &mut self,
+        chunk: Vec<(StateKey, StateValue)>,
+        proof: SparseMerkleRangeProof,
+    ) -> Result<()> {
+        self.restore.add_chunk(chunk, proof)
+    }
+
+    fn finish(self) -> Result<()> {
+        self.restore.finish()
+    }
+
+    fn finish_box(self: Box<Self>) -> Result<()> {
+        self.finish()
+    }
+}
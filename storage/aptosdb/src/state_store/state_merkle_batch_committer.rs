@@ -8,7 +8,7 @@ use crate::{
     metrics::LATEST_SNAPSHOT_VERSION,
     state_store::{buffered_state::CommitMessage, StateDb},
     version_data::VersionDataSchema,
-    PrunerManager, ShardedStateMerkleSchemaBatch, OTHER_TIMERS_SECONDS,
+    ShardedStateMerkleSchemaBatch, OTHER_TIMERS_SECONDS,
 };
 use anyhow::{anyhow, ensure, Result};
 use aptos_crypto::HashValue;
@@ -79,11 +79,10 @@ impl StateMerkleBatchCommitter {
                     );
                     LATEST_SNAPSHOT_VERSION.set(current_version as i64);
                     self.state_db
-                        .state_merkle_pruner
-                        .maybe_set_pruner_target_db_version(current_version);
+                        .maybe_set_state_merkle_pruner_target(current_version);
                     self.state_db
-                        .epoch_snapshot_pruner
-                        .maybe_set_pruner_target_db_version(current_version);
+                        .maybe_set_epoch_snapshot_pruner_target(current_version)
+                        .unwrap();
 
                     self.check_usage_consistency(&state_delta).unwrap();
                 },
@@ -4,6 +4,7 @@
 //! This file defines the state snapshot committer running in background thread within StateStore.
 
 use crate::{
+    metrics::ASYNC_COMMIT_CHANNEL_OCCUPANCY,
     state_store::{
         buffered_state::CommitMessage,
         state_merkle_batch_committer::{StateMerkleBatch, StateMerkleBatchCommitter},
@@ -13,13 +14,10 @@ use crate::{
 };
 use aptos_logger::trace;
 use aptos_storage_interface::{jmt_update_refs, jmt_updates, state_delta::StateDelta};
+use crossbeam_channel::Receiver;
 use static_assertions::const_assert;
 use std::{
-    sync::{
-        mpsc,
-        mpsc::{Receiver, SyncSender},
-        Arc,
-    },
+    sync::{mpsc, mpsc::SyncSender, Arc},
     thread::JoinHandle,
 };
 
@@ -67,6 +65,7 @@ impl StateSnapshotCommitter {
         while let Ok(msg) = self.state_snapshot_commit_receiver.recv() {
             match msg {
                 CommitMessage::Data(delta_to_commit) => {
+                    ASYNC_COMMIT_CHANNEL_OCCUPANCY.dec();
                     let node_hashes = delta_to_commit
                         .current
                         .clone()
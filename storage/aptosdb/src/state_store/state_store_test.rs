@@ -4,20 +4,42 @@
 
 use super::*;
 use crate::{
+    metrics::STATE_KV_FREE_BYTES,
     new_sharded_kv_schema_batch,
+    pruner::db_pruner::DBPruner,
+    schema::write_set::WriteSetSchema,
     state_restore::StateSnapshotRestore,
     test_helper::{arb_state_kv_sets, update_store},
     AptosDB,
 };
-use aptos_jellyfish_merkle::TreeReader;
+use aptos_config::config::{
+    LedgerPrunerConfig, PrunerConfig, RocksdbConfigs, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+    NO_OP_STORAGE_PRUNER_CONFIG,
+};
+use aptos_jellyfish_merkle::{
+    node_type::{Node, NodeKey},
+    TreeReader,
+};
 use aptos_storage_interface::{
-    jmt_update_refs, jmt_updates, DbReader, DbWriter, StateSnapshotReceiver,
+    jmt_update_refs, jmt_updates, DbReader, DbWriter, DefaultLeafHasher, StateSnapshotReceiver,
 };
 use aptos_temppath::TempPath;
 use aptos_types::{
-    access_path::AccessPath, account_address::AccountAddress, state_store::state_key::StateKeyTag,
+    access_path::AccessPath,
+    account_address::AccountAddress,
+    account_config::{new_block_event_key, NewBlockEvent},
+    contract_event::ContractEvent,
+    nibble::{Nibble, ROOT_NIBBLE_HEIGHT},
+    state_store::{state_key::StateKeyTag, table::TableHandle},
+    transaction::{ExecutionStatus, TransactionInfo},
+    write_set::{WriteOp, WriteSet, WriteSetMut},
+};
+use futures::StreamExt;
+use move_core_types::{
+    identifier::Identifier, language_storage::TypeTag, move_resource::MoveStructType,
 };
 use proptest::{collection::hash_map, prelude::*};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 fn put_value_set(
     state_store: &StateStore,
@@ -244,6 +266,473 @@ fn test_get_values_by_key_prefix() {
     assert_eq!(*key_value_map.get(&key5).unwrap(), value5_v2);
 }
 
+#[test]
+fn test_get_account_state() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    let address = AccountAddress::new([12u8; AccountAddress::LENGTH]);
+    let other_address = AccountAddress::new([22u8; AccountAddress::LENGTH]);
+
+    let key1 = StateKey::access_path(AccessPath::new(address, b"state_key1".to_vec()));
+    let key2 = StateKey::access_path(AccessPath::new(address, b"state_key2".to_vec()));
+    let key3 = StateKey::access_path(AccessPath::new(other_address, b"state_key3".to_vec()));
+    let value1 = StateValue::from(String::from("value1").into_bytes());
+    let value2 = StateValue::from(String::from("value2").into_bytes());
+    let value3 = StateValue::from(String::from("value3").into_bytes());
+
+    put_value_set(
+        store,
+        vec![(key1.clone(), value1.clone()), (key3, value3)],
+        0,
+        None,
+    );
+    put_value_set(store, vec![(key2.clone(), value2.clone())], 1, Some(0));
+
+    assert_eq!(store.get_account_state(address, 0).unwrap(), vec![(
+        key1.clone(),
+        value1.clone()
+    )]);
+    let account_state: HashMap<_, _> = store
+        .get_account_state(address, 1)
+        .unwrap()
+        .into_iter()
+        .collect();
+    assert_eq!(account_state.len(), 2);
+    assert_eq!(*account_state.get(&key1).unwrap(), value1);
+    assert_eq!(*account_state.get(&key2).unwrap(), value2);
+
+    // An account without any resources.
+    let empty_address = AccountAddress::new([32u8; AccountAddress::LENGTH]);
+    assert!(store
+        .get_account_state(empty_address, 1)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_count_account_resources() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    let address = AccountAddress::new([12u8; AccountAddress::LENGTH]);
+    let other_address = AccountAddress::new([22u8; AccountAddress::LENGTH]);
+
+    let key1 = StateKey::access_path(AccessPath::new(address, b"state_key1".to_vec()));
+    let key2 = StateKey::access_path(AccessPath::new(address, b"state_key2".to_vec()));
+    let key3 = StateKey::access_path(AccessPath::new(other_address, b"state_key3".to_vec()));
+    let value1 = StateValue::from(String::from("value1").into_bytes());
+    let value2 = StateValue::from(String::from("value2").into_bytes());
+    let value3 = StateValue::from(String::from("value3").into_bytes());
+
+    put_value_set(store, vec![(key1.clone(), value1), (key3, value3)], 0, None);
+    put_value_set(store, vec![(key2, value2)], 1, Some(0));
+    // Updating a key doesn't add to the count.
+    put_value_set(
+        store,
+        vec![(key1, StateValue::from(b"value1_v2".to_vec()))],
+        2,
+        Some(1),
+    );
+
+    assert_eq!(store.count_account_resources(address, 0).unwrap(), 1);
+    assert_eq!(store.count_account_resources(address, 1).unwrap(), 2);
+    assert_eq!(store.count_account_resources(address, 2).unwrap(), 2);
+    assert_eq!(store.count_account_resources(other_address, 2).unwrap(), 1);
+
+    // An account without any resources.
+    let empty_address = AccountAddress::new([32u8; AccountAddress::LENGTH]);
+    assert_eq!(store.count_account_resources(empty_address, 2).unwrap(), 0);
+}
+
+#[test]
+fn test_prefixed_state_value_iterator_across_shards() {
+    // Each db stands for a shard, holding a disjoint set of keys.
+    let tmp_dir1 = TempPath::new();
+    let db1 = AptosDB::new_for_test(&tmp_dir1);
+    let tmp_dir2 = TempPath::new();
+    let db2 = AptosDB::new_for_test(&tmp_dir2);
+    // The same keys in a single db, iterated without merging.
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let address = AccountAddress::new([12u8; AccountAddress::LENGTH]);
+    let prefix = StateKeyPrefix::from(address);
+
+    let key1 = StateKey::access_path(AccessPath::new(address, b"state_key1".to_vec()));
+    let key2 = StateKey::access_path(AccessPath::new(address, b"state_key2".to_vec()));
+    let key3 = StateKey::access_path(AccessPath::new(address, b"state_key3".to_vec()));
+    let value1_v0 = StateValue::from(String::from("value1_v0").into_bytes());
+    let value1_v1 = StateValue::from(String::from("value1_v1").into_bytes());
+    let value2 = StateValue::from(String::from("value2").into_bytes());
+    let value3 = StateValue::from(String::from("value3").into_bytes());
+
+    let value_set_v0 = vec![
+        (key1.clone(), value1_v0.clone()),
+        (key2.clone(), value2.clone()),
+        (key3.clone(), value3.clone()),
+    ];
+    put_value_set(
+        &db1.state_store,
+        vec![(key2.clone(), value2.clone())],
+        0,
+        None,
+    );
+    put_value_set(
+        &db2.state_store,
+        vec![(key1.clone(), value1_v0), (key3.clone(), value3)],
+        0,
+        None,
+    );
+    put_value_set(&db.state_store, value_set_v0, 0, None);
+    put_value_set(
+        &db2.state_store,
+        vec![(key1.clone(), value1_v1.clone())],
+        1,
+        Some(0),
+    );
+    put_value_set(&db.state_store, vec![(key1.clone(), value1_v1)], 1, Some(0));
+
+    let shards = || {
+        vec![
+            db1.state_store.state_kv_db.metadata_db(),
+            db2.state_store.state_kv_db.metadata_db(),
+        ]
+    };
+    for version in 0..2 {
+        let merged = PrefixedStateValueIterator::new(shards(), prefix.clone(), None, version)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        let expected = db
+            .state_store
+            .get_prefixed_state_value_iterator(&prefix, None, version)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged, expected);
+
+        // Paging from a key in the middle.
+        let merged_page =
+            PrefixedStateValueIterator::new(shards(), prefix.clone(), Some(key2.clone()), version)
+                .unwrap()
+                .collect::<Result<Vec<_>>>()
+                .unwrap();
+        let expected_page = db
+            .state_store
+            .get_prefixed_state_value_iterator(&prefix, Some(&key2), version)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(merged_page, expected_page);
+    }
+}
+
+#[test]
+fn test_get_resource_group() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    let address = AccountAddress::new([12u8; AccountAddress::LENGTH]);
+    let struct_tag = |name: &str| StructTag {
+        address: AccountAddress::ONE,
+        module: Identifier::new("object").unwrap(),
+        name: Identifier::new(name).unwrap(),
+        type_params: vec![],
+    };
+    let group_key =
+        |tag| StateKey::access_path(AccessPath::resource_group_access_path(address, tag));
+
+    let group: BTreeMap<_, _> = vec![
+        (struct_tag("ObjectCore"), vec![1u8, 2, 3]),
+        (struct_tag("Token"), vec![4u8, 5]),
+    ]
+    .into_iter()
+    .collect();
+    put_value_set(
+        store,
+        vec![
+            (
+                group_key(struct_tag("ObjectGroup")),
+                StateValue::from(bcs::to_bytes(&group).unwrap()),
+            ),
+            (
+                group_key(struct_tag("CorruptedGroup")),
+                StateValue::from(vec![0xFFu8]),
+            ),
+        ],
+        0,
+        None,
+    );
+
+    assert_eq!(
+        store
+            .get_resource_group(address, struct_tag("ObjectGroup"), 0)
+            .unwrap(),
+        Some(group)
+    );
+    // A group that doesn't exist.
+    assert_eq!(
+        store
+            .get_resource_group(address, struct_tag("OtherGroup"), 0)
+            .unwrap(),
+        None
+    );
+    // A value that is not a resource group.
+    assert!(matches!(
+        store
+            .get_resource_group(address, struct_tag("CorruptedGroup"), 0)
+            .unwrap_err()
+            .downcast_ref::<AptosDbError>(),
+        Some(AptosDbError::InvalidResourceGroup(_, _))
+    ));
+}
+
+#[test]
+fn test_shard_id_for_key() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key = StateKey::raw(String::from("test_key").into_bytes());
+    let value = StateValue::from(String::from("test_value").into_bytes());
+    let value_set: HashMap<_, _> = std::iter::once((key.clone(), Some(value.clone()))).collect();
+    let ledger_batch = SchemaBatch::new();
+    let sharded_state_kv_batches = new_sharded_kv_schema_batch();
+    store
+        .put_value_sets(
+            vec![&value_set],
+            0,
+            StateStorageUsage::new_untracked(),
+            &ledger_batch,
+            &sharded_state_kv_batches,
+        )
+        .unwrap();
+
+    // Commit the batches one by one, the value only shows up once the batch of its shard is
+    // written.
+    let shard_id = store.shard_id_for_key(&key) as usize;
+    for (i, batch) in sharded_state_kv_batches.into_iter().enumerate() {
+        store.state_kv_db.commit_raw_batch(batch).unwrap();
+        let value_opt = store
+            .state_kv_db
+            .metadata_db()
+            .get::<StateValueSchema>(&(key.clone(), 0))
+            .unwrap()
+            .flatten();
+        if i < shard_id {
+            assert!(value_opt.is_none());
+        } else {
+            assert_eq!(value_opt, Some(value.clone()));
+        }
+    }
+}
+
+#[test]
+fn test_export_snapshot_to_dir() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    let input: HashMap<_, _> = (0..5u8)
+        .map(|i| (StateKey::raw(vec![i]), StateValue::from(vec![i, i])))
+        .collect();
+    init_store(store, input.clone().into_iter());
+    let version = (input.len() - 1) as Version;
+
+    let export_dir = TempPath::new();
+    let manifest = store
+        .export_snapshot_to_dir(version, export_dir.path(), 2)
+        .unwrap();
+    assert_eq!(manifest.root_hash, store.get_root_hash(version).unwrap());
+    assert_eq!(manifest.num_values, 5);
+    assert_eq!(manifest.chunks.len(), 3);
+    let manifest_bytes = std::fs::read(export_dir.path().join(MANIFEST_FILE_NAME)).unwrap();
+    assert_eq!(
+        bcs::from_bytes::<ExportManifest>(&manifest_bytes).unwrap(),
+        manifest
+    );
+
+    let mut exported = HashMap::new();
+    for exported_chunk in &manifest.chunks {
+        let chunk_bytes = std::fs::read(export_dir.path().join(&exported_chunk.file_name)).unwrap();
+        let chunk: StateValueChunkWithProof = bcs::from_bytes(&chunk_bytes).unwrap();
+        assert_eq!(chunk.first_index, exported_chunk.first_index);
+        assert_eq!(chunk.last_index, exported_chunk.last_index);
+        exported.extend(chunk.raw_values);
+    }
+    assert_eq!(exported, input);
+
+    // A value that doesn't match the tree fails the export before the manifest is written.
+    let (key, _) = store
+        .get_value_chunk_with_proof(version, 3, 1)
+        .unwrap()
+        .raw_values[0]
+        .clone();
+    let batch = SchemaBatch::new();
+    for version in 0..=version {
+        batch
+            .put::<StateValueSchema>(
+                &(key.clone(), version),
+                &Some(StateValue::from(b"corrupted".to_vec())),
+            )
+            .unwrap();
+    }
+    store
+        .state_kv_db
+        .db_shard(key.get_shard_id())
+        .write_schemas(batch)
+        .unwrap();
+    let export_dir = TempPath::new();
+    assert!(store
+        .export_snapshot_to_dir(version, export_dir.path(), 2)
+        .is_err());
+    assert!(!export_dir.path().join(MANIFEST_FILE_NAME).exists());
+}
+
+#[test]
+fn test_get_value_by_leaf_hash() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(String::from("test_key1").into_bytes());
+    let key2 = StateKey::raw(String::from("test_key2").into_bytes());
+    let key3 = StateKey::raw(String::from("test_key3").into_bytes());
+    let value1 = StateValue::from(String::from("test_val1").into_bytes());
+    let value2 = StateValue::from(String::from("test_val2").into_bytes());
+    let value3 = StateValue::from(String::from("test_val3").into_bytes());
+    put_value_set(
+        store,
+        vec![
+            (key1, value1),
+            (key2.clone(), value2.clone()),
+            (key3, value3),
+        ],
+        0,
+        None,
+    );
+
+    let (_, proof) = store
+        .get_state_value_with_proof_by_version_ext(&key2, 0)
+        .unwrap();
+    let leaf_hash = proof.leaf().unwrap().hash();
+    assert_eq!(
+        store.get_value_by_leaf_hash(leaf_hash, 0).unwrap(),
+        Some((key2, value2))
+    );
+    assert!(store
+        .get_value_by_leaf_hash(HashValue::random(), 0)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_verify_value_against_merkle() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key = StateKey::raw(b"key".to_vec());
+    let value0 = StateValue::from(b"value0".to_vec());
+    let value1 = StateValue::from(b"value1".to_vec());
+    put_value_set(store, vec![(key.clone(), value0.clone())], 0, None);
+    put_value_set(store, vec![(key.clone(), value1.clone())], 1, Some(0));
+
+    assert!(store.verify_value_against_merkle(&key, &value0, 0).unwrap());
+    assert!(!store.verify_value_against_merkle(&key, &value1, 0).unwrap());
+    assert!(store.verify_value_against_merkle(&key, &value1, 1).unwrap());
+    assert!(!store.verify_value_against_merkle(&key, &value0, 1).unwrap());
+    // A key that doesn't exist.
+    let other_key = StateKey::raw(b"other_key".to_vec());
+    assert!(!store
+        .verify_value_against_merkle(&other_key, &value0, 1)
+        .unwrap());
+}
+
+#[test]
+fn test_get_state_value_with_epoch_anchored_proof() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key = StateKey::raw(String::from("test_key").into_bytes());
+    let value = StateValue::from(String::from("test_value").into_bytes());
+    let root = put_value_set(store, vec![(key.clone(), value.clone())], 0, None);
+
+    let (value_opt, proof, epoch_ending_proof) = store
+        .get_state_value_with_epoch_anchored_proof(&key, 0)
+        .unwrap();
+    assert_eq!(value_opt, Some(value));
+    proof.verify(root, key.hash(), value_opt.as_ref()).unwrap();
+    // No epoch has ended yet, so the version is anchored to genesis.
+    assert_eq!(epoch_ending_proof.epoch, 0);
+    assert!(epoch_ending_proof
+        .previous_epoch_ending_ledger_info
+        .is_none());
+}
+
+#[test]
+fn test_get_block_changed_keys() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(b"key1".to_vec());
+    let key2 = StateKey::raw(b"key2".to_vec());
+    let key3 = StateKey::raw(b"key3".to_vec());
+    let value = StateValue::from(b"value".to_vec());
+
+    put_value_set(
+        store,
+        vec![(key1.clone(), value.clone()), (key2.clone(), value.clone())],
+        0,
+        None,
+    );
+    put_value_set(
+        store,
+        vec![(key2.clone(), value.clone()), (key3.clone(), value.clone())],
+        1,
+        Some(0),
+    );
+    put_value_set(store, vec![(key2.clone(), value)], 2, Some(1));
+    // The keys are read from the write sets in the ledger.
+    for (version, keys) in [
+        vec![key1.clone(), key2.clone()],
+        vec![key2.clone(), key3.clone()],
+        vec![key2.clone()],
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let write_set = WriteSetMut::new(
+            keys.into_iter()
+                .map(|key| (key, WriteOp::Modification(b"value".to_vec())))
+                .collect(),
+        )
+        .freeze()
+        .unwrap();
+        store
+            .ledger_db
+            .put::<WriteSetSchema>(&(version as Version), &write_set)
+            .unwrap();
+    }
+
+    assert_eq!(
+        store.get_block_changed_keys(0, 0).unwrap(),
+        HashSet::from([key1.clone(), key2.clone()])
+    );
+    assert_eq!(
+        store.get_block_changed_keys(1, 2).unwrap(),
+        HashSet::from([key2.clone(), key3.clone()])
+    );
+    assert_eq!(
+        store.get_block_changed_keys(0, 2).unwrap(),
+        HashSet::from([key1, key2, key3])
+    );
+    assert!(store.get_block_changed_keys(3, 5).unwrap().is_empty());
+    assert!(store.get_block_changed_keys(2, 1).is_err());
+}
+
 #[test]
 pub fn test_get_state_snapshot_before() {
     let tmp_dir = TempPath::new();
@@ -279,65 +768,2568 @@ pub fn test_get_state_snapshot_before() {
     assert_eq!(store.get_state_snapshot_before(0).unwrap(), None,);
 }
 
-proptest! {
-    #![proptest_config(ProptestConfig::with_cases(10))]
-
-    #[test]
-    fn test_get_account_iter(
-        input in hash_map(any::<StateKey>(), any::<StateValue>(), 1..200)
-    ) {
-        // Convert to a vector so iteration order becomes deterministic.
-        let kvs: Vec<_> = input.into_iter().collect();
-
-        let tmp_dir = TempPath::new();
-        let db = AptosDB::new_for_test(&tmp_dir);
-        let store = &db.state_store;
-        init_store(store, kvs.clone().into_iter());
+struct ZeroValueLeafHasher;
 
-        // Test iterator at each version.
-        for i in 0..kvs.len() {
-            let actual_values = db
-                .get_backup_handler()
-                .get_account_iter(i as Version)
-                .unwrap()
-                .collect::<Result<Vec<_>>>()
-                .unwrap();
-            let mut expected_values: Vec<_> = kvs[..=i]
-                .iter()
-                .map(|(key, value)| (key.clone(), value.clone()))
-                .collect();
-            expected_values.sort_unstable_by_key(|item| item.0.hash());
-            prop_assert_eq!(actual_values, expected_values);
-        }
+impl LeafHasher for ZeroValueLeafHasher {
+    fn hash_key(&self, state_key: &StateKey) -> HashValue {
+        state_key.hash()
     }
 
-    #[test]
-    fn test_raw_restore(
-        (input, batch1_size) in hash_map(any::<StateKey>(), any::<StateValue>(), 2..1000)
-            .prop_flat_map(|input| {
-                let len = input.len();
-                (Just(input), 1..len)
-            })
-    ) {
-        let tmp_dir1 = TempPath::new();
-        let db1 = AptosDB::new_for_test(&tmp_dir1);
-        let store1 = &db1.state_store;
-        init_store(store1, input.clone().into_iter());
+    fn hash_value(&self, _state_value: &StateValue) -> HashValue {
+        HashValue::zero()
+    }
+}
 
-        let version = (input.len() - 1) as Version;
-        let expected_root_hash = store1.get_root_hash(version).unwrap();
+#[test]
+fn test_merklize_value_set_with_hasher() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
 
-        let tmp_dir2 = TempPath::new();
-        let db2 = AptosDB::new_for_test(&tmp_dir2);
-        let store2 = &db2.state_store;
+    let value_set: HashMap<_, _> = vec![
+        (
+            StateKey::raw(b"key1".to_vec()),
+            Some(StateValue::from(b"value1".to_vec())),
+        ),
+        (
+            StateKey::raw(b"key2".to_vec()),
+            Some(StateValue::from(b"value2".to_vec())),
+        ),
+    ]
+    .into_iter()
+    .collect();
+    let default_root = store
+        .merklize_value_set(jmt_update_refs(&jmt_updates(&value_set)), None, 0, None)
+        .unwrap();
 
-        let mut restore =
-            StateSnapshotRestore::new(&store2.state_merkle_db, store2, version, expected_root_hash, true /* async_commit */).unwrap();
+    // The default hasher reproduces the regular merklization.
+    let root = store
+        .merklize_value_set_with_hasher(&value_set, &DefaultLeafHasher, 1, None)
+        .unwrap();
+    assert_eq!(root, default_root);
 
-        let mut ordered_input: Vec<_> = input
-            .into_iter()
-            .collect();
-        ordered_input.sort_unstable_by_key(|(key, _value)| key.hash());
+    let root = store
+        .merklize_value_set_with_hasher(&value_set, &ZeroValueLeafHasher, 2, None)
+        .unwrap();
+    assert_ne!(root, default_root);
+    assert_eq!(store.get_root_hash(2).unwrap(), root);
+}
+
+#[test]
+fn test_get_snapshot_root_hashes() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    assert_eq!(store.get_snapshot_root_hashes(&[]).unwrap(), vec![]);
+    assert_eq!(store.get_snapshot_root_hashes(&[0]).unwrap(), vec![None]);
+
+    let key = StateKey::raw(b"key".to_vec());
+    let root0 = put_value_set(
+        store,
+        vec![(key.clone(), StateValue::from(b"value0".to_vec()))],
+        0,
+        None,
+    );
+    let usage = store.get_usage(Some(0)).unwrap();
+    store
+        .ledger_db
+        .put::<VersionDataSchema>(&1, &usage.into())
+        .unwrap();
+    let root2 = put_value_set(
+        store,
+        vec![(key, StateValue::from(b"value2".to_vec()))],
+        2,
+        Some(0),
+    );
+    assert_ne!(root0, root2);
+
+    assert_eq!(
+        store.get_snapshot_root_hashes(&[2, 1, 0, 3]).unwrap(),
+        vec![Some(root2), None, Some(root0), None],
+    );
+}
+
+#[test]
+fn test_find_version_for_root_hash() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key = StateKey::raw(b"key".to_vec());
+    let value0 = StateValue::from(b"value0".to_vec());
+    let root0 = put_value_set(store, vec![(key.clone(), value0.clone())], 0, None);
+    // Version 1 is not a snapshot.
+    let usage = store.get_usage(Some(0)).unwrap();
+    store
+        .ledger_db
+        .put::<VersionDataSchema>(&1, &usage.into())
+        .unwrap();
+    let root2 = put_value_set(
+        store,
+        vec![(key.clone(), StateValue::from(b"value2".to_vec()))],
+        2,
+        Some(0),
+    );
+    // Version 3 has the same state as version 0.
+    assert_eq!(put_value_set(store, vec![(key, value0)], 3, Some(2)), root0);
+
+    // The latest match in the range is returned.
+    assert_eq!(
+        store.find_version_for_root_hash(root0, 0..4).unwrap(),
+        Some(3)
+    );
+    assert_eq!(
+        store.find_version_for_root_hash(root0, 0..3).unwrap(),
+        Some(0)
+    );
+    assert_eq!(store.find_version_for_root_hash(root0, 1..3).unwrap(), None);
+    assert_eq!(
+        store.find_version_for_root_hash(root2, 1..3).unwrap(),
+        Some(2)
+    );
+    // Version 1 has the same state as version 0, but only snapshots are checked.
+    assert_eq!(store.find_version_for_root_hash(root0, 1..2).unwrap(), None);
+    assert_eq!(
+        store
+            .find_version_for_root_hash(HashValue::random(), 0..4)
+            .unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_get_subtree_hash() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key = StateKey::raw(b"key".to_vec());
+    let root = put_value_set(
+        store,
+        vec![(key.clone(), StateValue::from(b"value".to_vec()))],
+        0,
+        None,
+    );
+    assert_eq!(
+        store
+            .get_subtree_hash(NibblePath::new_even(vec![]), 0)
+            .unwrap(),
+        root
+    );
+
+    // The only leaf is the root, so it's the whole subtree under its own path and nothing is under
+    // any other path.
+    let key_path = NibblePath::new_even(key.hash().to_vec());
+    let mut prefix = NibblePath::new_even(vec![]);
+    prefix.push(key_path.get_nibble(0));
+    assert_eq!(store.get_subtree_hash(prefix.clone(), 0).unwrap(), root);
+    prefix.push(key_path.get_nibble(1));
+    assert_eq!(store.get_subtree_hash(prefix, 0).unwrap(), root);
+
+    let mut other_prefix = NibblePath::new_even(vec![]);
+    other_prefix.push(Nibble::from((u8::from(key_path.get_nibble(0)) + 1) % 16));
+    assert_eq!(
+        store.get_subtree_hash(other_prefix, 0).unwrap(),
+        *SPARSE_MERKLE_PLACEHOLDER_HASH
+    );
+}
+
+#[test]
+fn test_first_differing_subtree() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(b"key1".to_vec());
+    let key2 = StateKey::raw(b"key2".to_vec());
+    let key3 = StateKey::raw(b"key3".to_vec());
+    put_value_set(
+        store,
+        vec![
+            (key1.clone(), StateValue::from(b"value1".to_vec())),
+            (key2, StateValue::from(b"value2".to_vec())),
+            (key3, StateValue::from(b"value3".to_vec())),
+        ],
+        0,
+        None,
+    );
+    put_value_set(
+        store,
+        vec![(key1.clone(), StateValue::from(b"value4".to_vec()))],
+        1,
+        Some(0),
+    );
+    assert_eq!(store.first_differing_subtree(0, 0).unwrap(), None);
+
+    // Only key1 changed, so the differing subtree is on its path.
+    let nibble_path = store.first_differing_subtree(0, 1).unwrap().unwrap();
+    let key1_path = NibblePath::new_even(key1.hash().to_vec());
+    assert!(nibble_path
+        .nibbles()
+        .zip(key1_path.nibbles())
+        .all(|(a, b)| a == b));
+    assert_ne!(
+        store.get_subtree_hash(nibble_path.clone(), 0).unwrap(),
+        store.get_subtree_hash(nibble_path, 1).unwrap(),
+    );
+}
+
+#[test]
+fn test_compact_state_dbs() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key = StateKey::raw(b"key".to_vec());
+    let value = StateValue::from(b"value".to_vec());
+    let root = put_value_set(store, vec![(key.clone(), value.clone())], 0, None);
+
+    store.compact_state_kv(None).unwrap();
+    store.compact_state_kv(Some(0)).unwrap();
+    store.compact_state_merkle(None).unwrap();
+    store.compact_state_merkle(Some(15)).unwrap();
+    assert!(store.compact_state_kv(Some(16)).is_err());
+    assert!(store.compact_state_merkle(Some(16)).is_err());
+
+    assert_eq!(store.get_root_hash(0).unwrap(), root);
+    assert_eq!(
+        store.get_state_value_by_version(&key, 0).unwrap(),
+        Some(value)
+    );
+}
+
+#[test]
+fn test_get_value_range_proof_by_index() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    put_value_set(
+        store,
+        (0..3u8)
+            .map(|i| (StateKey::raw(vec![i]), StateValue::from(vec![i])))
+            .collect(),
+        0,
+        None,
+    );
+    for (first_index, last_index) in [(0, 0), (0, 1), (1, 2), (0, 2)] {
+        let chunk = store
+            .get_value_chunk_with_proof(0, first_index, last_index - first_index + 1)
+            .unwrap();
+        assert_eq!(
+            store
+                .get_value_range_proof_by_index(0, first_index, last_index)
+                .unwrap(),
+            chunk.proof,
+        );
+    }
+    assert!(store.get_value_range_proof_by_index(0, 1, 0).is_err());
+    assert!(store.get_value_range_proof_by_index(0, 0, 3).is_err());
+}
+
+#[test]
+fn test_get_value_chunk_cursor() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    put_value_set(
+        store,
+        (0..50u8)
+            .map(|i| (StateKey::raw(vec![i]), StateValue::from(vec![i])))
+            .collect(),
+        0,
+        None,
+    );
+    let mut cursor = store.get_value_chunk_cursor(0, 3).unwrap();
+    while cursor.next_index() < 50 {
+        let first_index = cursor.next_index();
+        let chunk = cursor.next_chunk(7).unwrap();
+        assert_eq!(
+            chunk,
+            store.get_value_chunk_with_proof(0, first_index, 7).unwrap()
+        );
+        assert_eq!(cursor.next_index(), chunk.last_index as usize + 1);
+    }
+    let err = cursor.next_chunk(7).unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<AptosDbError>(),
+        Some(AptosDbError::NotFound(_))
+    ));
+    assert!(store
+        .get_value_chunk_cursor(0, 50)
+        .unwrap()
+        .next_chunk(7)
+        .is_err());
+}
+
+#[test]
+fn test_get_value_chunk_cursor_after_error() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    put_value_set(
+        store,
+        (0..20u8)
+            .map(|i| (StateKey::raw(vec![i]), StateValue::from(vec![i])))
+            .collect(),
+        0,
+        None,
+    );
+    // Drop the value at index 10, in the middle of the second chunk.
+    let (key, _) = store
+        .get_value_chunk_with_proof(0, 10, 1)
+        .unwrap()
+        .raw_values[0]
+        .clone();
+    let batch = SchemaBatch::new();
+    batch.delete::<StateValueSchema>(&(key.clone(), 0)).unwrap();
+    store
+        .state_kv_db
+        .db_shard(key.get_shard_id())
+        .write_schemas(batch)
+        .unwrap();
+
+    let mut cursor = store.get_value_chunk_cursor(0, 0).unwrap();
+    cursor.next_chunk(7).unwrap();
+    assert!(cursor.next_chunk(7).is_err());
+    assert_eq!(cursor.next_index(), 7);
+    // The leaves of the failed chunk were consumed, so the cursor refuses to go on instead of
+    // returning the third chunk as if it started at index 7.
+    let err = cursor.next_chunk(7).unwrap_err();
+    assert!(err.downcast_ref::<AptosDbError>().is_none());
+    assert_eq!(cursor.next_index(), 7);
+}
+
+#[test]
+fn test_top_accounts_by_storage() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let address1 = AccountAddress::new([1u8; AccountAddress::LENGTH]);
+    let address2 = AccountAddress::new([2u8; AccountAddress::LENGTH]);
+    let address3 = AccountAddress::new([3u8; AccountAddress::LENGTH]);
+    let key = |address, path: &[u8]| StateKey::access_path(AccessPath::new(address, path.to_vec()));
+    let value = |len| StateValue::from(vec![0u8; len]);
+    put_value_set(
+        store,
+        vec![
+            (key(address1, b"a"), value(10)),
+            (key(address1, b"b"), value(20)),
+            (key(address2, b"a"), value(100)),
+            (key(address3, b"a"), value(5)),
+            (key(address3, b"b"), value(5)),
+            (
+                StateKey::table_item(TableHandle(address3), vec![0]),
+                value(1000),
+            ),
+        ],
+        0,
+        None,
+    );
+
+    let size = |len| value(len).size() as u64;
+    assert_eq!(store.top_accounts_by_storage(0, 2).unwrap(), vec![
+        (address2, size(100)),
+        (address1, size(10) + size(20)),
+    ]);
+    assert_eq!(store.top_accounts_by_storage(0, 10).unwrap().len(), 3);
+    assert!(store.top_accounts_by_storage(0, 0).unwrap().is_empty());
+}
+
+#[test]
+fn test_pin_framework_cache() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let framework_key = StateKey::access_path(AccessPath::new(
+        AccountAddress::ONE,
+        b"framework_key".to_vec(),
+    ));
+    let other_key =
+        StateKey::access_path(AccessPath::new(AccountAddress::TWO, b"other_key".to_vec()));
+    let value1 = StateValue::from(String::from("value1").into_bytes());
+    let value2 = StateValue::from(String::from("value2").into_bytes());
+    put_value_set(
+        store,
+        vec![
+            (framework_key.clone(), value1.clone()),
+            (other_key.clone(), value1.clone()),
+        ],
+        0,
+        None,
+    );
+    set_overall_commit_progress(store, 0);
+
+    // Only the latest committed version can be pinned.
+    assert!(store.pin_framework_cache(1).is_err());
+    store.pin_framework_cache(0).unwrap();
+    assert_eq!(
+        store.pinned_value_cache.get(&framework_key, 0),
+        Some(Some(value1.clone()))
+    );
+    assert_eq!(store.pinned_value_cache.get(&other_key, 0), None);
+
+    // Until the committed writes are applied, the new version is read from the DB.
+    put_value_set(
+        store,
+        vec![(framework_key.clone(), value2.clone())],
+        1,
+        Some(0),
+    );
+    set_overall_commit_progress(store, 1);
+    assert_eq!(store.pinned_value_cache.get(&framework_key, 1), None);
+    assert_eq!(
+        store.get_state_value_by_version(&framework_key, 1).unwrap(),
+        Some(value2.clone())
+    );
+
+    // Applying a write to a pinned key refreshes the pinned values, older versions are read from
+    // the DB.
+    let value_set = HashMap::from([(framework_key.clone(), Some(value2.clone()))]);
+    store.update_pinned_values(&[&value_set], 1);
+    assert_eq!(store.pinned_value_cache.get(&framework_key, 0), None);
+    assert_eq!(
+        store.pinned_value_cache.get(&framework_key, 1),
+        Some(Some(value2.clone()))
+    );
+    assert_eq!(
+        store.get_state_value_by_version(&framework_key, 0).unwrap(),
+        Some(value1)
+    );
+    assert_eq!(
+        store.get_state_value_by_version(&framework_key, 1).unwrap(),
+        Some(value2.clone())
+    );
+    // The values at version 0 are stale by now.
+    assert!(store.pin_framework_cache(0).is_err());
+
+    // Versions not touching a framework key extend the pinned values.
+    put_value_set(store, vec![(other_key.clone(), value2.clone())], 2, Some(1));
+    set_overall_commit_progress(store, 2);
+    let value_set = HashMap::from([(other_key, Some(value2.clone()))]);
+    store.update_pinned_values(&[&value_set], 2);
+    assert_eq!(
+        store.pinned_value_cache.get(&framework_key, 2),
+        Some(Some(value2))
+    );
+
+    // Missing the writes of some versions drops the pinned values.
+    store.update_pinned_values(&[&HashMap::new()], 4);
+    assert_eq!(store.pinned_value_cache.get(&framework_key, 2), None);
+    assert_eq!(store.pinned_value_cache.get(&framework_key, 4), None);
+}
+
+#[test]
+fn test_pin_framework_cache_ignores_failed_put() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let framework_key = StateKey::access_path(AccessPath::new(
+        AccountAddress::ONE,
+        b"framework_key".to_vec(),
+    ));
+    let value1 = StateValue::from(String::from("value1").into_bytes());
+    let value2 = StateValue::from(String::from("value2").into_bytes());
+    put_value_set(
+        store,
+        vec![(framework_key.clone(), value1.clone())],
+        0,
+        None,
+    );
+    set_overall_commit_progress(store, 0);
+    store.pin_framework_cache(0).unwrap();
+
+    // The usage check fails, so nothing is committed and the pinned values are untouched.
+    let value_set = HashMap::from([(framework_key.clone(), Some(value2))]);
+    assert!(store
+        .put_value_sets(
+            vec![&value_set],
+            1,
+            StateStorageUsage::zero(),
+            &SchemaBatch::new(),
+            &new_sharded_kv_schema_batch(),
+        )
+        .is_err());
+    assert_eq!(
+        store.pinned_value_cache.get(&framework_key, 0),
+        Some(Some(value1))
+    );
+    assert_eq!(store.pinned_value_cache.get(&framework_key, 1), None);
+}
+
+#[test]
+fn test_pin_framework_cache_cleared_on_reset_and_restore() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let framework_key = StateKey::access_path(AccessPath::new(
+        AccountAddress::ONE,
+        b"framework_key".to_vec(),
+    ));
+    let value = StateValue::from(String::from("value").into_bytes());
+    put_value_set(store, vec![(framework_key.clone(), value.clone())], 0, None);
+    set_overall_commit_progress(store, 0);
+
+    store.pin_framework_cache(0).unwrap();
+    assert!(store.pinned_value_cache.get(&framework_key, 0).is_some());
+    store.reset();
+    assert_eq!(store.pinned_value_cache.get(&framework_key, 0), None);
+
+    store.pin_framework_cache(0).unwrap();
+    assert!(store.pinned_value_cache.get(&framework_key, 0).is_some());
+    store
+        .write_kv_batch(
+            1,
+            &StateValueBatch::new(),
+            StateSnapshotProgress::new(HashValue::zero(), StateStorageUsage::zero()),
+        )
+        .unwrap();
+    assert_eq!(store.pinned_value_cache.get(&framework_key, 0), None);
+}
+
+fn set_overall_commit_progress(store: &StateStore, version: Version) {
+    store
+        .ledger_db
+        .put::<DbMetadataSchema>(
+            &DbMetadataKey::OverallCommitProgress,
+            &DbMetadataValue::Version(version),
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_get_state_value_consistent_while_committing() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    let key = StateKey::raw(String::from("test_key").into_bytes());
+    let num_versions: Version = 50;
+    assert_eq!(
+        store
+            .get_state_value_consistent(&key, ReadConsistency::Committed)
+            .unwrap(),
+        None
+    );
+
+    let committing = AtomicBool::new(true);
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            for version in 0..num_versions {
+                let value = StateValue::from(version.to_be_bytes().to_vec());
+                put_value_set(
+                    store,
+                    vec![(key.clone(), value)],
+                    version,
+                    version.checked_sub(1),
+                );
+                // The version is only committed once the overall commit progress reaches it.
+                set_overall_commit_progress(store, version);
+            }
+            committing.store(false, Ordering::SeqCst);
+        });
+
+        // A committed read never goes back, and never sees the version being committed.
+        let mut last_read_version = None;
+        while committing.load(Ordering::SeqCst) {
+            let value = store
+                .get_state_value_consistent(&key, ReadConsistency::Committed)
+                .unwrap();
+            let read_version =
+                value.map(|value| Version::from_be_bytes(value.bytes().try_into().unwrap()));
+            let progress = store
+                .ledger_db
+                .get::<DbMetadataSchema>(&DbMetadataKey::OverallCommitProgress)
+                .unwrap()
+                .map(|progress| progress.expect_version());
+            assert!(read_version >= last_read_version);
+            assert!(read_version <= progress);
+            last_read_version = read_version;
+        }
+    });
+
+    let value = store
+        .get_state_value_consistent(&key, ReadConsistency::Committed)
+        .unwrap()
+        .unwrap();
+    assert_eq!(value.bytes(), (num_versions - 1).to_be_bytes());
+}
+
+#[test]
+fn test_get_state_value_if_fresh() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    let commit = |version| {
+        store
+            .ledger_db
+            .put::<DbMetadataSchema>(
+                &DbMetadataKey::OverallCommitProgress,
+                &DbMetadataValue::Version(version),
+            )
+            .unwrap();
+    };
+    let is_stale = |res: Result<Option<StateValue>>, expected_last_write, expected_min_version| {
+        matches!(
+            res.unwrap_err().downcast_ref::<AptosDbError>(),
+            Some(AptosDbError::StaleStateValue(last_write, min_version))
+                if *last_write == expected_last_write && *min_version == expected_min_version
+        )
+    };
+
+    let key = StateKey::raw(String::from("test_key").into_bytes());
+    let value1 = StateValue::from(String::from("value1").into_bytes());
+    let value2 = StateValue::from(String::from("value2").into_bytes());
+    put_value_set(store, vec![(key.clone(), value1.clone())], 0, None);
+    // Nothing is committed yet.
+    assert!(is_stale(store.get_state_value_if_fresh(&key, 0), None, 0));
+    commit(0);
+    assert_eq!(
+        store.get_state_value_if_fresh(&key, 0).unwrap(),
+        Some(value1)
+    );
+
+    // The write at version 1 is not committed yet, so the value read would be older.
+    put_value_set(store, vec![(key.clone(), value2.clone())], 1, Some(0));
+    assert!(is_stale(
+        store.get_state_value_if_fresh(&key, 1),
+        Some(0),
+        1
+    ));
+    commit(1);
+    assert_eq!(
+        store.get_state_value_if_fresh(&key, 1).unwrap(),
+        Some(value2)
+    );
+
+    // A key that was never written is never fresh.
+    let unknown_key = StateKey::raw(String::from("unknown_key").into_bytes());
+    assert!(is_stale(
+        store.get_state_value_if_fresh(&unknown_key, 0),
+        None,
+        0
+    ));
+}
+
+#[test]
+fn test_disk_space_guard() {
+    let tmp_dir = TempPath::new();
+    tmp_dir.create_as_dir().unwrap();
+
+    // A disabled guard doesn't read the free space.
+    STATE_KV_FREE_BYTES.set(-1);
+    DiskSpaceGuard::new(tmp_dir.path(), 0).check().unwrap();
+    assert_eq!(STATE_KV_FREE_BYTES.get(), -1);
+
+    // Writes are allowed while the free space is above the threshold, which is reported.
+    DiskSpaceGuard::new(tmp_dir.path(), 1).check().unwrap();
+    assert!(STATE_KV_FREE_BYTES.get() >= 0);
+
+    // And refused once it's below.
+    let error = DiskSpaceGuard::new(tmp_dir.path(), u64::MAX)
+        .check()
+        .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<AptosDbError>(),
+        Some(AptosDbError::InsufficientDiskSpace(_, u64::MAX))
+    ));
+}
+
+#[test]
+fn test_degrade_on_state_merkle_errors() {
+    let tmp_dir = TempPath::new();
+    let mut db = AptosDB::new_for_test(&tmp_dir);
+    Arc::get_mut(&mut db.state_store)
+        .unwrap()
+        .degrade_on_state_merkle_errors = true;
+    let store = &db.state_store;
+
+    // Write a value without merklizing it, as if the state merkle DB lost the tree.
+    let key = StateKey::raw(String::from("test_key").into_bytes());
+    let value = StateValue::from(String::from("test_value").into_bytes());
+    let value_set: HashMap<_, _> = [(key.clone(), Some(value.clone()))].into_iter().collect();
+    let ledger_batch = SchemaBatch::new();
+    let sharded_state_kv_batches = new_sharded_kv_schema_batch();
+    store
+        .put_value_sets(
+            vec![&value_set],
+            0,
+            StateStorageUsage::new_untracked(),
+            &ledger_batch,
+            &sharded_state_kv_batches,
+        )
+        .unwrap();
+    store.ledger_db.write_schemas(ledger_batch).unwrap();
+    store
+        .state_kv_db
+        .commit(0, sharded_state_kv_batches)
+        .unwrap();
+
+    // The value is still served. The missing tree is not an IO error, so proofs fail as is.
+    assert_eq!(
+        store.get_state_value_by_version(&key, 0).unwrap(),
+        Some(value)
+    );
+    let num_errors = STATE_MERKLE_UNAVAILABLE_ERRORS.get();
+    for error in [
+        store.get_state_proof_by_version_ext(&key, 0).unwrap_err(),
+        store
+            .get_state_value_with_proof_by_version_ext(&key, 0)
+            .unwrap_err(),
+    ] {
+        assert!(error.downcast_ref::<AptosDbError>().is_none());
+    }
+    assert_eq!(STATE_MERKLE_UNAVAILABLE_ERRORS.get(), num_errors);
+
+    // IO errors are reported with a typed error, also when wrapped in a context.
+    let num_errors = STATE_MERKLE_UNAVAILABLE_ERRORS.get();
+    let io_error = || anyhow::Error::from(std::io::Error::from(std::io::ErrorKind::Other));
+    for error in [io_error(), io_error().context("reading node")] {
+        let error = store.state_merkle_error(error);
+        assert!(matches!(
+            error.downcast_ref::<AptosDbError>(),
+            Some(AptosDbError::MerkleUnavailable(_))
+        ));
+    }
+    assert!(STATE_MERKLE_UNAVAILABLE_ERRORS.get() >= num_errors + 2);
+
+    // Other errors are returned as is.
+    let error = store.state_merkle_error(AptosDbError::NotFound("node".to_string()).into());
+    assert!(matches!(
+        error.downcast_ref::<AptosDbError>(),
+        Some(AptosDbError::NotFound(_))
+    ));
+}
+
+#[test]
+fn test_error_on_pruned_state_value_reads() {
+    let tmp_dir = TempPath::new();
+    let mut db = AptosDB::new_for_test(&tmp_dir);
+    let key = StateKey::raw(String::from("test_key").into_bytes());
+    let value0 = StateValue::from(String::from("value0").into_bytes());
+    let value1 = StateValue::from(String::from("value1").into_bytes());
+    put_value_set(
+        &db.state_store,
+        vec![(key.clone(), value0.clone())],
+        0,
+        None,
+    );
+    put_value_set(
+        &db.state_store,
+        vec![(key.clone(), value1.clone())],
+        1,
+        Some(0),
+    );
+    // Mark version 0 as pruned, while its value is still there.
+    db.state_store.state_kv_pruner.pruner().record_progress(1);
+    assert_eq!(
+        db.state_store.get_state_value_by_version(&key, 0).unwrap(),
+        Some(value0)
+    );
+
+    Arc::get_mut(&mut db.state_store)
+        .unwrap()
+        .error_on_pruned_state_value_reads = true;
+    let store = &db.state_store;
+    assert!(matches!(
+        store
+            .get_state_value_by_version(&key, 0)
+            .unwrap_err()
+            .downcast_ref::<AptosDbError>(),
+        Some(AptosDbError::VersionPruned(0, 1))
+    ));
+    assert_eq!(
+        store.get_state_value_by_version(&key, 1).unwrap(),
+        Some(value1)
+    );
+    // A key that doesn't exist at a readable version is still reported as absent.
+    let other_key = StateKey::raw(String::from("other_key").into_bytes());
+    assert_eq!(
+        store.get_state_value_by_version(&other_key, 1).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_find_orphaned_state_values() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(String::from("test_key1").into_bytes());
+    let key2 = StateKey::raw(String::from("test_key2").into_bytes());
+    let value1_v0 = StateValue::from(String::from("value1_v0").into_bytes());
+    let value1_v1 = StateValue::from(String::from("value1_v1").into_bytes());
+    let value2_v0 = StateValue::from(String::from("value2_v0").into_bytes());
+    put_value_set(
+        store,
+        vec![(key1.clone(), value1_v0), (key2, value2_v0)],
+        0,
+        None,
+    );
+    put_value_set(store, vec![(key1.clone(), value1_v1)], 1, Some(0));
+    assert!(store.find_orphaned_state_values(1).unwrap().is_empty());
+
+    // Lose the stale index of the old value of key1, so it's never pruned.
+    let batch = SchemaBatch::new();
+    batch
+        .delete::<StaleStateValueIndexSchema>(&StaleStateValueIndex {
+            stale_since_version: 1,
+            version: 0,
+            state_key: key1.clone(),
+        })
+        .unwrap();
+    store
+        .state_kv_db
+        .db_shard(store.shard_id_for_key(&key1))
+        .write_schemas(batch)
+        .unwrap();
+    assert_eq!(store.find_orphaned_state_values(1).unwrap(), vec![(
+        key1, 0
+    )]);
+}
+
+#[test]
+fn test_iter_stale_values_with_sizes() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(String::from("test_key1").into_bytes());
+    let key2 = StateKey::raw(String::from("test_key2").into_bytes());
+    let value1_v0 = StateValue::from(vec![1u8; 10]);
+    let value1_v1 = StateValue::from(vec![1u8; 20]);
+    let value2_v0 = StateValue::from(vec![2u8; 30]);
+    put_value_set(
+        store,
+        vec![
+            (key1.clone(), value1_v0.clone()),
+            (key2.clone(), value2_v0.clone()),
+        ],
+        0,
+        None,
+    );
+    put_value_set(store, vec![(key1.clone(), value1_v1)], 1, Some(0));
+    put_value_set(
+        store,
+        vec![(key2.clone(), StateValue::from(vec![2u8; 40]))],
+        2,
+        Some(1),
+    );
+
+    let stale_values = |up_to_version| {
+        let mut stale_values = store
+            .iter_stale_values_with_sizes(up_to_version)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        stale_values.sort();
+        stale_values
+    };
+    assert!(stale_values(0).is_empty());
+    let size1 = bcs::serialized_size(&Some(value1_v0)).unwrap();
+    assert_eq!(stale_values(1), vec![(key1.clone(), 0, size1)]);
+    let size2 = bcs::serialized_size(&Some(value2_v0)).unwrap();
+    assert_eq!(stale_values(2), vec![(key1, 0, size1), (key2, 0, size2)]);
+}
+
+#[test]
+fn test_count_pending_stale_state_values() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key = StateKey::raw(String::from("test_key1").into_bytes());
+    put_value_set(
+        store,
+        vec![(key.clone(), StateValue::from(vec![1u8]))],
+        0,
+        None,
+    );
+    put_value_set(
+        store,
+        vec![(key.clone(), StateValue::from(vec![2u8]))],
+        1,
+        Some(0),
+    );
+    put_value_set(store, vec![(key, StateValue::from(vec![3u8]))], 2, Some(1));
+
+    assert_eq!(store.count_pending_stale_state_values(0).unwrap(), 0);
+    assert_eq!(store.count_pending_stale_state_values(1).unwrap(), 1);
+    assert_eq!(store.count_pending_stale_state_values(2).unwrap(), 2);
+
+    let counts =
+        StateStore::count_pending_stale_state_values_by_shard(&store.state_kv_db, 2).unwrap();
+    assert_eq!(counts.iter().map(|(_, count)| count).sum::<u64>(), 2);
+}
+
+#[test]
+fn test_verify_shard_routing() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::open(
+        &tmp_dir,
+        false, /* readonly */
+        NO_OP_STORAGE_PRUNER_CONFIG,
+        RocksdbConfigs {
+            use_state_kv_db: true,
+            ..Default::default()
+        },
+        false, /* enable_indexer */
+        DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+        StateStoreOptions::default(),
+    )
+    .unwrap();
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(String::from("test_key1").into_bytes());
+    let key2 = StateKey::raw(String::from("test_key2").into_bytes());
+    let value = StateValue::from(String::from("test_val").into_bytes());
+    put_value_set(
+        store,
+        vec![(key1.clone(), value.clone()), (key2, value.clone())],
+        0,
+        None,
+    );
+    assert!(store.verify_shard_routing(0).unwrap().is_empty());
+
+    // Move the value of key1 to another shard.
+    let expected_shard = key1.get_shard_id();
+    let wrong_shard = (expected_shard + 1) % NUM_STATE_SHARDS as u8;
+    let db_key = (key1.clone(), 0);
+    store
+        .state_kv_db
+        .db_shard(expected_shard)
+        .delete::<StateValueSchema>(&db_key)
+        .unwrap();
+    store
+        .state_kv_db
+        .db_shard(wrong_shard)
+        .put::<StateValueSchema>(&db_key, &Some(value))
+        .unwrap();
+    assert_eq!(store.verify_shard_routing(0).unwrap(), vec![(
+        key1,
+        expected_shard,
+        wrong_shard
+    )]);
+
+    // The value is lost.
+    store
+        .state_kv_db
+        .db_shard(wrong_shard)
+        .delete::<StateValueSchema>(&db_key)
+        .unwrap();
+    assert!(store.verify_shard_routing(0).is_err());
+}
+
+#[test]
+fn test_get_state_value_with_shard() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(String::from("test_key1").into_bytes());
+    let key2 = StateKey::raw(String::from("test_key2").into_bytes());
+    let value1_v0 = StateValue::from(String::from("value1_v0").into_bytes());
+    let value1_v1 = StateValue::from(String::from("value1_v1").into_bytes());
+    put_value_set(store, vec![(key1.clone(), value1_v0.clone())], 0, None);
+    put_value_set(store, vec![(key1.clone(), value1_v1.clone())], 1, Some(0));
+
+    let shard_id = key1.get_shard_id();
+    assert_eq!(
+        store.get_state_value_with_shard(&key1, 0).unwrap(),
+        (shard_id, Some(value1_v0))
+    );
+    assert_eq!(
+        store.get_state_value_with_shard(&key1, 1).unwrap(),
+        (shard_id, Some(value1_v1))
+    );
+    // A key that doesn't exist is looked up in its shard all the same.
+    assert_eq!(
+        store.get_state_value_with_shard(&key2, 1).unwrap(),
+        (key2.get_shard_id(), None)
+    );
+}
+
+#[test]
+fn test_get_state_value_with_version_by_version_with_lower_bound() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    // A key with thousands of versions, written at every other version and deleted at 1000.
+    let key = StateKey::raw(b"key".to_vec());
+    let value_at = |version: Version| StateValue::from(version.to_be_bytes().to_vec());
+    let batch = SchemaBatch::new();
+    for version in (0..4000).step_by(2) {
+        let value = (version != 1000).then(|| value_at(version));
+        batch
+            .put::<StateValueSchema>(&(key.clone(), version), &value)
+            .unwrap();
+    }
+    store
+        .state_kv_db
+        .db_shard(key.get_shard_id())
+        .write_schemas(batch)
+        .unwrap();
+
+    assert_eq!(
+        store
+            .get_state_value_with_version_by_version_with_lower_bound(&key, 3999, Some(3998))
+            .unwrap(),
+        Some((3998, value_at(3998)))
+    );
+    // A hint that's too recent falls back to the older versions.
+    assert_eq!(
+        store
+            .get_state_value_with_version_by_version_with_lower_bound(&key, 3001, Some(3001))
+            .unwrap(),
+        Some((3000, value_at(3000)))
+    );
+    // A deletion within the bound is returned as such.
+    assert_eq!(
+        store
+            .get_state_value_with_version_by_version_with_lower_bound(&key, 1001, Some(500))
+            .unwrap(),
+        None
+    );
+    for version in [0, 1, 999, 1000, 1001, 3998, 5000] {
+        let expected = store
+            .get_state_value_with_version_by_version(&key, version)
+            .unwrap();
+        for lower_bound_version in [0, 1, version / 2, version, version + 1] {
+            assert_eq!(
+                store
+                    .get_state_value_with_version_by_version_with_lower_bound(
+                        &key,
+                        version,
+                        Some(lower_bound_version),
+                    )
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn test_get_values_at_previous_snapshot() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(String::from("test_key1").into_bytes());
+    let key2 = StateKey::raw(String::from("test_key2").into_bytes());
+    let value1_v0 = StateValue::from(String::from("value1_v0").into_bytes());
+    let value1_v1 = StateValue::from(String::from("value1_v1").into_bytes());
+    let value2_v1 = StateValue::from(String::from("value2_v1").into_bytes());
+    assert!(store
+        .get_values_at_previous_snapshot(&[key1.clone()], 0)
+        .is_err());
+
+    put_value_set(store, vec![(key1.clone(), value1_v0.clone())], 0, None);
+    put_value_set(
+        store,
+        vec![(key1.clone(), value1_v1), (key2.clone(), value2_v1)],
+        1,
+        Some(0),
+    );
+    assert_eq!(
+        store
+            .get_values_at_previous_snapshot(&[key1, key2], 1)
+            .unwrap(),
+        (0, vec![Some(value1_v0), None])
+    );
+}
+
+#[test]
+fn test_get_state_values_by_version() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    // Enough keys to spread over all shards.
+    let keys: Vec<_> = (0..64)
+        .map(|i| StateKey::raw(format!("test_key{}", i).into_bytes()))
+        .collect();
+    let value = |i: usize, version: Version| {
+        StateValue::from(format!("value{}_v{}", i, version).into_bytes())
+    };
+    put_value_set(
+        store,
+        keys.iter()
+            .enumerate()
+            .map(|(i, key)| (key.clone(), value(i, 0)))
+            .collect(),
+        0,
+        None,
+    );
+    put_value_set(
+        store,
+        keys.iter()
+            .enumerate()
+            .step_by(2)
+            .map(|(i, key)| (key.clone(), value(i, 1)))
+            .collect(),
+        1,
+        Some(0),
+    );
+
+    // Out of order, with a repeated key and a nonexistent one.
+    let mut query_keys: Vec<_> = keys.iter().rev().cloned().collect();
+    query_keys.push(keys[3].clone());
+    query_keys.push(StateKey::raw(String::from("nonexistent_key").into_bytes()));
+    for version in 0..2 {
+        let values = store
+            .get_state_values_by_version(&query_keys, version)
+            .unwrap();
+        let expected: Vec<_> = query_keys
+            .iter()
+            .map(|key| store.get_state_value_by_version(key, version).unwrap())
+            .collect();
+        assert_eq!(values, expected);
+        assert_eq!(values[0], Some(value(63, 0)));
+        assert_eq!(values[64], Some(value(3, 0)));
+        assert_eq!(values[65], None);
+    }
+    let values = store.get_state_values_by_version(&keys[..2], 1).unwrap();
+    assert_eq!(values, vec![Some(value(0, 1)), Some(value(1, 0))]);
+    assert!(store
+        .get_state_values_by_version(&[], 1)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_get_state_values_with_proofs_by_version() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(String::from("test_key1").into_bytes());
+    let key2 = StateKey::raw(String::from("test_key2").into_bytes());
+    let key3 = StateKey::raw(String::from("test_key3").into_bytes());
+    let value1_v0 = StateValue::from(String::from("value1_v0").into_bytes());
+    let value1_v1 = StateValue::from(String::from("value1_v1").into_bytes());
+    let value2_v0 = StateValue::from(String::from("value2_v0").into_bytes());
+    put_value_set(
+        store,
+        vec![(key1.clone(), value1_v0), (key2.clone(), value2_v0.clone())],
+        0,
+        None,
+    );
+    put_value_set(store, vec![(key1.clone(), value1_v1.clone())], 1, Some(0));
+
+    // With a repeated key and a nonexistent one.
+    let keys = vec![key2.clone(), key1.clone(), key3.clone(), key2];
+    let values_with_proofs = store
+        .get_state_values_with_proofs_by_version(&keys, 1)
+        .unwrap();
+    let expected: Vec<_> = keys
+        .iter()
+        .map(|key| {
+            store
+                .get_state_value_with_proof_by_version_ext(key, 1)
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(values_with_proofs, expected);
+    assert_eq!(values_with_proofs[0].0, Some(value2_v0.clone()));
+    assert_eq!(values_with_proofs[1].0, Some(value1_v1));
+    assert_eq!(values_with_proofs[2].0, None);
+    assert_eq!(values_with_proofs[3].0, Some(value2_v0));
+    assert!(store
+        .get_state_values_with_proofs_by_version(&[], 1)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_pinned_reader() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::open(
+        &tmp_dir,
+        false, /* readonly */
+        PrunerConfig {
+            ledger_pruner_config: LedgerPrunerConfig {
+                enable: true,
+                prune_window: 0,
+                batch_size: 1,
+                user_pruning_window_offset: 0,
+            },
+            ..NO_OP_STORAGE_PRUNER_CONFIG
+        },
+        RocksdbConfigs::default(),
+        false, /* enable_indexer */
+        DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+        StateStoreOptions::default(),
+    )
+    .unwrap();
+    let store = &db.state_store;
+
+    let key = StateKey::raw(String::from("test_key").into_bytes());
+    let value = |version: Version| StateValue::from(format!("value_v{}", version).into_bytes());
+    for version in 0..3 {
+        put_value_set(
+            store,
+            vec![(key.clone(), value(version))],
+            version,
+            version.checked_sub(1),
+        );
+    }
+    assert!(store.pinned_reader(3).is_err());
+
+    // The pinned version stays readable while the pruner catches up with later versions.
+    let reader = store.pinned_reader(1).unwrap();
+    store.maybe_set_state_kv_pruner_target(2);
+    store.state_kv_pruner.wait_for_pruner().unwrap();
+    assert_eq!(reader.version(), 1);
+    assert_eq!(reader.get_state_value(&key).unwrap(), Some(value(1)));
+    assert_eq!(reader.get_state_values(&[key.clone()]).unwrap(), vec![
+        Some(value(1))
+    ]);
+    assert_eq!(store.get_state_value_by_version(&key, 0).unwrap(), None);
+    assert!(store.pinned_reader(0).is_err());
+
+    // Dropping the reader releases the pin.
+    drop(reader);
+    store.maybe_set_state_kv_pruner_target(3);
+    store.state_kv_pruner.wait_for_pruner().unwrap();
+    assert_eq!(store.get_state_value_by_version(&key, 1).unwrap(), None);
+    assert_eq!(
+        store.get_state_value_by_version(&key, 2).unwrap(),
+        Some(value(2))
+    );
+    assert!(store.pinned_reader(1).is_err());
+}
+
+#[test]
+fn test_reconstruct_value_at_pruned_version() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(String::from("test_key1").into_bytes());
+    let key2 = StateKey::raw(String::from("test_key2").into_bytes());
+    let key3 = StateKey::raw(String::from("test_key3").into_bytes());
+    let value1_v0 = StateValue::from(String::from("value1_v0").into_bytes());
+    let value2_v0 = StateValue::from(String::from("value2_v0").into_bytes());
+    // Version 0 is the only snapshot, later versions only have write sets in the ledger.
+    put_value_set(
+        store,
+        vec![(key1.clone(), value1_v0), (key2.clone(), value2_v0.clone())],
+        0,
+        None,
+    );
+    let write_set = WriteSetMut::new(vec![
+        (key1.clone(), WriteOp::Modification(b"value1_v1".to_vec())),
+        (key3.clone(), WriteOp::Deletion),
+    ])
+    .freeze()
+    .unwrap();
+    store
+        .ledger_db
+        .put::<WriteSetSchema>(&1, &write_set)
+        .unwrap();
+    store
+        .ledger_db
+        .put::<WriteSetSchema>(&2, &WriteSet::default())
+        .unwrap();
+
+    assert_eq!(
+        store.reconstruct_value_at_pruned_version(&key1, 2).unwrap(),
+        Some(StateValue::from(b"value1_v1".to_vec()))
+    );
+    assert_eq!(
+        store.reconstruct_value_at_pruned_version(&key2, 2).unwrap(),
+        Some(value2_v0)
+    );
+    assert_eq!(
+        store.reconstruct_value_at_pruned_version(&key3, 2).unwrap(),
+        None
+    );
+    // The write set at version 3 is missing.
+    assert!(store.reconstruct_value_at_pruned_version(&key1, 3).is_err());
+}
+
+#[test]
+fn test_oldest_retained_epoch_snapshot() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    assert_eq!(store.epoch_snapshot_retention(), 0);
+
+    let key = StateKey::raw(String::from("test_key").into_bytes());
+    let value = StateValue::from(String::from("test_val").into_bytes());
+    put_value_set(store, vec![(key.clone(), value.clone())], 0, None);
+    put_value_set(store, vec![(key.clone(), value.clone())], 1, Some(0));
+    put_value_set(store, vec![(key, value)], 2, Some(1));
+    assert_eq!(store.oldest_retained_epoch_snapshot().unwrap(), None);
+
+    // Epochs end at versions 1 and 2, and at 3 which has no snapshot yet.
+    let batch = SchemaBatch::new();
+    for (epoch, version) in [(0, 1), (1, 2), (2, 3)] {
+        batch.put::<EpochByVersionSchema>(&version, &epoch).unwrap();
+    }
+    store.ledger_db.write_schemas(batch).unwrap();
+    assert_eq!(store.oldest_retained_epoch_snapshot().unwrap(), Some(1));
+
+    store.epoch_snapshot_pruner.testonly_update_min_version(2);
+    assert_eq!(store.oldest_retained_epoch_snapshot().unwrap(), Some(2));
+    store.epoch_snapshot_pruner.testonly_update_min_version(3);
+    assert_eq!(store.oldest_retained_epoch_snapshot().unwrap(), None);
+}
+
+#[test]
+fn test_get_proof_depths() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let keys: Vec<_> = (0..10u8).map(|i| StateKey::raw(vec![i])).collect();
+    let value = StateValue::from(String::from("test_val").into_bytes());
+    put_value_set(
+        store,
+        keys.iter()
+            .map(|key| (key.clone(), value.clone()))
+            .collect(),
+        0,
+        None,
+    );
+
+    let depths = store.get_proof_depths(&keys, 0).unwrap();
+    assert_eq!(depths.len(), keys.len());
+    for (key, depth) in keys.iter().zip(depths) {
+        let (_, proof) = store.state_merkle_db.get_with_proof_ext(key, 0).unwrap();
+        assert_eq!(depth, proof.siblings().len());
+        assert!(depth > 0);
+    }
+    assert!(store.get_proof_depths(&keys, 1).is_err());
+}
+
+#[test]
+fn test_compute_balanced_splits() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let value = StateValue::from(String::from("test_val").into_bytes());
+    put_value_set(
+        store,
+        (0..10u8)
+            .map(|i| (StateKey::raw(vec![i]), value.clone()))
+            .collect(),
+        0,
+        None,
+    );
+    let mut all_keys: Vec<_> = store
+        .get_state_key_and_value_iter(0, HashValue::zero())
+        .unwrap()
+        .map(|res| res.unwrap().0)
+        .collect();
+    all_keys.sort_by_key(|key| key.hash());
+
+    for num_splits in 1..=10 {
+        let splits = store.compute_balanced_splits(0, num_splits).unwrap();
+        assert_eq!(splits.len(), num_splits - 1);
+
+        let mut keys = vec![];
+        let starts = std::iter::once(HashValue::zero()).chain(splits.iter().copied());
+        let ends = splits.iter().map(Some).chain(std::iter::once(None));
+        for (start, end) in starts.zip(ends) {
+            let range_keys: Vec<_> = store
+                .get_state_key_and_value_iter(0, start)
+                .unwrap()
+                .map(|res| res.unwrap().0)
+                .take_while(|key| end.map_or(true, |end| key.hash() < *end))
+                .collect();
+            assert!(range_keys.len() >= 10 / num_splits);
+            assert!(range_keys.len() <= (10 + num_splits - 1) / num_splits);
+            keys.extend(range_keys);
+        }
+        assert_eq!(keys, all_keys);
+    }
+    assert!(store.compute_balanced_splits(0, 0).is_err());
+    assert!(store.compute_balanced_splits(0, 11).is_err());
+}
+
+#[test]
+fn test_stream_state_values() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let value = StateValue::from(String::from("test_val").into_bytes());
+    put_value_set(
+        store,
+        (0..10u8)
+            .map(|i| (StateKey::raw(vec![i]), value.clone()))
+            .collect(),
+        0,
+        None,
+    );
+
+    for start_hashed_key in [HashValue::zero(), StateKey::raw(vec![5]).hash()] {
+        let expected: Vec<_> = store
+            .get_state_key_and_value_iter(0, start_hashed_key)
+            .unwrap()
+            .map(|res| res.unwrap())
+            .collect();
+        let streamed: Vec<_> = block_on(
+            store
+                .stream_state_values(0, start_hashed_key)
+                .map(|res| res.unwrap())
+                .collect(),
+        );
+        assert_eq!(streamed, expected);
+    }
+    let mut stream = store.stream_state_values(1, HashValue::zero());
+    assert!(block_on(stream.next()).unwrap().is_err());
+}
+
+#[test]
+fn test_get_state_key_and_value_iter_rev() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let value = StateValue::from(String::from("test_val").into_bytes());
+    put_value_set(
+        store,
+        (0..10u8)
+            .map(|i| (StateKey::raw(vec![i]), value.clone()))
+            .collect(),
+        0,
+        None,
+    );
+    let all: Vec<_> = store
+        .get_state_key_and_value_iter(0, HashValue::zero())
+        .unwrap()
+        .map(|res| res.unwrap())
+        .collect();
+
+    for start_hashed_key in [
+        HashValue::new([0xFF; HashValue::LENGTH]),
+        StateKey::raw(vec![5]).hash(),
+        HashValue::zero(),
+    ] {
+        let expected: Vec<_> = all
+            .iter()
+            .rev()
+            .filter(|(key, _)| key.hash() <= start_hashed_key)
+            .cloned()
+            .collect();
+        let items: Vec<_> = store
+            .get_state_key_and_value_iter_rev(0, start_hashed_key)
+            .unwrap()
+            .map(|res| res.unwrap())
+            .collect();
+        assert_eq!(items, expected);
+    }
+    assert!(store
+        .get_state_key_and_value_iter_rev(1, HashValue::zero())
+        .is_err());
+}
+
+#[test]
+fn test_get_value_history() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key = StateKey::raw(String::from("test_key").into_bytes());
+    let other_key = StateKey::raw(String::from("other_key").into_bytes());
+    let value1 = StateValue::from(String::from("test_val1").into_bytes());
+    let value2 = StateValue::from(String::from("test_val2").into_bytes());
+    put_value_set(store, vec![(other_key.clone(), value1.clone())], 0, None);
+    put_value_set(store, vec![(key.clone(), value1.clone())], 1, Some(0));
+    put_value_set(store, vec![(key.clone(), value2.clone())], 3, Some(1));
+    // Delete the key at version 5.
+    store
+        .state_kv_db
+        .db_shard(key.get_shard_id())
+        .put::<StateValueSchema>(&(key.clone(), 5), &None)
+        .unwrap();
+    put_value_set(store, vec![(key.clone(), value1.clone())], 8, Some(3));
+
+    let versions = [6, 0, 3, 9, 2, 1, 5, 4, 100, 7, 8, 3];
+    let history = store.get_value_history(&key, &versions).unwrap();
+    let expected = vec![
+        None,
+        None,
+        Some(value2.clone()),
+        Some(value1.clone()),
+        Some(value1.clone()),
+        Some(value1.clone()),
+        None,
+        Some(value2.clone()),
+        Some(value1.clone()),
+        None,
+        Some(value1.clone()),
+        Some(value2),
+    ];
+    assert_eq!(history, expected);
+    for (version, value) in versions.iter().zip(history) {
+        assert_eq!(
+            store.get_state_value_by_version(&key, *version).unwrap(),
+            value
+        );
+    }
+    assert!(store.get_value_history(&key, &[]).unwrap().is_empty());
+    assert_eq!(store.get_value_history(&other_key, &[0, 9]).unwrap(), vec![
+        Some(value1.clone()),
+        Some(value1)
+    ]);
+}
+
+#[test]
+fn test_get_value_and_previous() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key = StateKey::raw(String::from("test_key").into_bytes());
+    let value1 = StateValue::from(String::from("test_val1").into_bytes());
+    let value2 = StateValue::from(String::from("test_val2").into_bytes());
+    put_value_set(store, vec![(key.clone(), value1.clone())], 0, None);
+    put_value_set(store, vec![(key.clone(), value2.clone())], 3, Some(0));
+    // Rewriting the same value is not a change.
+    put_value_set(store, vec![(key.clone(), value2.clone())], 5, Some(3));
+    // Delete the key at version 7.
+    store
+        .state_kv_db
+        .db_shard(key.get_shard_id())
+        .put::<StateValueSchema>(&(key.clone(), 7), &None)
+        .unwrap();
+    put_value_set(store, vec![(key.clone(), value1.clone())], 8, Some(5));
+
+    let missing_key = StateKey::raw(String::from("missing_key").into_bytes());
+    assert_eq!(
+        store.get_value_and_previous(&missing_key, 9).unwrap(),
+        (None, None)
+    );
+    assert_eq!(
+        store.get_value_and_previous(&key, 1).unwrap(),
+        (Some(value1.clone()), None)
+    );
+    assert_eq!(
+        store.get_value_and_previous(&key, 4).unwrap(),
+        (Some(value2.clone()), Some((0, value1.clone())))
+    );
+    assert_eq!(
+        store.get_value_and_previous(&key, 6).unwrap(),
+        (Some(value2.clone()), Some((0, value1.clone())))
+    );
+    assert_eq!(
+        store.get_value_and_previous(&key, 7).unwrap(),
+        (None, Some((5, value2)))
+    );
+    // The key didn't exist before it was written again.
+    assert_eq!(
+        store.get_value_and_previous(&key, 9).unwrap(),
+        (Some(value1), None)
+    );
+}
+
+#[test]
+fn test_get_batch_absence_proof() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let value = StateValue::from(String::from("test_val").into_bytes());
+    let root = put_value_set(
+        store,
+        (0..20u8)
+            .map(|i| (StateKey::raw(vec![i]), value.clone()))
+            .collect(),
+        0,
+        None,
+    );
+    let absent_keys: Vec<_> = (20..30u8).map(|i| StateKey::raw(vec![i])).collect();
+    let proofs = store.get_batch_absence_proof(&absent_keys, 0).unwrap();
+    assert_eq!(proofs.len(), absent_keys.len());
+    for (key, proof) in absent_keys.iter().zip(proofs) {
+        proof.verify::<StateValue>(root, key.hash(), None).unwrap();
+        assert_eq!(
+            proof,
+            store.state_merkle_db.get_with_proof_ext(key, 0).unwrap().1
+        );
+    }
+    assert!(store.get_batch_absence_proof(&[], 0).unwrap().is_empty());
+
+    // One of the keys exists.
+    let mut keys = absent_keys;
+    keys.push(StateKey::raw(vec![3]));
+    assert!(store.get_batch_absence_proof(&keys, 0).is_err());
+}
+
+#[test]
+fn test_get_key_index() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let value = StateValue::from(String::from("test_val").into_bytes());
+    put_value_set(
+        store,
+        (0..20u8)
+            .map(|i| (StateKey::raw(vec![i]), value.clone()))
+            .collect(),
+        0,
+        None,
+    );
+    let keys: Vec<_> = store
+        .get_state_key_and_value_iter(0, HashValue::zero())
+        .unwrap()
+        .map(|res| res.unwrap().0)
+        .collect();
+    assert_eq!(keys.len(), 20);
+    for (index, key) in keys.iter().enumerate() {
+        assert_eq!(store.get_key_index(key, 0).unwrap(), Some(index));
+        let (hashed_key, _) =
+            JellyfishMerkleIterator::new_by_index(Arc::clone(&store.state_merkle_db), 0, index)
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap();
+        assert_eq!(hashed_key, key.hash());
+    }
+    assert_eq!(
+        store.get_key_index(&StateKey::raw(vec![20]), 0).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_get_prefix_content_hash() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    let address = AccountAddress::new([12u8; AccountAddress::LENGTH]);
+    let other_address = AccountAddress::new([22u8; AccountAddress::LENGTH]);
+    let prefix = StateKeyPrefix::from(address);
+
+    let key1 = StateKey::access_path(AccessPath::new(address, b"state_key1".to_vec()));
+    let key2 = StateKey::access_path(AccessPath::new(other_address, b"state_key2".to_vec()));
+    let value1 = StateValue::from(String::from("value1").into_bytes());
+    let value1_v2 = StateValue::from(String::from("value1_v2").into_bytes());
+    let value2 = StateValue::from(String::from("value2").into_bytes());
+
+    put_value_set(store, vec![(key1.clone(), value1)], 0, None);
+    // Writes outside of the prefix don't change the hash.
+    put_value_set(store, vec![(key2, value2)], 1, Some(0));
+    let hash_v0 = store.get_prefix_content_hash(&prefix, 0).unwrap();
+    assert_eq!(store.get_prefix_content_hash(&prefix, 1).unwrap(), hash_v0);
+
+    put_value_set(store, vec![(key1, value1_v2)], 2, Some(1));
+    let hash_v2 = store.get_prefix_content_hash(&prefix, 2).unwrap();
+    assert_ne!(hash_v2, hash_v0);
+    // Older versions still hash to what they did before.
+    assert_eq!(store.get_prefix_content_hash(&prefix, 0).unwrap(), hash_v0);
+
+    // An empty prefix hashes differently from a non-empty one.
+    let empty_prefix = StateKeyPrefix::from(AccountAddress::new([32u8; AccountAddress::LENGTH]));
+    assert_ne!(
+        store.get_prefix_content_hash(&empty_prefix, 2).unwrap(),
+        hash_v2
+    );
+}
+
+#[test]
+fn test_export_jmt_structure() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let value_set: Vec<_> = (0..20u8)
+        .map(|i| (StateKey::raw(vec![i]), StateValue::from(vec![i])))
+        .collect();
+    let root_hash = put_value_set(store, value_set, 0, None);
+
+    // Depth 0 only returns the root.
+    let dump = store.export_jmt_structure(0, 0).unwrap();
+    assert_eq!(dump.nodes.len(), 1);
+    let root = &dump.nodes[0];
+    assert_eq!(root.nibble_path, NibblePath::new_even(vec![]));
+    assert_eq!(root.node_type, JmtNodeKind::Internal);
+    assert_eq!(root.hash, root_hash);
+    let dump = store.export_jmt_structure(0, 1).unwrap();
+    assert_eq!(dump.nodes.len(), root.num_children + 1);
+
+    // Deep enough to reach all the leaves.
+    let dump = store.export_jmt_structure(0, ROOT_NIBBLE_HEIGHT).unwrap();
+    let leaves: Vec<_> = dump
+        .nodes
+        .iter()
+        .filter(|node| node.node_type == JmtNodeKind::Leaf)
+        .collect();
+    assert_eq!(leaves.len(), 20);
+    assert!(leaves.iter().all(|leaf| leaf.num_children == 0));
+    let num_children: usize = dump.nodes.iter().map(|node| node.num_children).sum();
+    assert_eq!(num_children, dump.nodes.len() - 1);
+    for node in &dump.nodes {
+        assert_eq!(
+            store.get_subtree_hash(node.nibble_path.clone(), 0).unwrap(),
+            node.hash
+        );
+    }
+}
+
+#[test]
+fn test_get_incremental_state_proof() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(String::from("test_key1").into_bytes());
+    let key2 = StateKey::raw(String::from("test_key2").into_bytes());
+    let key3 = StateKey::raw(String::from("test_key3").into_bytes());
+    let value1_v0 = StateValue::from(String::from("value1_v0").into_bytes());
+    let value1_v1 = StateValue::from(String::from("value1_v1").into_bytes());
+    let value2_v0 = StateValue::from(String::from("value2_v0").into_bytes());
+    let value3_v1 = StateValue::from(String::from("value3_v1").into_bytes());
+    let root_v0 = put_value_set(
+        store,
+        vec![(key1.clone(), value1_v0), (key2.clone(), value2_v0)],
+        0,
+        None,
+    );
+    let root_v1 = put_value_set(
+        store,
+        vec![
+            (key1.clone(), value1_v1.clone()),
+            (key3.clone(), value3_v1.clone()),
+        ],
+        1,
+        Some(0),
+    );
+
+    let proof = store
+        .get_incremental_state_proof(0, 1, &[key1.clone(), key3.clone()])
+        .unwrap();
+    let updated_values: Vec<_> = proof
+        .updates
+        .iter()
+        .map(|(key, value, _)| (key.clone(), value.clone()))
+        .collect();
+    assert_eq!(updated_values, vec![
+        (key1.clone(), Some(value1_v1)),
+        (key3.clone(), Some(value3_v1)),
+    ]);
+    // The client can verify it with the root hashes it trusts.
+    proof.verify(root_v0, root_v1).unwrap();
+    assert!(proof.verify(root_v0, root_v0).is_err());
+
+    // The changed keys must cover all the changes.
+    assert!(store.get_incremental_state_proof(0, 1, &[key1]).is_err());
+    assert!(store
+        .get_incremental_state_proof(0, 1, &[key2, key3])
+        .is_err());
+    // Nothing changes from a version to itself.
+    store.get_incremental_state_proof(1, 1, &[]).unwrap();
+}
+
+#[test]
+fn test_write_kv_batch_in_sub_batches() {
+    let tmp_dir = TempPath::new();
+    let mut db = AptosDB::new_for_test(&tmp_dir);
+    Arc::get_mut(&mut db.state_store)
+        .unwrap()
+        .max_state_values_per_restore_batch = 2;
+    let store = &db.state_store;
+
+    let kv_batch: StateValueBatch = (0..5u8)
+        .map(|i| ((StateKey::raw(vec![i]), 0), Some(StateValue::from(vec![i]))))
+        .collect();
+    let progress = StateSnapshotProgress::new(HashValue::random(), StateStorageUsage::new(5, 10));
+    store.write_kv_batch(0, &kv_batch, progress).unwrap();
+    for ((key, version), value) in &kv_batch {
+        assert_eq!(
+            store.get_state_value_by_version(key, *version).unwrap(),
+            *value
+        );
+    }
+    assert_eq!(store.get_progress(0).unwrap(), Some(progress));
+}
+
+#[test]
+fn test_max_concurrent_restores() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let expected_root_hash = HashValue::random();
+    let restore = store.get_snapshot_receiver(0, expected_root_hash).unwrap();
+    // Only one restore is allowed at a time by default.
+    assert!(store.get_snapshot_receiver(0, expected_root_hash).is_err());
+    drop(restore);
+    store.get_snapshot_receiver(0, expected_root_hash).unwrap();
+}
+
+#[test]
+fn test_get_state_value_by_timestamp() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key = StateKey::raw(b"key".to_vec());
+    let values: Vec<_> = (0..4u8).map(|i| StateValue::from(vec![i])).collect();
+    for (version, value) in values.iter().enumerate() {
+        let version = version as Version;
+        put_value_set(
+            store,
+            vec![(key.clone(), value.clone())],
+            version,
+            version.checked_sub(1),
+        );
+    }
+    // Blocks start at version 0 and 2, at timestamps 100 and 200.
+    let batch = SchemaBatch::new();
+    for (seq, (version, timestamp)) in [(0, 100), (2, 200)].into_iter().enumerate() {
+        let new_block_event = NewBlockEvent::new(
+            AccountAddress::ZERO, // hash
+            0,                    // epoch
+            seq as u64,           // round
+            seq as u64,           // height
+            vec![],               // prev block voters
+            AccountAddress::ZERO, // proposer
+            Vec::new(),           // failed_proposers
+            timestamp,
+        );
+        let event = ContractEvent::new(
+            new_block_event_key(),
+            seq as u64,
+            TypeTag::Struct(Box::new(NewBlockEvent::struct_tag())),
+            bcs::to_bytes(&new_block_event).unwrap(),
+        );
+        db.event_store
+            .put_events(version, &[event], &batch)
+            .unwrap();
+    }
+    batch
+        .put::<DbMetadataSchema>(
+            &DbMetadataKey::OverallCommitProgress,
+            &DbMetadataValue::Version(3),
+        )
+        .unwrap();
+    store.ledger_db.write_schemas(batch).unwrap();
+
+    assert!(store.get_state_value_by_timestamp(&key, 99).is_err());
+    assert_eq!(
+        store.get_state_value_by_timestamp(&key, 100).unwrap(),
+        (1, Some(values[1].clone())),
+    );
+    assert_eq!(
+        store.get_state_value_by_timestamp(&key, 199).unwrap(),
+        (1, Some(values[1].clone())),
+    );
+    // No block started after the timestamp yet, so the latest committed version answers it.
+    assert_eq!(
+        store.get_state_value_by_timestamp(&key, 200).unwrap(),
+        (3, Some(values[3].clone())),
+    );
+}
+
+fn put_value_sets_and_get_usages(
+    store: &StateStore,
+    value_state_sets: &[HashMap<StateKey, Option<StateValue>>],
+    expected_usage: StateStorageUsage,
+) -> Vec<StateStorageUsage> {
+    let ledger_batch = SchemaBatch::new();
+    let sharded_state_kv_batches = new_sharded_kv_schema_batch();
+    store
+        .put_value_sets(
+            value_state_sets.iter().collect(),
+            0,
+            expected_usage,
+            &ledger_batch,
+            &sharded_state_kv_batches,
+        )
+        .unwrap();
+    let last_version = value_state_sets.len() as Version - 1;
+    store.ledger_db.write_schemas(ledger_batch).unwrap();
+    store
+        .state_kv_db
+        .commit(last_version, sharded_state_kv_batches)
+        .unwrap();
+    (0..=last_version)
+        .map(|version| store.get_usage(Some(version)).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_put_stats_and_indices_in_sub_batches() {
+    let key1 = StateKey::raw(b"key1".to_vec());
+    let key2 = StateKey::raw(b"key2".to_vec());
+    let key3 = StateKey::raw(b"key3".to_vec());
+    let value = |v: &[u8]| Some(StateValue::from(v.to_vec()));
+    let value_state_sets = vec![
+        HashMap::from([(key1.clone(), value(b"v1")), (key2.clone(), value(b"v2"))]),
+        HashMap::from([
+            (key1.clone(), value(b"v1_update")),
+            (key3.clone(), value(b"v3")),
+        ]),
+        HashMap::from([(key2, None), (key3.clone(), value(b"v3_update"))]),
+    ];
+
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let expected_usages = put_value_sets_and_get_usages(
+        &db.state_store,
+        &value_state_sets,
+        StateStorageUsage::new_untracked(),
+    );
+    assert_eq!(expected_usages[2].items(), 2);
+
+    // With a cache of a single key, every write set is processed in its own sub-batch.
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test_with_max_num_keys_in_state_stats_cache(&tmp_dir, 1);
+    let store = &db.state_store;
+    assert_eq!(
+        store.split_by_stats_cache_capacity(&value_state_sets.iter().collect::<Vec<_>>()),
+        vec![0..1, 1..2, 2..3],
+    );
+    let usages =
+        put_value_sets_and_get_usages(store, &value_state_sets, *expected_usages.last().unwrap());
+    assert_eq!(usages, expected_usages);
+    assert_eq!(
+        store.get_state_value_by_version(&key1, 2).unwrap(),
+        value(b"v1_update")
+    );
+    assert_eq!(
+        store.get_state_value_by_version(&key3, 2).unwrap(),
+        value(b"v3_update")
+    );
+}
+
+#[test]
+fn test_usage_mismatch_policy() {
+    let key = StateKey::raw(b"key".to_vec());
+    let value = StateValue::from(b"value".to_vec());
+    let value_state_sets = vec![HashMap::from([(key.clone(), Some(value.clone()))])];
+    let wrong_usage = StateStorageUsage::new(10, 10);
+
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    assert!(db
+        .state_store
+        .put_value_sets(
+            value_state_sets.iter().collect(),
+            0,
+            wrong_usage,
+            &SchemaBatch::new(),
+            &new_sharded_kv_schema_batch(),
+        )
+        .is_err());
+
+    let tmp_dir = TempPath::new();
+    let mut db = AptosDB::new_for_test(&tmp_dir);
+    Arc::get_mut(&mut db.state_store)
+        .unwrap()
+        .usage_mismatch_policy = UsageMismatchPolicy::LogAndUseComputed;
+    let usages = put_value_sets_and_get_usages(&db.state_store, &value_state_sets, wrong_usage);
+    assert_eq!(usages, vec![StateStorageUsage::new(
+        1,
+        key.size() + value.size()
+    )]);
+}
+
+#[test]
+fn test_usage_alert() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    let alerts = Arc::new(Mutex::new(vec![]));
+    let callback: UsageAlertCallback = {
+        let alerts = Arc::clone(&alerts);
+        Arc::new(move |usage: StateStorageUsage| alerts.lock().push(usage))
+    };
+
+    let key = |i: u8| StateKey::raw(vec![i]);
+    let value = StateValue::from(b"value".to_vec());
+    put_value_set(store, vec![(key(0), value.clone())], 0, None);
+    let usage0 = store.get_usage(Some(0)).unwrap();
+    store.set_usage_alert(usage0.bytes() as u64 + 1, Arc::clone(&callback));
+
+    // Crossing the threshold fires the alert, staying over it doesn't.
+    put_value_set(store, vec![(key(1), value.clone())], 1, Some(0));
+    let usage1 = store.get_usage(Some(1)).unwrap();
+    assert_eq!(*alerts.lock(), vec![usage1]);
+    put_value_set(store, vec![(key(2), value.clone())], 2, Some(1));
+    assert_eq!(*alerts.lock(), vec![usage1]);
+
+    // A cleared alert doesn't fire.
+    let usage2 = store.get_usage(Some(2)).unwrap();
+    store.set_usage_alert(usage2.bytes() as u64 + 1, callback);
+    store.clear_usage_alert();
+    put_value_set(store, vec![(key(3), value)], 3, Some(2));
+    assert_eq!(*alerts.lock(), vec![usage1]);
+}
+
+#[test]
+fn test_get_usage_delta() {
+    let key1 = StateKey::raw(b"key1".to_vec());
+    let key2 = StateKey::raw(b"key2".to_vec());
+    let value1 = StateValue::from(b"v1".to_vec());
+    let value2 = StateValue::from(b"v2".to_vec());
+    let value_state_sets = vec![
+        HashMap::from([(key1.clone(), Some(value1.clone()))]),
+        HashMap::from([(key2.clone(), Some(value2.clone()))]),
+        HashMap::from([(key1.clone(), None)]),
+    ];
+
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    put_value_sets_and_get_usages(store, &value_state_sets, StateStorageUsage::new_untracked());
+
+    let size1 = (key1.size() + value1.size()) as i64;
+    let size2 = (key2.size() + value2.size()) as i64;
+    assert_eq!(
+        store.get_usage_delta(None, 0).unwrap(),
+        StateStorageUsageDelta {
+            items_delta: 1,
+            bytes_delta: size1,
+        }
+    );
+    assert_eq!(
+        store.get_usage_delta(Some(0), 1).unwrap(),
+        StateStorageUsageDelta {
+            items_delta: 1,
+            bytes_delta: size2,
+        }
+    );
+    assert_eq!(
+        store.get_usage_delta(Some(1), 2).unwrap(),
+        StateStorageUsageDelta {
+            items_delta: -1,
+            bytes_delta: -size1,
+        }
+    );
+    assert_eq!(
+        store.get_usage_delta(Some(2), 2).unwrap(),
+        StateStorageUsageDelta {
+            items_delta: 0,
+            bytes_delta: 0,
+        }
+    );
+    assert!(store.get_usage_delta(Some(2), 3).is_err());
+}
+
+#[test]
+fn test_put_value_sets_multi() {
+    let key1 = StateKey::raw(b"key1".to_vec());
+    let key2 = StateKey::raw(b"key2".to_vec());
+    let value1 = StateValue::from(b"v1".to_vec());
+    let value2 = StateValue::from(b"v2".to_vec());
+    let value1_update = StateValue::from(b"v1_update".to_vec());
+    // The second block updates and deletes the values of the first one.
+    let block0 = vec![
+        HashMap::from([(key1.clone(), Some(value1.clone()))]),
+        HashMap::from([(key2.clone(), Some(value2.clone()))]),
+    ];
+    let block1 = vec![HashMap::from([
+        (key1.clone(), Some(value1_update.clone())),
+        (key2.clone(), None),
+    ])];
+    let usage0 =
+        StateStorageUsage::new(2, key1.size() + value1.size() + key2.size() + value2.size());
+    let usage1 = StateStorageUsage::new(1, key1.size() + value1_update.size());
+
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    let ledger_batch = SchemaBatch::new();
+    let sharded_state_kv_batches = new_sharded_kv_schema_batch();
+    store
+        .put_value_sets_multi(
+            &[
+                (0, block0.iter().collect(), usage0),
+                (2, block1.iter().collect(), usage1),
+            ],
+            &ledger_batch,
+            &sharded_state_kv_batches,
+        )
+        .unwrap();
+    store.ledger_db.write_schemas(ledger_batch).unwrap();
+    store
+        .state_kv_db
+        .commit(2, sharded_state_kv_batches)
+        .unwrap();
+    assert_eq!(store.get_usage(Some(1)).unwrap(), usage0);
+    assert_eq!(store.get_usage(Some(2)).unwrap(), usage1);
+    assert_eq!(
+        store.get_state_value_by_version(&key1, 2).unwrap(),
+        Some(value1_update)
+    );
+    assert_eq!(
+        store.get_state_value_by_version(&key2, 1).unwrap(),
+        Some(value2)
+    );
+    assert_eq!(store.get_state_value_by_version(&key2, 2).unwrap(), None);
+
+    // A usage mismatch in the first block is still caught, with its version.
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let error = db
+        .state_store
+        .put_value_sets_multi(
+            &[
+                (0, block0.iter().collect(), usage1),
+                (2, block1.iter().collect(), usage1),
+            ],
+            &SchemaBatch::new(),
+            &new_sharded_kv_schema_batch(),
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains("at version 1 not expected"));
+
+    // The blocks must be consecutive.
+    assert!(db
+        .state_store
+        .put_value_sets_multi(
+            &[
+                (0, block0.iter().collect(), usage0),
+                (3, block1.iter().collect(), usage1),
+            ],
+            &SchemaBatch::new(),
+            &new_sharded_kv_schema_batch(),
+        )
+        .is_err());
+}
+
+#[test]
+fn test_put_value_sets_unchecked() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let key1 = StateKey::raw(b"key1".to_vec());
+    let key2 = StateKey::raw(b"key2".to_vec());
+    let value1 = StateValue::from(b"v1".to_vec());
+    let value1_update = StateValue::from(b"v1_update".to_vec());
+    let value2 = StateValue::from(b"v2".to_vec());
+    let value_state_sets = vec![
+        HashMap::from([
+            (key1.clone(), Some(value1.clone())),
+            (key2.clone(), Some(value2.clone())),
+        ]),
+        HashMap::from([(key1.clone(), Some(value1_update.clone()))]),
+        HashMap::from([(key2.clone(), None)]),
+    ];
+    let item_size = |key: &StateKey, value: &StateValue| key.size() + value.size();
+    let ledger_batch = SchemaBatch::new();
+    let sharded_state_kv_batches = new_sharded_kv_schema_batch();
+    store
+        .put_value_sets_unchecked(
+            value_state_sets.iter().collect(),
+            0,
+            StateStorageUsage::new(1, item_size(&key1, &value1_update)),
+            &ledger_batch,
+            &sharded_state_kv_batches,
+        )
+        .unwrap();
+    store.ledger_db.write_schemas(ledger_batch).unwrap();
+    store
+        .state_kv_db
+        .commit(2, sharded_state_kv_batches)
+        .unwrap();
+
+    // The usage is calculated at each version before the last one, which is the given one.
+    assert_eq!(
+        store.get_usage(Some(0)).unwrap(),
+        StateStorageUsage::new(2, item_size(&key1, &value1) + item_size(&key2, &value2)),
+    );
+    assert_eq!(
+        store.get_usage(Some(1)).unwrap(),
+        StateStorageUsage::new(
+            2,
+            item_size(&key1, &value1_update) + item_size(&key2, &value2),
+        ),
+    );
+    assert_eq!(
+        store.get_usage(Some(2)).unwrap(),
+        StateStorageUsage::new(1, item_size(&key1, &value1_update)),
+    );
+    assert_eq!(
+        store.get_state_value_by_version(&key1, 2).unwrap(),
+        Some(value1_update)
+    );
+    assert_eq!(store.get_state_value_by_version(&key2, 2).unwrap(), None);
+    // The values of key1 and key2 at version 0 and the tombstone of key2 are stale.
+    assert_eq!(store.count_pending_stale_state_values(2).unwrap(), 3);
+
+    // The given usage is trusted as is, even if it doesn't match the value sets.
+    let value_state_set = HashMap::from([(key1.clone(), Some(value1.clone()))]);
+    let ledger_batch = SchemaBatch::new();
+    let sharded_state_kv_batches = new_sharded_kv_schema_batch();
+    store
+        .put_value_sets_unchecked(
+            vec![&value_state_set],
+            3,
+            StateStorageUsage::new(10, 1000),
+            &ledger_batch,
+            &sharded_state_kv_batches,
+        )
+        .unwrap();
+    store.ledger_db.write_schemas(ledger_batch).unwrap();
+    store
+        .state_kv_db
+        .commit(3, sharded_state_kv_batches)
+        .unwrap();
+    assert_eq!(
+        store.get_usage(Some(3)).unwrap(),
+        StateStorageUsage::new(10, 1000)
+    );
+    assert_eq!(
+        store.get_state_value_by_version(&key1, 3).unwrap(),
+        Some(value1)
+    );
+    // The old value of key1 is not read from the DB, so it is never marked stale.
+    assert_eq!(store.count_pending_stale_state_values(3).unwrap(), 3);
+}
+
+#[test]
+fn test_put_stats_and_indices_stale_index_batches() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+
+    let keys: Vec<_> = (0..100)
+        .map(|i| StateKey::raw(format!("key{}", i).into_bytes()))
+        .collect();
+    let value_of = |i: u8| StateValue::from(vec![i; 10]);
+    put_value_set(
+        store,
+        keys[..50]
+            .iter()
+            .map(|key| (key.clone(), value_of(0)))
+            .collect(),
+        0,
+        None,
+    );
+    // Updates, deletions and re-creations, of keys both in the DB and new, across all shards.
+    let value_state_sets = vec![
+        keys.iter()
+            .step_by(2)
+            .map(|key| (key.clone(), Some(value_of(1))))
+            .collect::<HashMap<_, _>>(),
+        keys.iter()
+            .step_by(3)
+            .map(|key| (key.clone(), None))
+            .collect::<HashMap<_, _>>(),
+        keys.iter()
+            .step_by(5)
+            .map(|key| (key.clone(), Some(value_of(3))))
+            .collect::<HashMap<_, _>>(),
+    ];
+    let sharded_state_kv_batches = new_sharded_kv_schema_batch();
+    store
+        .put_stats_and_indices(
+            &value_state_sets.iter().collect::<Vec<_>>(),
+            1,
+            &[(value_state_sets.len(), StateStorageUsage::new_untracked())],
+            &SchemaBatch::new(),
+            &sharded_state_kv_batches,
+        )
+        .unwrap();
+
+    // The same stale indices put one by one in version order.
+    let expected_batches = new_sharded_kv_schema_batch();
+    let mut live_versions: HashMap<_, _> = keys[..50].iter().map(|key| (key.clone(), 0)).collect();
+    for (idx, kvs) in value_state_sets.iter().enumerate() {
+        let version = 1 + idx as Version;
+        for (key, value) in kvs {
+            let batch = &expected_batches[store.shard_id_for_key(key) as usize];
+            let old_version = if value.is_some() {
+                live_versions.insert(key.clone(), version)
+            } else {
+                batch
+                    .put::<StaleStateValueIndexSchema>(
+                        &StaleStateValueIndex {
+                            stale_since_version: version,
+                            version,
+                            state_key: key.clone(),
+                        },
+                        &(),
+                    )
+                    .unwrap();
+                live_versions.remove(key)
+            };
+            if let Some(old_version) = old_version {
+                batch
+                    .put::<StaleStateValueIndexSchema>(
+                        &StaleStateValueIndex {
+                            stale_since_version: version,
+                            version: old_version,
+                            state_key: key.clone(),
+                        },
+                        &(),
+                    )
+                    .unwrap();
+            }
+        }
+    }
+    for (batch, expected_batch) in sharded_state_kv_batches.iter().zip(expected_batches.iter()) {
+        assert_eq!(format!("{:?}", batch), format!("{:?}", expected_batch));
+    }
+}
+
+#[test]
+fn test_prime_read_cache() {
+    let tmp_dir = TempPath::new();
+    let key1 = StateKey::raw(String::from("test_key1").into_bytes());
+    let key2 = StateKey::raw(String::from("test_key2").into_bytes());
+    let leaf_node_key = {
+        let db = AptosDB::new_for_test(&tmp_dir);
+        let store = &db.state_store;
+        // Nothing is primed before the first snapshot.
+        store.prime_read_cache(&[key1.clone()], 0).unwrap();
+
+        put_value_set(
+            store,
+            vec![
+                (
+                    key1.clone(),
+                    StateValue::from(String::from("value1").into_bytes()),
+                ),
+                (key2, StateValue::from(String::from("value2").into_bytes())),
+            ],
+            0,
+            None,
+        );
+        (1..=ROOT_NIBBLE_HEIGHT)
+            .map(|num_nibbles| NodeKey::new(0, NibblePath::new_from_state_key(&key1, num_nibbles)))
+            .find(|node_key| {
+                matches!(
+                    store
+                        .state_merkle_db
+                        .get_node_option(node_key, "test")
+                        .unwrap(),
+                    Some(Node::Leaf(_))
+                )
+            })
+            .unwrap()
+    };
+
+    // Reopen the db so that the node caches start empty.
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    assert!(store.state_merkle_db.cache_enabled());
+    assert!(store
+        .state_merkle_db
+        .lru_cache()
+        .get(&leaf_node_key)
+        .is_none());
+
+    // The nodes on the proof path are loaded, while unknown keys are skipped.
+    let unknown_key = StateKey::raw(String::from("unknown_key").into_bytes());
+    store
+        .prime_read_cache(&[key1.clone(), unknown_key], 0)
+        .unwrap();
+    assert!(store
+        .state_merkle_db
+        .lru_cache()
+        .get(&leaf_node_key)
+        .is_some());
+
+    // A later version is primed from the latest snapshot before it.
+    store.prime_read_cache(&[key1], 10).unwrap();
+}
+
+/// Moves the latest version of the buffered state to `version`, without a new checkpoint.
+fn set_buffered_state_version(store: &StateStore, version: Version) {
+    let mut buffered_state = store.buffered_state().lock();
+    let mut new_state_after_checkpoint = buffered_state.current_state().clone();
+    new_state_after_checkpoint.current_version = Some(version);
+    buffered_state
+        .update(
+            None,
+            new_state_after_checkpoint,
+            false, /* sync_commit */
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_versions_since_last_snapshot() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    assert_eq!(store.versions_since_last_snapshot().unwrap(), 0);
+
+    // Nothing is persisted in the state merkle db yet.
+    set_buffered_state_version(store, 2);
+    assert_eq!(store.versions_since_last_snapshot().unwrap(), 3);
+
+    // Snapshots at versions 0 and 1.
+    let key1 = StateKey::raw(String::from("test_key1").into_bytes());
+    let key2 = StateKey::raw(String::from("test_key2").into_bytes());
+    let value = StateValue::from(String::from("test_value").into_bytes());
+    update_store(
+        store,
+        [(key1, Some(value.clone())), (key2, Some(value))].into_iter(),
+        0,
+    );
+    assert_eq!(store.versions_since_last_snapshot().unwrap(), 1);
+
+    set_buffered_state_version(store, 5);
+    assert_eq!(store.versions_since_last_snapshot().unwrap(), 4);
+}
+
+fn set_buffered_state_usage(store: &StateStore, usage: StateStorageUsage) {
+    let mut buffered_state = store.buffered_state().lock();
+    let mut new_state_after_checkpoint = buffered_state.current_state().clone();
+    new_state_after_checkpoint.current = SparseMerkleTree::new(HashValue::random(), usage);
+    buffered_state
+        .update(
+            None,
+            new_state_after_checkpoint,
+            false, /* sync_commit */
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_get_latest_usage() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let store = &db.state_store;
+    assert_eq!(store.get_latest_usage().unwrap(), StateStorageUsage::zero());
+
+    // Untracked in memory and nothing persisted.
+    set_buffered_state_usage(store, StateStorageUsage::new_untracked());
+    assert_eq!(store.get_latest_usage().unwrap(), StateStorageUsage::zero());
+
+    // Untracked in memory, falls back to the latest persisted usage.
+    for (version, items, bytes) in [(0, 1, 10), (1, 2, 20)] {
+        store
+            .ledger_db
+            .put::<VersionDataSchema>(&version, &StateStorageUsage::new(items, bytes).into())
+            .unwrap();
+    }
+    assert_eq!(
+        store.get_latest_usage().unwrap(),
+        StateStorageUsage::new(2, 20)
+    );
+
+    // Tracked in memory.
+    set_buffered_state_usage(store, StateStorageUsage::new(3, 30));
+    assert_eq!(
+        store.get_latest_usage().unwrap(),
+        StateStorageUsage::new(3, 30)
+    );
+}
+
+#[test]
+fn test_get_latest_txn_info_version() {
+    let tmp_dir = TempPath::new();
+    let key = StateKey::raw(b"key".to_vec());
+    {
+        let db = AptosDB::new_for_test(&tmp_dir);
+        let store = &db.state_store;
+        assert_eq!(store.get_latest_txn_info_version().unwrap(), None);
+        assert_eq!(store.get_latest_checkpoint_version(), None);
+
+        let root_hash = put_value_set(
+            store,
+            vec![(key.clone(), StateValue::from(b"value".to_vec()))],
+            2,
+            None,
+        );
+        for version in 0..3 {
+            let txn_info = TransactionInfo::new(
+                HashValue::random(),
+                HashValue::random(),
+                HashValue::random(),
+                (version == 2).then_some(root_hash),
+                0,
+                ExecutionStatus::Success,
+            );
+            store
+                .ledger_db
+                .put::<TransactionInfoSchema>(&version, &txn_info)
+                .unwrap();
+        }
+        assert_eq!(store.get_latest_txn_info_version().unwrap(), Some(2));
+        // The buffered state is only rebuilt from the committed snapshot on start up.
+        assert_eq!(store.get_latest_checkpoint_version(), None);
+    }
+
+    let db = AptosDB::new_for_test(&tmp_dir);
+    assert_eq!(
+        db.state_store.get_latest_txn_info_version().unwrap(),
+        Some(2)
+    );
+    assert_eq!(db.state_store.get_latest_checkpoint_version(), Some(2));
+    assert_eq!(db.get_latest_state_checkpoint_version().unwrap(), Some(2));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+
+    #[test]
+    fn test_get_account_iter(
+        input in hash_map(any::<StateKey>(), any::<StateValue>(), 1..200)
+    ) {
+        // Convert to a vector so iteration order becomes deterministic.
+        let kvs: Vec<_> = input.into_iter().collect();
+
+        let tmp_dir = TempPath::new();
+        let db = AptosDB::new_for_test(&tmp_dir);
+        let store = &db.state_store;
+        init_store(store, kvs.clone().into_iter());
+
+        // Test iterator at each version.
+        for i in 0..kvs.len() {
+            let actual_values = db
+                .get_backup_handler()
+                .get_account_iter(i as Version)
+                .unwrap()
+                .collect::<Result<Vec<_>>>()
+                .unwrap();
+            let mut expected_values: Vec<_> = kvs[..=i]
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            expected_values.sort_unstable_by_key(|item| item.0.hash());
+            prop_assert_eq!(actual_values, expected_values);
+        }
+    }
+
+    #[test]
+    fn test_raw_restore(
+        (input, batch1_size) in hash_map(any::<StateKey>(), any::<StateValue>(), 2..1000)
+            .prop_flat_map(|input| {
+                let len = input.len();
+                (Just(input), 1..len)
+            })
+    ) {
+        let tmp_dir1 = TempPath::new();
+        let db1 = AptosDB::new_for_test(&tmp_dir1);
+        let store1 = &db1.state_store;
+        init_store(store1, input.clone().into_iter());
+
+        let version = (input.len() - 1) as Version;
+        let expected_root_hash = store1.get_root_hash(version).unwrap();
+
+        let tmp_dir2 = TempPath::new();
+        let db2 = AptosDB::new_for_test(&tmp_dir2);
+        let store2 = &db2.state_store;
+
+        let mut restore =
+            StateSnapshotRestore::new(&store2.state_merkle_db, store2, version, expected_root_hash, true /* async_commit */).unwrap();
+
+        let mut ordered_input: Vec<_> = input
+            .into_iter()
+            .collect();
+        ordered_input.sort_unstable_by_key(|(key, _value)| key.hash());
 
         let batch1: Vec<_> = ordered_input
             .clone()
@@ -409,6 +3401,87 @@ proptest! {
         );
     }
 
+    #[test]
+    fn test_verify_value_chunk(
+        (input, chunk_size) in hash_map(any::<StateKey>(), any::<StateValue>(), 2..1000)
+            .prop_flat_map(|input| {
+                let len = input.len();
+                (Just(input), 1..len)
+            })
+    ) {
+        let tmp_dir = TempPath::new();
+        let db = AptosDB::new_for_test(&tmp_dir);
+        let store = &db.state_store;
+        init_store(store, input.clone().into_iter());
+
+        let version = (input.len() - 1) as Version;
+        let root_hash = store.get_root_hash(version).unwrap();
+        let first_value_proof = |chunk: &StateValueChunkWithProof| -> SparseMerkleProof {
+            store
+                .get_state_value_with_proof_by_version_ext(&chunk.raw_values[0].0, version)
+                .unwrap()
+                .1
+                .into()
+        };
+        let chunk = store.get_value_chunk_with_proof(version, 0, chunk_size).unwrap();
+        prop_assert!(store.verify_value_chunk(&chunk, root_hash, None).is_ok());
+        prop_assert!(store.verify_value_chunk(&chunk, HashValue::zero(), None).is_err());
+
+        let mut tampered_chunk = chunk.clone();
+        tampered_chunk.raw_values[0].1 = StateValue::from(b"tampered".to_vec());
+        prop_assume!(tampered_chunk.raw_values[0] != chunk.raw_values[0]);
+        let err = store.verify_value_chunk(&tampered_chunk, root_hash, None).unwrap_err();
+        prop_assert!(matches!(
+            err.downcast_ref::<StateValueChunkError>(),
+            Some(StateValueChunkError::InvalidProof(_))
+        ));
+
+        // The later chunks verify with the proof of their first value.
+        let mut first_index = chunk_size;
+        while first_index < input.len() {
+            let chunk = store
+                .get_value_chunk_with_proof(version, first_index, chunk_size)
+                .unwrap();
+            let proof = first_value_proof(&chunk);
+            prop_assert!(store.verify_value_chunk(&chunk, root_hash, Some(&proof)).is_ok());
+
+            let err = store.verify_value_chunk(&chunk, root_hash, None).unwrap_err();
+            prop_assert!(matches!(
+                err.downcast_ref::<StateValueChunkError>(),
+                Some(StateValueChunkError::PrecedingValuesUnknown(_))
+            ));
+
+            // Neither the first value nor the last one can be changed.
+            let mut tampered_chunk = chunk.clone();
+            tampered_chunk.raw_values[0].1 = StateValue::from(b"tampered".to_vec());
+            if tampered_chunk.raw_values[0] != chunk.raw_values[0] {
+                prop_assert!(store
+                    .verify_value_chunk(&tampered_chunk, root_hash, Some(&proof))
+                    .is_err());
+            }
+            let mut tampered_chunk = chunk.clone();
+            tampered_chunk.raw_values.last_mut().unwrap().1 =
+                StateValue::from(b"tampered".to_vec());
+            if tampered_chunk.raw_values.last() != chunk.raw_values.last() {
+                prop_assert!(store
+                    .verify_value_chunk(&tampered_chunk, root_hash, Some(&proof))
+                    .is_err());
+            }
+            // Nor can values be dropped from the start of the chunk.
+            if chunk.raw_values.len() > 1 {
+                let mut truncated_chunk = chunk.clone();
+                truncated_chunk.raw_values.remove(0);
+                truncated_chunk.first_index += 1;
+                truncated_chunk.first_key = truncated_chunk.raw_values[0].0.hash();
+                prop_assert!(store
+                    .verify_value_chunk(&truncated_chunk, root_hash, Some(&proof))
+                    .is_err());
+            }
+
+            first_index += chunk_size;
+        }
+    }
+
     #[test]
     fn test_get_rightmost_leaf(
         (input, batch1_size) in hash_map(any::<StateKey>(), any::<StateValue>(), 2..1000)
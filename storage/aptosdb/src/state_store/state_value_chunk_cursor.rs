@@ -0,0 +1,78 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{state_merkle_db::StateMerkleDb, state_store::StateStore};
+use anyhow::{ensure, Result};
+use aptos_jellyfish_merkle::iterator::JellyfishMerkleIterator;
+use aptos_types::{
+    state_store::{state_key::StateKey, state_value::StateValueChunkWithProof},
+    transaction::Version,
+};
+use std::sync::Arc;
+
+/// Reads consecutive value chunks of the snapshot at a version, as returned by
+/// `StateStore::get_value_chunk_cursor`. It keeps the position in the tree between chunks, so
+/// unlike `StateStore::get_value_chunk_with_proof` it doesn't seek from the root for each chunk.
+pub struct StateValueChunkCursor {
+    store: Arc<StateStore>,
+    version: Version,
+    next_index: usize,
+    iter: JellyfishMerkleIterator<StateMerkleDb, StateKey>,
+    failed: bool,
+}
+
+impl StateValueChunkCursor {
+    pub(crate) fn new(
+        store: Arc<StateStore>,
+        version: Version,
+        first_index: usize,
+        iter: JellyfishMerkleIterator<StateMerkleDb, StateKey>,
+    ) -> Self {
+        Self {
+            store,
+            version,
+            next_index: first_index,
+            iter,
+            failed: false,
+        }
+    }
+
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// The index of the first value of the next chunk.
+    pub fn next_index(&self) -> usize {
+        self.next_index
+    }
+
+    /// Returns the chunk of up to `chunk_size` values starting at `next_index`, and moves past it.
+    /// Fails with `AptosDbError::NotFound` once all the values have been read. After any other
+    /// error the cursor can't be used anymore, and reading has to resume from a new cursor at
+    /// `next_index`.
+    pub fn next_chunk(&mut self, chunk_size: usize) -> Result<StateValueChunkWithProof> {
+        ensure!(
+            !self.failed,
+            "Value chunk cursor at version {} failed at index {}.",
+            self.version,
+            self.next_index,
+        );
+        // The leaves taken from the iterator are gone even if the chunk then fails, so the
+        // cursor would no longer know where the next chunk starts.
+        self.failed = true;
+        let leaves = self
+            .iter
+            .by_ref()
+            .take(chunk_size)
+            .map(|res| res.map(|(_, (key, version))| (key, version)))
+            .collect::<Result<Vec<_>>>()?;
+        let at_end = leaves.is_empty();
+        let chunk = self
+            .store
+            .value_chunk_with_proof(self.version, self.next_index, leaves);
+        self.failed = chunk.is_err() && !at_end;
+        let chunk = chunk?;
+        self.next_index += chunk.raw_values.len();
+        Ok(chunk)
+    }
+}
@@ -413,6 +413,7 @@ pub fn test_save_blocks_impl(
             .flat_map(|(txns_to_commit, _)| txns_to_commit.iter())
             .collect(),
     );
+    db.state_store.verify_buffered_state_consistency().unwrap();
 }
 
 fn verify_snapshots(
@@ -884,6 +885,26 @@ pub fn put_as_state_root(db: &AptosDB, version: Version, key: StateKey, value: S
         .unwrap();
 }
 
+/// Writes `value` for `key` at each of `versions` in the state kv db only, e.g. to give a key a
+/// long history of versions to read from.
+pub fn put_state_value_versions(
+    db: &AptosDB,
+    key: &StateKey,
+    value: &StateValue,
+    versions: impl IntoIterator<Item = Version>,
+) {
+    let batch = SchemaBatch::new();
+    for version in versions {
+        batch
+            .put::<StateValueSchema>(&(key.clone(), version), &Some(value.clone()))
+            .unwrap();
+    }
+    db.state_kv_db
+        .db_shard(key.get_shard_id())
+        .write_schemas(batch)
+        .unwrap();
+}
+
 pub fn test_sync_transactions_impl(
     input: Vec<(Vec<TransactionToCommit>, LedgerInfoWithSignatures)>,
     snapshot_size_threshold: usize,
@@ -90,7 +90,94 @@ where
     }
 }
 
+/// Iterates the key, value pairs under a key prefix at a desired version, in the order of the keys
+/// in the db. With sharding, the keys are spread over the shards by their hashes, so the shards are
+/// iterated together and merged back into that order.
 pub struct PrefixedStateValueIterator<'a> {
+    shard_iters: Vec<ShardPrefixedStateValueIterator<'a>>,
+    // With more than one shard, the next pair of each shard, if any, with its key encoded as in
+    // the db to merge by.
+    next_items: Vec<Option<(Vec<u8>, StateKey, StateValue)>>,
+}
+
+impl<'a> PrefixedStateValueIterator<'a> {
+    /// Iterates over `dbs`, each holding a disjoint set of keys, e.g., the shards of the state K/V
+    /// db or the metadata db alone without sharding.
+    pub fn new(
+        dbs: Vec<&'a DB>,
+        key_prefix: StateKeyPrefix,
+        first_key: Option<StateKey>,
+        desired_version: Version,
+    ) -> Result<Self> {
+        let shard_iters = dbs
+            .into_iter()
+            .map(|db| {
+                ShardPrefixedStateValueIterator::new(
+                    db,
+                    key_prefix.clone(),
+                    first_key.clone(),
+                    desired_version,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut iter = Self {
+            shard_iters,
+            next_items: vec![],
+        };
+        if iter.shard_iters.len() > 1 {
+            for shard_index in 0..iter.shard_iters.len() {
+                let next_item = iter.next_shard_item(shard_index)?;
+                iter.next_items.push(next_item);
+            }
+        }
+        Ok(iter)
+    }
+
+    fn next_shard_item(
+        &mut self,
+        shard_index: usize,
+    ) -> Result<Option<(Vec<u8>, StateKey, StateValue)>> {
+        self.shard_iters[shard_index]
+            .next_impl()?
+            .map(|(state_key, state_value)| Ok((state_key.encode()?, state_key, state_value)))
+            .transpose()
+    }
+
+    fn next_impl(&mut self) -> Result<Option<(StateKey, StateValue)>> {
+        // Without sharding, there's nothing to merge.
+        if let [shard_iter] = self.shard_iters.as_mut_slice() {
+            return shard_iter.next_impl();
+        }
+        let next_shard_index = self
+            .next_items
+            .iter()
+            .enumerate()
+            .filter_map(|(shard_index, item)| item.as_ref().map(|(key, _, _)| (key, shard_index)))
+            .min()
+            .map(|(_, shard_index)| shard_index);
+        match next_shard_index {
+            Some(shard_index) => {
+                let next_item = self.next_shard_item(shard_index)?;
+                let (_, state_key, state_value) =
+                    std::mem::replace(&mut self.next_items[shard_index], next_item)
+                        .expect("Next item must exist.");
+                Ok(Some((state_key, state_value)))
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'a> Iterator for PrefixedStateValueIterator<'a> {
+    type Item = Result<(StateKey, StateValue)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_impl().transpose()
+    }
+}
+
+/// Iterates the key, value pairs under a key prefix at a desired version in a single db.
+struct ShardPrefixedStateValueIterator<'a> {
     inner: SchemaIterator<'a, StateValueSchema>,
     key_prefix: StateKeyPrefix,
     prev_key: Option<StateKey>,
@@ -98,8 +185,8 @@ pub struct PrefixedStateValueIterator<'a> {
     is_finished: bool,
 }
 
-impl<'a> PrefixedStateValueIterator<'a> {
-    pub fn new(
+impl<'a> ShardPrefixedStateValueIterator<'a> {
+    fn new(
         db: &'a DB,
         key_prefix: StateKeyPrefix,
         first_key: Option<StateKey>,
@@ -167,14 +254,6 @@ impl<'a> PrefixedStateValueIterator<'a> {
     }
 }
 
-impl<'a> Iterator for PrefixedStateValueIterator<'a> {
-    type Item = Result<(StateKey, StateValue)>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.next_impl().transpose()
-    }
-}
-
 pub struct AccountTransactionVersionIter<'a> {
     inner: SchemaIterator<'a, TransactionByAccountSchema>,
     address: AccountAddress,
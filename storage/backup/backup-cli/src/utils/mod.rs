@@ -13,8 +13,8 @@ pub mod test_utils;
 
 use anyhow::{anyhow, Result};
 use aptos_config::config::{
-    RocksdbConfig, RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS,
-    DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD, NO_OP_STORAGE_PRUNER_CONFIG,
+    RocksdbConfig, RocksdbConfigs, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+    NO_OP_STORAGE_PRUNER_CONFIG,
 };
 use aptos_crypto::HashValue;
 use aptos_db::{
@@ -22,7 +22,7 @@ use aptos_db::{
     state_restore::{
         StateSnapshotProgress, StateSnapshotRestore, StateValueBatch, StateValueWriter,
     },
-    AptosDB, GetRestoreHandler,
+    AptosDB, GetRestoreHandler, StateStoreOptions,
 };
 use aptos_infallible::duration_since_epoch;
 use aptos_jellyfish_merkle::{NodeBatch, TreeWriter};
@@ -275,8 +275,8 @@ impl TryFrom<GlobalRestoreOpt> for GlobalRestoreOptions {
                 NO_OP_STORAGE_PRUNER_CONFIG, /* pruner config */
                 opt.rocksdb_opt.into(),
                 false,
-                BUFFERED_STATE_TARGET_ITEMS,
                 DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+                StateStoreOptions::default(),
             )?)
             .get_restore_handler();
             RestoreRunMode::Restore { restore_handler }
@@ -9,10 +9,9 @@ use aptos_backup_cli::{
     utils::{ConcurrentDownloadsOpt, ReplayConcurrencyLevelOpt, RocksdbOpt, TrustedWaypointOpt},
 };
 use aptos_config::config::{
-    BUFFERED_STATE_TARGET_ITEMS, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
-    NO_OP_STORAGE_PRUNER_CONFIG,
+    DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD, NO_OP_STORAGE_PRUNER_CONFIG,
 };
-use aptos_db::{AptosDB, GetRestoreHandler};
+use aptos_db::{AptosDB, GetRestoreHandler, StateStoreOptions};
 use aptos_executor_types::VerifyExecutionMode;
 use aptos_types::transaction::Version;
 use clap::Parser;
@@ -66,8 +65,8 @@ impl Opt {
             NO_OP_STORAGE_PRUNER_CONFIG, /* pruner config */
             self.rocksdb_opt.into(),
             false,
-            BUFFERED_STATE_TARGET_ITEMS,
             DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            StateStoreOptions::default(),
         )?)
         .get_restore_handler();
         ReplayVerifyCoordinator::new(
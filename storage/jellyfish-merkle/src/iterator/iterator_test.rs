@@ -177,6 +177,40 @@ fn run_tests<K>(
                     .collect::<Vec<_>>(),
             );
         }
+
+        {
+            let iter = JellyfishMerkleIterator::new_rev(Arc::clone(&db), version, ith_key).unwrap();
+            assert_eq!(
+                iter.collect::<Result<Vec<_>>>()
+                    .unwrap()
+                    .into_iter()
+                    .map(|x| (x.0, x.1 .0))
+                    .collect::<Vec<_>>(),
+                btree
+                    .range(..=ith_key)
+                    .rev()
+                    .map(|x| (*x.0, x.1 .1.clone()))
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        {
+            let ith_key_plus_one = plus_one(ith_key);
+            let iter = JellyfishMerkleIterator::new_rev(Arc::clone(&db), version, ith_key_plus_one)
+                .unwrap();
+            assert_eq!(
+                iter.collect::<Result<Vec<_>>>()
+                    .unwrap()
+                    .into_iter()
+                    .map(|x| (x.0, x.1 .0))
+                    .collect::<Vec<_>>(),
+                btree
+                    .range(..=ith_key_plus_one)
+                    .rev()
+                    .map(|x| (*x.0, x.1 .1.clone()))
+                    .collect::<Vec<_>>(),
+            );
+        }
     }
 
     {
@@ -208,4 +242,42 @@ fn run_tests<K>(
             vec![]
         );
     }
+
+    {
+        let iter = JellyfishMerkleIterator::new_rev(
+            Arc::clone(&db),
+            version,
+            HashValue::new([0xFF; HashValue::LENGTH]),
+        )
+        .unwrap();
+        assert_eq!(
+            iter.collect::<Result<Vec<_>>>()
+                .unwrap()
+                .into_iter()
+                .map(|x| (x.0, x.1 .0))
+                .collect::<Vec<_>>(),
+            btree
+                .clone()
+                .into_iter()
+                .rev()
+                .map(|x| (x.0, x.1 .1))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    {
+        let iter =
+            JellyfishMerkleIterator::new_rev(Arc::clone(&db), version, HashValue::zero()).unwrap();
+        assert_eq!(
+            iter.collect::<Result<Vec<_>>>()
+                .unwrap()
+                .into_iter()
+                .map(|x| (x.0, x.1 .0))
+                .collect::<Vec<_>>(),
+            btree
+                .range(..=HashValue::zero())
+                .map(|x| (*x.0, x.1 .1.clone()))
+                .collect::<Vec<_>>(),
+        );
+    }
 }
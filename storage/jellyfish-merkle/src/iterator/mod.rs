@@ -5,7 +5,8 @@
 //! This module implements `JellyfishMerkleIterator`. Initialized with a version and a key, the
 //! iterator generates all the key-value pairs in this version of the tree, starting from the
 //! smallest key that is greater or equal to the given key, by performing a depth first traversal
-//! on the tree.
+//! on the tree. In reverse, it generates them in descending order instead, starting from the
+//! largest key that is less or equal to the given key.
 
 #[cfg(test)]
 mod iterator_test;
@@ -39,57 +40,92 @@ struct NodeVisitInfo {
     /// This integer always has exactly one 1-bit. The position of the 1-bit (from LSB) indicates
     /// the next child to visit in the iteration process. All the ones on the left have already
     /// been visited. All the children on the right (including this one) have not been visited yet.
+    /// In reverse, it's the other way around.
     next_child_to_visit: u16,
+
+    /// Whether the children are visited from right to left.
+    reverse: bool,
 }
 
 impl NodeVisitInfo {
     /// Constructs a new `NodeVisitInfo` with given node key and node. `next_child_to_visit` will
-    /// be set to the leftmost child.
-    fn new(node_key: NodeKey, node: InternalNode) -> Self {
+    /// be set to the leftmost child, or the rightmost one in reverse.
+    fn new(node_key: NodeKey, node: InternalNode, reverse: bool) -> Self {
         let (children_bitmap, _) = node.generate_bitmaps();
         assert!(children_bitmap != 0);
+        let next_child_to_visit = if reverse {
+            1 << (15 - children_bitmap.leading_zeros())
+        } else {
+            1 << children_bitmap.trailing_zeros()
+        };
         Self {
             node_key,
             node,
             children_bitmap,
-            next_child_to_visit: 1 << children_bitmap.trailing_zeros(),
+            next_child_to_visit,
+            reverse,
         }
     }
 
     /// Same as `new` but points `next_child_to_visit` to a specific location. If the child
     /// corresponding to `next_child_to_visit` does not exist, set it to the next one on the
-    /// right.
+    /// right, or on the left in reverse.
     fn new_next_child_to_visit(
         node_key: NodeKey,
         node: InternalNode,
         next_child_to_visit: Nibble,
+        reverse: bool,
     ) -> Self {
         let (children_bitmap, _) = node.generate_bitmaps();
         let mut next_child_to_visit = 1 << u8::from(next_child_to_visit);
-        assert!(children_bitmap >= next_child_to_visit);
-        while next_child_to_visit & children_bitmap == 0 {
-            next_child_to_visit <<= 1;
+        if reverse {
+            assert!(children_bitmap & (next_child_to_visit | (next_child_to_visit - 1)) != 0);
+            while next_child_to_visit & children_bitmap == 0 {
+                next_child_to_visit >>= 1;
+            }
+        } else {
+            assert!(children_bitmap >= next_child_to_visit);
+            while next_child_to_visit & children_bitmap == 0 {
+                next_child_to_visit <<= 1;
+            }
         }
         Self {
             node_key,
             node,
             children_bitmap,
             next_child_to_visit,
+            reverse,
         }
     }
 
-    /// Whether the next child to visit is the rightmost one.
-    fn is_rightmost(&self) -> bool {
-        assert!(self.next_child_to_visit.leading_zeros() >= self.children_bitmap.leading_zeros());
-        self.next_child_to_visit.leading_zeros() == self.children_bitmap.leading_zeros()
+    /// Whether the next child to visit is the last one, i.e. the rightmost one, or the leftmost
+    /// one in reverse.
+    fn is_last(&self) -> bool {
+        if self.reverse {
+            assert!(
+                self.next_child_to_visit.trailing_zeros() >= self.children_bitmap.trailing_zeros()
+            );
+            self.next_child_to_visit.trailing_zeros() == self.children_bitmap.trailing_zeros()
+        } else {
+            assert!(
+                self.next_child_to_visit.leading_zeros() >= self.children_bitmap.leading_zeros()
+            );
+            self.next_child_to_visit.leading_zeros() == self.children_bitmap.leading_zeros()
+        }
     }
 
-    /// Advances `next_child_to_visit` to the next child on the right.
+    /// Advances `next_child_to_visit` to the next child on the right, or on the left in reverse.
     fn advance(&mut self) {
-        assert!(!self.is_rightmost(), "Advancing past rightmost child.");
-        self.next_child_to_visit <<= 1;
-        while self.next_child_to_visit & self.children_bitmap == 0 {
-            self.next_child_to_visit <<= 1;
+        assert!(!self.is_last(), "Advancing past last child.");
+        loop {
+            if self.reverse {
+                self.next_child_to_visit >>= 1;
+            } else {
+                self.next_child_to_visit <<= 1;
+            }
+            if self.next_child_to_visit & self.children_bitmap != 0 {
+                break;
+            }
         }
     }
 }
@@ -110,6 +146,9 @@ pub struct JellyfishMerkleIterator<R, K> {
     /// additional bit.
     done: bool,
 
+    /// Whether the keys are generated in descending order.
+    reverse: bool,
+
     phantom_value: PhantomData<K>,
 }
 
@@ -122,6 +161,22 @@ where
     /// following `next` call will yield the smallest key that is greater or equal to
     /// `starting_key`.
     pub fn new(reader: Arc<R>, version: Version, starting_key: HashValue) -> Result<Self> {
+        Self::new_impl(reader, version, starting_key, false)
+    }
+
+    /// Constructs a new reverse iterator, which yields the keys in descending order. This puts
+    /// the internal state in the correct position, so the following `next` call will yield the
+    /// largest key that is less or equal to `starting_key`.
+    pub fn new_rev(reader: Arc<R>, version: Version, starting_key: HashValue) -> Result<Self> {
+        Self::new_impl(reader, version, starting_key, true)
+    }
+
+    fn new_impl(
+        reader: Arc<R>,
+        version: Version,
+        starting_key: HashValue,
+        reverse: bool,
+    ) -> Result<Self> {
         let mut parent_stack = vec![];
         let mut done = false;
 
@@ -138,19 +193,26 @@ where
                         current_node_key.clone(),
                         internal_node.clone(),
                         child_index,
+                        reverse,
                     ));
                     current_node_key =
                         current_node_key.gen_child_node_key(child.version, child_index);
                 },
                 None => {
                     let (bitmap, _) = internal_node.generate_bitmaps();
-                    if u32::from(u8::from(child_index)) < 15 - bitmap.leading_zeros() {
-                        // If this child does not exist and there's another child on the right, we
-                        // set the child on the right to be the next one to visit.
+                    let has_next_child = if reverse {
+                        bitmap & ((1 << u8::from(child_index)) - 1) != 0
+                    } else {
+                        u32::from(u8::from(child_index)) < 15 - bitmap.leading_zeros()
+                    };
+                    if has_next_child {
+                        // If this child does not exist and there's another child on the right (on
+                        // the left in reverse), we set that child to be the next one to visit.
                         parent_stack.push(NodeVisitInfo::new_next_child_to_visit(
                             current_node_key,
                             internal_node,
                             child_index,
+                            reverse,
                         ));
                     } else {
                         // Otherwise we have done visiting this node. Go backward and clean up the
@@ -162,6 +224,7 @@ where
                         version,
                         parent_stack,
                         done,
+                        reverse,
                         phantom_value: PhantomData,
                     });
                 },
@@ -171,7 +234,12 @@ where
         match reader.get_node(&current_node_key)? {
             Node::Internal(_) => unreachable!("Should have reached the bottom of the tree."),
             Node::Leaf(leaf_node) => {
-                if leaf_node.account_key() < starting_key {
+                let skip_leaf = if reverse {
+                    leaf_node.account_key() > starting_key
+                } else {
+                    leaf_node.account_key() < starting_key
+                };
+                if skip_leaf {
                     Self::cleanup_stack(&mut parent_stack);
                     if parent_stack.is_empty() {
                         done = true;
@@ -188,13 +256,14 @@ where
             version,
             parent_stack,
             done,
+            reverse,
             phantom_value: PhantomData,
         })
     }
 
     fn cleanup_stack(parent_stack: &mut Vec<NodeVisitInfo>) {
         while let Some(info) = parent_stack.last_mut() {
-            if info.is_rightmost() {
+            if info.is_last() {
                 parent_stack.pop();
             } else {
                 info.advance();
@@ -216,6 +285,7 @@ where
                 version,
                 parent_stack,
                 done: true,
+                reverse: false,
                 phantom_value: PhantomData,
             });
         }
@@ -233,6 +303,7 @@ where
                         version,
                         parent_stack,
                         done: false,
+                        reverse: false,
                         phantom_value: PhantomData,
                     });
                 },
@@ -244,6 +315,7 @@ where
                         current_node_key,
                         internal_node,
                         nibble,
+                        false,
                     ));
                     current_node_key = next_node_key;
                 },
@@ -291,9 +363,9 @@ where
             match self.reader.get_node(&root_node_key) {
                 Ok(Node::Leaf(leaf_node)) => {
                     // This means the entire tree has a single leaf node. The key of this leaf node
-                    // is greater or equal to `starting_key` (otherwise we would have set `done` to
-                    // true in `new`). Return the node and mark `self.done` so next time we return
-                    // None.
+                    // is greater or equal to `starting_key`, or less or equal in reverse
+                    // (otherwise we would have set `done` to true in `new`). Return the node and
+                    // mark `self.done` so next time we return None.
                     self.done = true;
                     return Some(Ok((
                         leaf_node.account_key(),
@@ -301,8 +373,8 @@ where
                     )));
                 },
                 Ok(Node::Internal(_)) => {
-                    // This means `starting_key` is bigger than every key in this tree, or we have
-                    // iterated past the last key.
+                    // This means `starting_key` is bigger than every key in this tree (smaller in
+                    // reverse), or we have iterated past the last key.
                     return None;
                 },
                 Ok(Node::Null) => {
@@ -329,7 +401,7 @@ where
             );
             match self.reader.get_node(&node_key) {
                 Ok(Node::Internal(internal_node)) => {
-                    let visit_info = NodeVisitInfo::new(node_key, internal_node);
+                    let visit_info = NodeVisitInfo::new(node_key, internal_node, self.reverse);
                     self.parent_stack.push(visit_info);
                 },
                 Ok(Node::Leaf(leaf_node)) => {
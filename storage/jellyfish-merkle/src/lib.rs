@@ -104,6 +104,7 @@ use std::{
     collections::{BTreeMap, HashMap},
     hash::Hash,
     marker::PhantomData,
+    sync::Mutex,
 };
 use thiserror::Error;
 
@@ -147,6 +148,44 @@ pub trait TreeReader<K> {
     fn get_rightmost_leaf(&self, version: Version) -> Result<Option<(NodeKey, LeafNode<K>)>>;
 }
 
+/// Remembers the nodes read through `reader`, so a batch of reads sharing paths from the root
+/// reads each node from the underlying storage only once.
+struct CachingTreeReader<'a, R, K> {
+    reader: &'a R,
+    nodes: Mutex<HashMap<NodeKey, Option<Node<K>>>>,
+}
+
+impl<'a, R, K> CachingTreeReader<'a, R, K> {
+    fn new(reader: &'a R) -> Self {
+        Self {
+            reader,
+            nodes: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<'a, R, K> TreeReader<K> for CachingTreeReader<'a, R, K>
+where
+    R: TreeReader<K>,
+    K: Clone,
+{
+    fn get_node_option(&self, node_key: &NodeKey, tag: &str) -> Result<Option<Node<K>>> {
+        if let Some(node) = self.nodes.lock().unwrap().get(node_key) {
+            return Ok(node.clone());
+        }
+        let node = self.reader.get_node_option(node_key, tag)?;
+        self.nodes
+            .lock()
+            .unwrap()
+            .insert(node_key.clone(), node.clone());
+        Ok(node)
+    }
+
+    fn get_rightmost_leaf(&self, version: Version) -> Result<Option<(NodeKey, LeafNode<K>)>> {
+        self.reader.get_rightmost_leaf(version)
+    }
+}
+
 pub trait TreeWriter<K>: Send + Sync {
     /// Writes a node batch into storage.
     fn write_node_batch(&self, node_batch: &HashMap<NodeKey, Node<K>>) -> Result<()>;
@@ -704,6 +743,20 @@ where
         bail!("Jellyfish Merkle tree has cyclic graph inside.");
     }
 
+    /// Same as `get_with_proof_ext` for each of `keys`, in the same order, but reads each node only
+    /// once, so the part of the tree the keys' paths share is traversed once.
+    pub fn batch_get_with_proof_ext(
+        &self,
+        keys: &[HashValue],
+        version: Version,
+    ) -> Result<Vec<(Option<(HashValue, (K, Version))>, SparseMerkleProofExt)>> {
+        let reader = CachingTreeReader::new(self.reader);
+        let tree = JellyfishMerkleTree::new(&reader);
+        keys.iter()
+            .map(|key| tree.get_with_proof_ext(*key, version))
+            .collect()
+    }
+
     /// Gets the proof that shows a list of keys up to `rightmost_key_to_prove` exist at `version`.
     pub fn get_range_proof(
         &self,
@@ -758,6 +811,46 @@ where
         self.get_root_node(version).map(|n| n.leaf_count())
     }
 
+    /// Returns the index of the leaf of `key` among all the leaves at `version` ordered by key,
+    /// i.e. the number of leaves to its left, or `None` if `key` doesn't exist at `version`.
+    pub fn get_leaf_index(&self, key: HashValue, version: Version) -> Result<Option<usize>> {
+        let mut next_node_key = NodeKey::new_empty_path(version);
+        let nibble_path = NibblePath::new_even(key.to_vec());
+        let mut nibble_iter = nibble_path.nibbles();
+        let mut leaves_to_left = 0;
+
+        // We limit the number of loops here deliberately to avoid potential cyclic graph bugs
+        // in the tree structure.
+        for _ in 0..=ROOT_NIBBLE_HEIGHT {
+            match self
+                .reader
+                .get_node_with_tag(&next_node_key, "get_leaf_index")?
+            {
+                Node::Internal(internal_node) => {
+                    let queried_child_index = nibble_iter
+                        .next()
+                        .ok_or_else(|| format_err!("ran out of nibbles"))?;
+                    leaves_to_left += internal_node
+                        .children_sorted()
+                        .take_while(|(nibble, _)| **nibble < queried_child_index)
+                        .map(|(_, child)| child.leaf_count())
+                        .sum::<usize>();
+                    next_node_key = match internal_node.child(queried_child_index) {
+                        Some(child) => {
+                            next_node_key.gen_child_node_key(child.version, queried_child_index)
+                        },
+                        None => return Ok(None),
+                    };
+                },
+                Node::Leaf(leaf_node) => {
+                    return Ok((leaf_node.account_key() == key).then_some(leaves_to_left));
+                },
+                Node::Null => return Ok(None),
+            }
+        }
+        bail!("Jellyfish Merkle tree has cyclic graph inside.");
+    }
+
     pub fn get_all_nodes_referenced(&self, version: Version) -> Result<Vec<NodeKey>> {
         let mut out_keys = vec![];
         self.get_all_nodes_referenced_impl(NodeKey::new_empty_path(version), &mut out_keys)?;
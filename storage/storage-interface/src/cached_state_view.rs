@@ -17,6 +17,7 @@ use aptos_types::{
 use dashmap::DashMap;
 use once_cell::sync::Lazy;
 use parking_lot::RwLock;
+use rayon::prelude::*;
 use std::{
     collections::{HashMap, HashSet},
     sync::Arc,
@@ -113,24 +114,22 @@ impl CachedStateView {
         })
     }
 
+    /// Loads the values of all the keys touched by `write_sets` into the cache. The keys are
+    /// deduplicated first, then read in parallel on `IO_POOL`, since the reads don't depend on
+    /// each other and don't apply the write sets.
     pub fn prime_cache_by_write_set<'a, T: IntoIterator<Item = &'a WriteSet> + Send>(
         &self,
         write_sets: T,
     ) -> Result<()> {
-        IO_POOL.scope(|s| {
-            write_sets
-                .into_iter()
-                .flat_map(|write_set| write_set.iter())
-                .map(|(key, _)| key)
-                .collect::<HashSet<_>>()
-                .into_iter()
-                .for_each(|key| {
-                    s.spawn(move |_| {
-                        self.get_state_value_bytes(key).expect("Must succeed.");
-                    })
-                });
-        });
-        Ok(())
+        let keys = write_sets
+            .into_iter()
+            .flat_map(|write_set| write_set.iter())
+            .map(|(key, _)| key)
+            .collect::<HashSet<_>>();
+        IO_POOL.install(|| {
+            keys.into_par_iter()
+                .try_for_each(|key| self.get_state_value_bytes(key).map(|_| ()))
+        })
     }
 
     pub fn into_state_cache(self) -> StateCache {
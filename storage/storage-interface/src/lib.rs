@@ -100,6 +100,16 @@ pub enum Order {
     Descending,
 }
 
+/// Whether the async commit channel of the DB had room for the state snapshot due on a commit, if
+/// any. A commit reporting it full should be followed by slowing down.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AsyncCommitChannelStatus {
+    Available,
+    /// The channel stayed full until the timeout of the `AsyncCommitChannelFullPolicy`, so the
+    /// snapshot due is held back and sent with a later commit.
+    Full,
+}
+
 /// Trait that is implemented by a DB that supports certain public (to client) read APIs
 /// expected of an Aptos DB
 #[allow(unused_variables)]
@@ -394,6 +404,18 @@ pub trait DbReader: Send + Sync {
         unimplemented!()
     }
 
+    /// Same as `get_state_value_by_version`, with a hint that the key was last written at or
+    /// after `lower_bound_version`, which lets the read skip the older versions of the key. The
+    /// hint is advisory, so by default it's ignored.
+    fn get_state_value_by_version_with_lower_bound(
+        &self,
+        state_key: &StateKey,
+        version: Version,
+        lower_bound_version: Option<Version>,
+    ) -> Result<Option<StateValue>> {
+        self.get_state_value_by_version(state_key, version)
+    }
+
     /// Returns the proof of the given state key and version.
     fn get_state_proof_by_version_ext(
         &self,
@@ -613,7 +635,7 @@ pub trait DbWriter: Send + Sync {
     }
 
     /// Persist transactions. Called by the executor module when either syncing nodes or committing
-    /// blocks during normal operation.
+    /// blocks during normal operation. Reports whether the async commit channel was full.
     /// See [`AptosDB::save_transactions`].
     ///
     /// [`AptosDB::save_transactions`]: ../aptosdb/struct.AptosDB.html#method.save_transactions
@@ -625,7 +647,7 @@ pub trait DbWriter: Send + Sync {
         ledger_info_with_sigs: Option<&LedgerInfoWithSignatures>,
         sync_commit: bool,
         latest_in_memory_state: StateDelta,
-    ) -> Result<()> {
+    ) -> Result<AsyncCommitChannelStatus> {
         unimplemented!()
     }
 }
@@ -703,12 +725,46 @@ impl SaveTransactionsRequest {
     }
 }
 
+/// Determines how a state key and value map to the key hash and value hash of a JMT leaf.
+pub trait LeafHasher: Send + Sync {
+    fn hash_key(&self, state_key: &StateKey) -> HashValue;
+
+    fn hash_value(&self, state_value: &StateValue) -> HashValue;
+}
+
+/// Hashes the leaves with the default crypto hashes of the key and value.
+pub struct DefaultLeafHasher;
+
+impl LeafHasher for DefaultLeafHasher {
+    fn hash_key(&self, state_key: &StateKey) -> HashValue {
+        state_key.hash()
+    }
+
+    fn hash_value(&self, state_value: &StateValue) -> HashValue {
+        state_value.hash()
+    }
+}
+
 pub fn jmt_updates(
     state_updates: &HashMap<StateKey, Option<StateValue>>,
+) -> Vec<(HashValue, Option<(HashValue, StateKey)>)> {
+    jmt_updates_with_hasher(state_updates, &DefaultLeafHasher)
+}
+
+pub fn jmt_updates_with_hasher<H: LeafHasher + ?Sized>(
+    state_updates: &HashMap<StateKey, Option<StateValue>>,
+    leaf_hasher: &H,
 ) -> Vec<(HashValue, Option<(HashValue, StateKey)>)> {
     state_updates
         .iter()
-        .map(|(k, v_opt)| (k.hash(), v_opt.as_ref().map(|v| (v.hash(), k.clone()))))
+        .map(|(k, v_opt)| {
+            (
+                leaf_hasher.hash_key(k),
+                v_opt
+                    .as_ref()
+                    .map(|v| (leaf_hasher.hash_value(v), k.clone())),
+            )
+        })
         .collect()
 }
 